@@ -0,0 +1,195 @@
+//! Asset handles.
+//!
+//! Typed, generational asset IDs.
+//! Strong handles that keep an asset loaded.
+//! Weak handles that only observe it.
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::rc::{Rc, Weak};
+
+/// Number of strong handles to one loaded asset, shared by its slot and handles.
+pub(crate) type RefCount = Rc<Cell<usize>>;
+
+/// Identifies an asset of type `T` in an [`super::Assets`] storage.
+///
+/// Uses generational indexing, so the ID of an unloaded asset never names an
+/// asset added later in the same slot.
+pub struct AssetId<T> {
+    pub index: usize,
+    pub generation: usize,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> AssetId<T> {
+    /// Creates an ID from its slot index and generation.
+    pub const fn new(index: usize, generation: usize) -> Self {
+        Self {
+            index,
+            generation,
+            marker: PhantomData,
+        }
+    }
+}
+
+// Implemented by hand: deriving would require `T` itself to implement each trait
+impl<T> Clone for AssetId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AssetId<T> {}
+
+impl<T> PartialEq for AssetId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.index, self.generation) == (other.index, other.generation)
+    }
+}
+
+impl<T> Eq for AssetId<T> {}
+
+impl<T> PartialOrd for AssetId<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for AssetId<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.index, self.generation).cmp(&(other.index, other.generation))
+    }
+}
+
+impl<T> Hash for AssetId<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for AssetId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssetId")
+            .field("index", &self.index)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+/// A strong reference to an asset: while any clone of it exists, the asset
+/// stays loaded.
+///
+/// Cloning and dropping handles is how the reference count changes; see
+/// [`super::Assets::unload_unused`].
+pub struct Handle<T> {
+    id: AssetId<T>,
+    count: RefCount,
+}
+
+impl<T> Handle<T> {
+    /// Creates a handle and counts it.
+    pub(crate) fn new(id: AssetId<T>, count: RefCount) -> Self {
+        count.set(count.get() + 1);
+        Self { id, count }
+    }
+
+    /// Returns the asset's ID.
+    pub fn id(&self) -> AssetId<T> {
+        self.id
+    }
+
+    /// Returns a weak handle to the same asset.
+    pub fn downgrade(&self) -> WeakHandle<T> {
+        WeakHandle {
+            id: self.id,
+            count: Rc::downgrade(&self.count),
+        }
+    }
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.id, Rc::clone(&self.count))
+    }
+}
+
+impl<T> Drop for Handle<T> {
+    fn drop(&mut self) {
+        self.count.set(self.count.get() - 1);
+    }
+}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> Hash for Handle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Handle").field(&self.id).finish()
+    }
+}
+
+impl<T> From<&Handle<T>> for AssetId<T> {
+    fn from(handle: &Handle<T>) -> Self {
+        handle.id
+    }
+}
+
+/// A reference to an asset that does not keep it loaded.
+pub struct WeakHandle<T> {
+    id: AssetId<T>,
+    /// Dead once the asset is unloaded.
+    count: Weak<Cell<usize>>,
+}
+
+impl<T> WeakHandle<T> {
+    /// Returns the asset's ID.
+    pub fn id(&self) -> AssetId<T> {
+        self.id
+    }
+
+    /// Returns a strong handle, or `None` while no strong handle is left.
+    ///
+    /// An asset without strong handles may still be loaded until the next
+    /// [`super::Assets::unload_unused`]; use [`super::Assets::handle`] to revive it,
+    /// after which this upgrades again.
+    pub fn upgrade(&self) -> Option<Handle<T>> {
+        let count = self.count.upgrade().filter(|count| count.get() > 0)?;
+        Some(Handle::new(self.id, count))
+    }
+}
+
+impl<T> Clone for WeakHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            count: Weak::clone(&self.count),
+        }
+    }
+}
+
+impl<T> fmt::Debug for WeakHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WeakHandle").field(&self.id).finish()
+    }
+}
+
+impl<T> From<&WeakHandle<T>> for AssetId<T> {
+    fn from(handle: &WeakHandle<T>) -> Self {
+        handle.id
+    }
+}
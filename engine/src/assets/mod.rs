@@ -4,3 +4,16 @@
 //! Asset handles and IDs.
 //! Caching and lifetime management.
 //! No actual asset files live here.
+//!
+//! [`Assets`] is for game data that is loaded and unloaded over time. The
+//! renderer's registries ([`crate::render::Textures`], [`crate::render::Meshes`],
+//! [`crate::render::Fonts`] and [`crate::render::Materials`]) are separate: they
+//! never remove entries and render commands refer to them by plain index, so an
+//! asset that must reach the renderer is still added to its registry, e.g. from
+//! a texture asset's loader, and the returned ID is kept next to the handle.
+
+mod handle;
+mod storage;
+
+pub use handle::{AssetId, Handle, WeakHandle};
+pub use storage::Assets;
@@ -0,0 +1,199 @@
+//! Asset storage.
+//!
+//! Typed asset storage resource.
+//! Path lookup so each file is loaded once.
+//! Unloading assets nothing refers to.
+
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use super::handle::RefCount;
+use super::{AssetId, Handle};
+
+struct Slot<T> {
+    generation: usize,
+    asset: Option<T>,
+    /// Counts the strong handles; replaced when the asset is unloaded.
+    count: RefCount,
+    path: Option<String>,
+}
+
+/// Resource owning every loaded asset of type `T`.
+///
+/// Adding or loading an asset hands out a strong [`Handle`]. Assets stay
+/// loaded while a strong handle exists; afterwards [`Self::unload_unused`]
+/// drops them, so call it (or run [`crate::ecs::system::asset_system`]) once
+/// per frame. Assets loaded from a path are cached by it: loading the same
+/// path again returns a handle to the loaded asset instead of loading a copy.
+pub struct Assets<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+    paths: BTreeMap<String, AssetId<T>>,
+}
+
+impl<T> Assets<T> {
+    /// Creates empty storage.
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            paths: BTreeMap::new(),
+        }
+    }
+
+    /// Stores an asset and returns the first strong handle to it.
+    pub fn add(&mut self, asset: T) -> Handle<T> {
+        self.insert(asset, None)
+    }
+
+    /// Returns a handle to the asset loaded from `path`, calling `loader` with the
+    /// path only if it is not loaded yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns the loader's error; nothing is stored then.
+    pub fn load<E>(
+        &mut self,
+        path: &str,
+        loader: impl FnOnce(&str) -> Result<T, E>,
+    ) -> Result<Handle<T>, E> {
+        if let Some(handle) = self.find(path) {
+            return Ok(handle);
+        }
+        let asset = loader(path)?;
+        Ok(self.insert(asset, Some(path.to_string())))
+    }
+
+    /// Returns a strong handle to the asset loaded from `path`, if it is loaded.
+    pub fn find(&mut self, path: &str) -> Option<Handle<T>> {
+        let id = *self.paths.get(path)?;
+        self.handle(id)
+    }
+
+    /// Returns a strong handle to a loaded asset.
+    ///
+    /// This keeps an asset whose handles were all dropped from being unloaded,
+    /// and lets earlier [`super::WeakHandle`]s to it upgrade again.
+    pub fn handle(&mut self, id: AssetId<T>) -> Option<Handle<T>> {
+        let slot = self.slot(id)?;
+        Some(Handle::new(id, Rc::clone(&slot.count)))
+    }
+
+    /// Returns the asset with the given ID, if it is loaded.
+    pub fn get(&self, id: impl Into<AssetId<T>>) -> Option<&T> {
+        self.slot(id.into())?.asset.as_ref()
+    }
+
+    /// Returns the asset with the given ID for editing, if it is loaded.
+    pub fn get_mut(&mut self, id: impl Into<AssetId<T>>) -> Option<&mut T> {
+        self.slot_mut(id.into())?.asset.as_mut()
+    }
+
+    /// Returns `true` if the asset with the given ID is loaded.
+    pub fn contains(&self, id: impl Into<AssetId<T>>) -> bool {
+        self.slot(id.into()).is_some()
+    }
+
+    /// Returns the path the asset was loaded from, or `None` for added assets.
+    pub fn path(&self, id: impl Into<AssetId<T>>) -> Option<&str> {
+        self.slot(id.into())?.path.as_deref()
+    }
+
+    /// Returns the number of strong handles to the asset; 0 if it is not loaded.
+    pub fn ref_count(&self, id: impl Into<AssetId<T>>) -> usize {
+        self.slot(id.into()).map_or(0, |slot| slot.count.get())
+    }
+
+    /// Returns the number of loaded assets.
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    /// Returns `true` if no asset is loaded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns every loaded asset in slot order.
+    pub fn iter(&self) -> impl Iterator<Item = (AssetId<T>, &T)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            let asset = slot.asset.as_ref()?;
+            Some((AssetId::new(index, slot.generation), asset))
+        })
+    }
+
+    /// Unloads an asset right away, even if handles to it remain; they then
+    /// resolve to nothing.
+    pub fn remove(&mut self, id: impl Into<AssetId<T>>) -> Option<T> {
+        let id = id.into();
+        let slot = self.slot_mut(id)?;
+        let asset = slot.asset.take();
+        // Detach remaining handles, so weak ones no longer upgrade
+        slot.count = RefCount::default();
+        slot.generation += 1;
+        if let Some(path) = slot.path.take() {
+            self.paths.remove(&path);
+        }
+        self.free.push(id.index);
+        asset
+    }
+
+    /// Unloads every asset without strong handles and returns their IDs in slot order.
+    pub fn unload_unused(&mut self) -> Vec<AssetId<T>> {
+        let unused: Vec<AssetId<T>> = self
+            .slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.asset.is_some() && slot.count.get() == 0)
+            .map(|(index, slot)| AssetId::new(index, slot.generation))
+            .collect();
+        for &id in &unused {
+            self.remove(id);
+        }
+        unused
+    }
+
+    fn insert(&mut self, asset: T, path: Option<String>) -> Handle<T> {
+        let count = RefCount::default();
+        let slot = Slot {
+            generation: 0,
+            asset: Some(asset),
+            count: Rc::clone(&count),
+            path: path.clone(),
+        };
+        let id = match self.free.pop() {
+            Some(index) => {
+                let generation = self.slots[index].generation;
+                self.slots[index] = Slot { generation, ..slot };
+                AssetId::new(index, generation)
+            }
+            None => {
+                self.slots.push(slot);
+                AssetId::new(self.slots.len() - 1, 0)
+            }
+        };
+        if let Some(path) = path {
+            self.paths.insert(path, id);
+        }
+        Handle::new(id, count)
+    }
+
+    fn slot(&self, id: AssetId<T>) -> Option<&Slot<T>> {
+        self.slots
+            .get(id.index)
+            .filter(|slot| slot.generation == id.generation && slot.asset.is_some())
+    }
+
+    fn slot_mut(&mut self, id: AssetId<T>) -> Option<&mut Slot<T>> {
+        self.slots
+            .get_mut(id.index)
+            .filter(|slot| slot.generation == id.generation && slot.asset.is_some())
+    }
+}
+
+impl<T> Default for Assets<T> {
+    /// Returns [`Assets::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,7 +1,10 @@
 use crate::World;
+use crate::core::extract::{extract_render_commands, extract_views};
 use crate::core::frame::FrameOutput;
+use crate::core::replay::{InputRecording, RecordedFrame, ReplayError, StateHasher};
 use crate::core::schedule::Schedule;
 use crate::input::Input;
+use crate::render::{DebugDraw, FrameStats, Meshes};
 use crate::time::FrameDelta;
 
 /// The main game engine.
@@ -9,6 +12,13 @@ use crate::time::FrameDelta;
 pub struct Engine {
     world: World,
     schedule: Schedule,
+    recorder: Option<Recorder>,
+}
+
+/// An in-progress recording started by [`Engine::start_recording`].
+struct Recorder {
+    recording: InputRecording,
+    hasher: Option<StateHasher>,
 }
 
 impl Engine {
@@ -17,16 +27,32 @@ impl Engine {
         Self {
             world: World::new(),
             schedule: Schedule::new(),
+            recorder: None,
         }
     }
 
+    /// Returns the engine's world.
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Returns the engine's world for setup (spawning entities, inserting resources).
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Returns the engine's schedule for registering systems.
+    pub fn schedule_mut(&mut self) -> &mut Schedule {
+        &mut self.schedule
+    }
+
     /// Runs all systems in the schedule once.
     pub fn run(&mut self) {
         self.schedule.run(&mut self.world);
     }
 
     /// Processes a single frame: updates systems and returns render output.
-    pub fn tick(&mut self, _input: Input, dt: f32) -> FrameOutput {
+    pub fn tick(&mut self, input: Input, dt: f32) -> FrameOutput {
         // Publish frame delta as a resource
         match self.world.insert_resource(FrameDelta { dt }) {
             None => {
@@ -37,12 +63,96 @@ impl Engine {
             }
         }
 
+        // Keep a copy of the input only while recording
+        let recorded_input = self.recorder.as_ref().map(|_| input.clone());
+
+        // Publish this frame's input as a resource, replacing last frame's
+        self.world.insert_resource(input);
+
         // Run all systems (including TimeSystem)
         self.schedule.run(&mut self.world);
 
+        if let (Some(recorder), Some(input)) = (self.recorder.as_mut(), recorded_input) {
+            let hash = recorder.hasher.map(|hasher| hasher(&self.world));
+            recorder
+                .recording
+                .frames
+                .push(RecordedFrame { input, dt, hash });
+        }
+
+        let render_commands = extract_render_commands(&self.world);
+        let views = extract_views(&self.world, &render_commands);
+        let mut stats = FrameStats::default();
+        if let Ok(meshes) = self.world.get_resource::<Meshes>() {
+            for output in &views {
+                stats += output.view.stats(meshes);
+            }
+        }
+        let debug = self
+            .world
+            .get_resource_mut::<DebugDraw>()
+            .map(|debug| debug.flush(dt))
+            .unwrap_or_default();
         FrameOutput {
-            render_commands: Vec::new(),
+            render_commands,
+            views,
+            stats,
+            debug,
+        }
+    }
+
+    /// Starts recording every subsequent [`Self::tick`] call.
+    ///
+    /// With a `hasher`, the world state is hashed after each frame so the
+    /// recording can later be checked with [`Self::verify_replay`].
+    /// Restarting discards any recording in progress.
+    pub fn start_recording(&mut self, hasher: Option<StateHasher>) {
+        self.recorder = Some(Recorder {
+            recording: InputRecording::new(),
+            hasher,
+        });
+    }
+
+    /// Stops recording and returns what was captured, if recording was active.
+    pub fn stop_recording(&mut self) -> Option<InputRecording> {
+        self.recorder.take().map(|recorder| recorder.recording)
+    }
+
+    /// Feeds every recorded frame through [`Self::tick`], in order.
+    ///
+    /// The engine should be set up exactly as it was when recording started.
+    pub fn replay(&mut self, recording: &InputRecording) -> Vec<FrameOutput> {
+        recording
+            .frames
+            .iter()
+            .map(|frame| self.tick(frame.input.clone(), frame.dt))
+            .collect()
+    }
+
+    /// Replays a recording and checks the world hash after every frame.
+    ///
+    /// Stops at the first frame whose hash differs from the recorded one and
+    /// returns `Err(ReplayError::Diverged { .. })` for it.
+    pub fn verify_replay(
+        &mut self,
+        recording: &InputRecording,
+        hasher: StateHasher,
+    ) -> Result<(), ReplayError> {
+        for (frame_index, frame) in recording.frames.iter().enumerate() {
+            let expected = frame
+                .hash
+                .ok_or(ReplayError::MissingHash { frame: frame_index })?;
+            self.tick(frame.input.clone(), frame.dt);
+            let actual = hasher(&self.world);
+            if actual != expected {
+                return Err(ReplayError::Diverged {
+                    frame: frame_index,
+                    expected,
+                    actual,
+                });
+            }
         }
+        Ok(())
     }
 }
 
@@ -0,0 +1,216 @@
+//! Render extraction.
+//!
+//! Reads renderable entities from the world.
+//! Produces sorted render commands for the frame.
+//! Splits them into per-camera views.
+//! The only place where ECS data meets render types.
+
+use crate::core::frame::ViewOutput;
+use crate::ecs::components::{Color, Position, Renderable, Sprite, Text};
+use crate::math::Vec2;
+use crate::particles::ParticleEmitter;
+use crate::render::{
+    Camera, Fonts, Materials, Meshes, RenderCommand, RenderView, TextureId, Tilemap, Transform,
+    UvRect, view_matrix,
+};
+use crate::{Entity, World};
+
+/// Builds the render commands for every entity with a [`Renderable`], [`Sprite`],
+/// [`Text`], [`Tilemap`] or [`ParticleEmitter`] component.
+///
+/// Text is only drawn while a [`Fonts`] resource holds its font. Commands are
+/// sorted by sort key. Equal keys keep renderables, sprites, text, tilemaps and
+/// particles in that order and each in id order, so the output is identical on
+/// every run.
+pub fn extract_render_commands(world: &World) -> Vec<RenderCommand> {
+    let mut entities = world.entities_with::<(Renderable,)>();
+    entities.sort_by_key(|entity| entity.id);
+
+    let mut commands: Vec<RenderCommand> = entities
+        .into_iter()
+        .map(|entity| {
+            let renderable = world.get_component::<Renderable>(entity).unwrap();
+
+            let transform = world_transform(world, entity);
+            let color = world
+                .get_component::<Color>(entity)
+                .map_or(Color::WHITE, |color| *color)
+                .to_array();
+
+            RenderCommand {
+                transform,
+                mesh: renderable.mesh,
+                color,
+                material: renderable.material,
+                texture: None,
+                uv: UvRect::FULL,
+                layer: renderable.layer,
+                sort_key: RenderCommand::sort_key_for(
+                    renderable.layer,
+                    renderable.material,
+                    renderable.mesh,
+                ),
+            }
+        })
+        .collect();
+
+    let mut sprites = world.entities_with::<(Sprite,)>();
+    sprites.sort_by_key(|entity| entity.id);
+    commands.extend(sprites.into_iter().map(|entity| {
+        let sprite = world.get_component::<Sprite>(entity).unwrap();
+        sprite_command(sprite, world_transform(world, entity))
+    }));
+
+    if let Ok(fonts) = world.get_resource::<Fonts>() {
+        let mut texts = world.entities_with::<(Text,)>();
+        texts.sort_by_key(|entity| entity.id);
+        for entity in texts {
+            let text = world.get_component::<Text>(entity).unwrap();
+            text_commands(text, fonts, world_transform(world, entity), &mut commands);
+        }
+    }
+
+    let mut tilemaps = world.entities_with::<(Tilemap,)>();
+    tilemaps.sort_by_key(|entity| entity.id);
+    for entity in tilemaps {
+        let tilemap = world.get_component::<Tilemap>(entity).unwrap();
+        commands.extend(tilemap.render_commands(&world_transform(world, entity)));
+    }
+
+    let mut emitters = world.entities_with::<(ParticleEmitter,)>();
+    emitters.sort_by_key(|entity| entity.id);
+    for entity in emitters {
+        let emitter = world.get_component::<ParticleEmitter>(entity).unwrap();
+        emitter.render_commands(&mut commands);
+    }
+
+    // Stable sort keeps id order among equal keys
+    commands.sort_by_key(|command| command.sort_key);
+    commands
+}
+
+/// Builds one view per active [`Camera`], ordered by camera order then entity id.
+///
+/// Each view keeps the `commands` on the camera's layers, sorted and batched
+/// for that camera, see [`RenderView::new`]. Materials come from the
+/// [`Materials`] resource, or are all the default one without it.
+pub fn extract_views(world: &World, commands: &[RenderCommand]) -> Vec<ViewOutput> {
+    let default_materials;
+    let materials = match world.get_resource::<Materials>() {
+        Ok(materials) => materials,
+        Err(_) => {
+            default_materials = Materials::new();
+            &default_materials
+        }
+    };
+
+    let mut cameras: Vec<(Entity, Camera)> = world
+        .entities_with::<(Camera,)>()
+        .into_iter()
+        .map(|entity| (entity, *world.get_component::<Camera>(entity).unwrap()))
+        .filter(|(_, camera)| camera.active)
+        .collect();
+    cameras.sort_by_key(|(entity, camera)| (camera.order, entity.id));
+
+    cameras
+        .into_iter()
+        .map(|(entity, camera)| ViewOutput {
+            camera: entity,
+            view: RenderView::new(
+                &camera,
+                view_matrix(&world_transform(world, entity)),
+                commands,
+                materials,
+            ),
+        })
+        .collect()
+}
+
+/// Places the unit quad so it covers the sprite's rectangle around its anchor.
+fn sprite_command(sprite: &Sprite, transform: Transform) -> RenderCommand {
+    let center = Vec2::new(
+        (0.5 - sprite.anchor.x) * sprite.size.x,
+        (0.5 - sprite.anchor.y) * sprite.size.y,
+    );
+    let mut command = quad_command(
+        transform,
+        center,
+        sprite.size,
+        sprite.texture,
+        sprite.layer,
+        sprite.tint,
+    );
+    command.material = sprite.material;
+    command.sort_key = RenderCommand::sort_key_for(sprite.layer, sprite.material, Meshes::QUAD);
+    command.uv = sprite.region.flipped(sprite.flip_x, sprite.flip_y);
+    command
+}
+
+/// Appends one textured quad per visible glyph of the text.
+fn text_commands(
+    text: &Text,
+    fonts: &Fonts,
+    transform: Transform,
+    commands: &mut Vec<RenderCommand>,
+) {
+    let Some(font) = fonts.get(text.font) else {
+        return;
+    };
+    // Layout works in font pixels with y down; world space has y up
+    let scale = text.size / font.line_height();
+    let layout = font.layout(
+        &text.content,
+        text.align,
+        text.max_width.map(|width| width / scale),
+    );
+    commands.extend(layout.glyphs.iter().map(|glyph| {
+        let center = glyph.min + glyph.size * 0.5;
+        let mut command = quad_command(
+            transform,
+            Vec2::new(center.x * scale, -center.y * scale),
+            glyph.size * scale,
+            font.texture(),
+            text.layer,
+            text.color,
+        );
+        command.uv = glyph.uv;
+        command
+    }));
+}
+
+/// Places the unit quad with its center at `center` and the given size, in the entity's local space.
+fn quad_command(
+    transform: Transform,
+    center: Vec2,
+    size: Vec2,
+    texture: TextureId,
+    layer: i32,
+    color: Color,
+) -> RenderCommand {
+    RenderCommand {
+        transform: Transform {
+            translation: transform.transform_point(center.extend(0.0)),
+            rotation: transform.rotation,
+            scale: transform.scale * size.extend(1.0),
+        },
+        mesh: Meshes::QUAD,
+        color: color.to_array(),
+        material: Materials::DEFAULT,
+        texture: Some(texture),
+        uv: UvRect::FULL,
+        layer,
+        sort_key: RenderCommand::sort_key_for(layer, Materials::DEFAULT, Meshes::QUAD),
+    }
+}
+
+/// The entity's [`Transform`] (or identity), with [`Position`] overriding the translation.
+pub(crate) fn world_transform(world: &World, entity: Entity) -> Transform {
+    let mut transform = world
+        .get_component::<Transform>(entity)
+        .copied()
+        .unwrap_or_default();
+    if let Ok(position) = world.get_component::<Position>(entity) {
+        transform.translation = position.0;
+    }
+    transform
+}
@@ -1,7 +1,23 @@
-use crate::render::RenderCommand;
+use crate::Entity;
+use crate::render::{DebugOverlay, FrameStats, RenderCommand, RenderView};
 
 /// Output from a single frame of engine execution.
 /// Contains all rendering commands to be processed.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct FrameOutput {
+    /// Commands in draw order (ascending sort key), regardless of camera.
     pub render_commands: Vec<RenderCommand>,
+    /// One view per active camera, in camera draw order.
+    pub views: Vec<ViewOutput>,
+    /// Draw work of all views together; zero unless a [`crate::render::Meshes`] resource exists.
+    pub stats: FrameStats,
+    /// Gizmos flushed from the [`crate::render::DebugDraw`] resource, drawn over every view.
+    pub debug: DebugOverlay,
+}
+
+/// The view produced by one camera entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewOutput {
+    pub camera: Entity,
+    pub view: RenderView,
 }
@@ -0,0 +1,174 @@
+//! Golden-image testing.
+//!
+//! Renders engine frames headlessly.
+//! Compares them against stored reference images.
+//! Writes diff images when they do not match.
+
+use std::path::{Path, PathBuf};
+
+use crate::Engine;
+use crate::input::Input;
+use crate::render::image::{load_image, save_image};
+use crate::render::{Framebuffer, ImageError, Materials, Meshes, SoftwareRenderer, Textures};
+
+/// Environment variable test harnesses read to decide whether to pass `update`
+/// to [`check_golden`]; the engine itself never reads it.
+pub const UPDATE_GOLDEN_ENV: &str = "PYREFRAME_UPDATE_GOLDEN";
+
+/// Error returned by [`check_golden`].
+#[derive(Debug, PartialEq)]
+pub enum GoldenError {
+    /// Reading or writing an image failed.
+    Image(ImageError),
+    /// No golden image existed; the rendered frame was written there for review.
+    Missing { written: PathBuf },
+    /// The golden image has different dimensions than the frame.
+    SizeMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    /// Pixels differ by more than the tolerance; a diff image was written.
+    Mismatch {
+        /// Number of pixels outside the tolerance.
+        mismatched: usize,
+        /// Largest per-channel difference found.
+        max_difference: u8,
+        /// Where the diff image was written.
+        diff: PathBuf,
+    },
+}
+
+/// The result of comparing two images of equal size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageDiff {
+    /// Number of pixels with a channel differing by more than the tolerance.
+    pub mismatched: usize,
+    /// Largest per-channel difference found.
+    pub max_difference: u8,
+    /// Visualization: matching pixels as dimmed grayscale, mismatches in red.
+    pub pixels: Vec<[u8; 4]>,
+}
+
+/// Compares two equally sized images channel by channel.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn diff_images(actual: &[[u8; 4]], expected: &[[u8; 4]], tolerance: u8) -> ImageDiff {
+    assert_eq!(actual.len(), expected.len(), "images must have equal size");
+
+    let mut mismatched = 0;
+    let mut max_difference = 0;
+    let pixels = actual
+        .iter()
+        .zip(expected)
+        .map(|(a, e)| {
+            let difference = (0..4).map(|i| a[i].abs_diff(e[i])).max().unwrap_or(0);
+            max_difference = max_difference.max(difference);
+            if difference > tolerance {
+                mismatched += 1;
+                [255, 0, 0, 255]
+            } else {
+                let luma = (e[0] as u32 * 3 + e[1] as u32 * 6 + e[2] as u32) / 10;
+                let dimmed = (luma / 3) as u8;
+                [dimmed, dimmed, dimmed, 255]
+            }
+        })
+        .collect();
+
+    ImageDiff {
+        mismatched,
+        max_difference,
+        pixels,
+    }
+}
+
+/// Ticks the engine `ticks` times with empty input and renders the last frame.
+///
+/// With zero ticks the renderer is only cleared.
+pub fn render_ticks<'a>(
+    engine: &mut Engine,
+    renderer: &'a mut SoftwareRenderer,
+    meshes: &Meshes,
+    textures: &Textures,
+    materials: &Materials,
+    ticks: usize,
+    dt: f32,
+) -> &'a Framebuffer {
+    let mut last = None;
+    for _ in 0..ticks {
+        last = Some(engine.tick(Input::default(), dt));
+    }
+    match last {
+        Some(output) => renderer.render(&output.render_commands, meshes, textures, materials),
+        None => {
+            renderer.clear();
+            renderer.framebuffer()
+        }
+    }
+}
+
+/// Compares a frame against the golden image at `path`.
+///
+/// Channels may differ by up to `tolerance`. On a mismatch the diff image is
+/// written to `<name>.diff.png` and the frame to `<name>.actual.png` beside the
+/// golden. A missing golden is created from the frame and reported as an error
+/// so it gets reviewed. With `update`, the golden is always overwritten and the
+/// check passes.
+pub fn check_golden(
+    framebuffer: &Framebuffer,
+    path: impl AsRef<Path>,
+    tolerance: u8,
+    update: bool,
+) -> Result<(), GoldenError> {
+    let path = path.as_ref();
+    let (width, height) = (framebuffer.width(), framebuffer.height());
+    let save = |target: &Path, pixels: &[[u8; 4]]| {
+        save_image(target, width, height, pixels).map_err(GoldenError::Image)
+    };
+
+    if update {
+        return save(path, framebuffer.pixels());
+    }
+
+    let expected = match load_image(path) {
+        Ok(expected) => expected,
+        Err(ImageError::Io(std::io::ErrorKind::NotFound)) => {
+            save(path, framebuffer.pixels())?;
+            return Err(GoldenError::Missing {
+                written: path.to_path_buf(),
+            });
+        }
+        Err(error) => return Err(GoldenError::Image(error)),
+    };
+
+    if (expected.width(), expected.height()) != (width, height) {
+        return Err(GoldenError::SizeMismatch {
+            expected: (expected.width(), expected.height()),
+            actual: (width, height),
+        });
+    }
+
+    let diff = diff_images(framebuffer.pixels(), expected.pixels(), tolerance);
+    if diff.mismatched == 0 {
+        return Ok(());
+    }
+
+    let diff_path = sibling(path, "diff");
+    save(&diff_path, &diff.pixels)?;
+    save(&sibling(path, "actual"), framebuffer.pixels())?;
+    Err(GoldenError::Mismatch {
+        mismatched: diff.mismatched,
+        max_difference: diff.max_difference,
+        diff: diff_path,
+    })
+}
+
+/// Returns `dir/<stem>.<suffix>.png` for a golden at `dir/<stem>.<ext>`.
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!("{stem}.{suffix}.png"))
+}
@@ -6,6 +6,9 @@
 //! High-level engine orchestration.
 
 pub mod engine;
+pub mod extract;
 pub mod frame;
+pub mod golden;
+pub mod replay;
 pub mod resources;
 pub mod schedule;
@@ -0,0 +1,471 @@
+//! Input recording and replay.
+//!
+//! Captures the per-tick input and delta time stream.
+//! Compact binary file format.
+//! World state hashing for divergence detection.
+
+use std::path::Path;
+
+use crate::World;
+use crate::ecs::components::{Color, Position, Velocity};
+use crate::input::{
+    Gamepad, GamepadAxis, GamepadButton, GamepadEvent, GamepadId, GamepadState, Input, Key,
+    KeyboardState, MouseButton, MouseButtons, MouseDelta, MousePosition, MouseState, MouseWheel,
+    TextEvent, TextInput,
+};
+use crate::time::Time;
+
+/// Magic bytes at the start of every recording file.
+const MAGIC: &[u8; 4] = b"PFRI";
+
+/// Current recording format version.
+const VERSION: u8 = 4;
+
+/// A function that reduces the world state to a hash.
+///
+/// Must only read state that is deterministic (no addresses, no `HashMap` order).
+pub type StateHasher = fn(&World) -> u64;
+
+/// Error returned when reading, writing or verifying an [`InputRecording`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// Reading or writing the recording file failed.
+    Io(std::io::ErrorKind),
+    /// The data does not start with the recording magic bytes.
+    InvalidFormat,
+    /// The recording was written by an unknown format version.
+    UnsupportedVersion(u8),
+    /// The data ended in the middle of a frame.
+    Truncated,
+    /// The data contains a value that does not decode (e.g. an unknown key index).
+    Corrupt,
+    /// Verification was requested but the frame has no stored hash.
+    MissingHash {
+        /// 0-based index of the frame.
+        frame: usize,
+    },
+    /// The replayed world state differs from the recorded one.
+    Diverged {
+        /// 0-based index of the first frame whose hash differs.
+        frame: usize,
+        expected: u64,
+        actual: u64,
+    },
+}
+
+/// A single recorded call to [`crate::Engine::tick`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedFrame {
+    pub input: Input,
+    pub dt: f32,
+    /// World hash taken after the frame's systems ran, if a hasher was set.
+    pub hash: Option<u64>,
+}
+
+/// An ordered stream of recorded frames.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputRecording {
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl InputRecording {
+    /// Creates an empty recording.
+    pub fn new() -> Self {
+        Self { frames: Vec::new() }
+    }
+
+    /// Encodes the recording into its binary file format.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        write_count(&mut out, self.frames.len());
+        for frame in &self.frames {
+            write_frame(&mut out, frame);
+        }
+        out
+    }
+
+    /// Decodes a recording produced by [`Self::encode`].
+    pub fn decode(bytes: &[u8]) -> Result<InputRecording, ReplayError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        if reader.take(4)? != MAGIC {
+            return Err(ReplayError::InvalidFormat);
+        }
+        let version = reader.u8()?;
+        if version != VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+
+        let count = reader.u32()? as usize;
+        let mut frames = Vec::new();
+        for _ in 0..count {
+            frames.push(read_frame(&mut reader)?);
+        }
+
+        if reader.pos != bytes.len() {
+            return Err(ReplayError::Corrupt);
+        }
+        Ok(InputRecording { frames })
+    }
+
+    /// Loads a recording file.
+    pub fn load(path: impl AsRef<Path>) -> Result<InputRecording, ReplayError> {
+        let bytes = std::fs::read(path).map_err(|e| ReplayError::Io(e.kind()))?;
+        Self::decode(&bytes)
+    }
+
+    /// Saves the recording to a file, overwriting it.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ReplayError> {
+        std::fs::write(path, self.encode()).map_err(|e| ReplayError::Io(e.kind()))
+    }
+}
+
+/// Hashes the alive entities, the built-in components and the [`Time`] resource.
+///
+/// Entities are visited in id order and floats are hashed by their bit patterns,
+/// so two worlds hash equal only if they are bit-for-bit identical in this state.
+pub fn default_state_hash(world: &World) -> u64 {
+    let mut hash = Fnv1a::new();
+
+    for entity in world.entities() {
+        hash.write_u64(entity.id as u64);
+        hash.write_u64(entity.generation as u64);
+
+        if let Ok(pos) = world.get_component::<Position>(entity) {
+            hash.write_u8(1);
+            hash.write_f32(pos.x);
+            hash.write_f32(pos.y);
+            hash.write_f32(pos.z);
+        }
+        if let Ok(vel) = world.get_component::<Velocity>(entity) {
+            hash.write_u8(2);
+            hash.write_f32(vel.x);
+            hash.write_f32(vel.y);
+            hash.write_f32(vel.z);
+        }
+        if let Ok(color) = world.get_component::<Color>(entity) {
+            hash.write_u8(3);
+            hash.write_bytes(&color.to_array());
+        }
+    }
+
+    if let Ok(time) = world.get_resource::<Time>() {
+        hash.write_f32(time.delta);
+        hash.write_u64(time.frame);
+    }
+
+    hash.finish()
+}
+
+/// 64-bit FNV-1a: small, stable across platforms and Rust versions.
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    pub(crate) fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub(crate) fn write_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    pub(crate) fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub(crate) fn write_f32(&mut self, value: f32) {
+        self.write_bytes(&value.to_bits().to_le_bytes());
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+fn write_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_f32(out: &mut Vec<u8>, value: f32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Writes the length of a list; `u32` so a busy frame never wraps around.
+fn write_count(out: &mut Vec<u8>, len: usize) {
+    write_u32(
+        out,
+        u32::try_from(len).expect("list too long for a recording"),
+    );
+}
+
+/// Writes a gamepad id as `u64`, so no id is truncated.
+fn write_gamepad_id(out: &mut Vec<u8>, id: GamepadId) {
+    out.extend_from_slice(&(id.0 as u64).to_le_bytes());
+}
+
+fn write_str(out: &mut Vec<u8>, value: &str) {
+    write_count(out, value.len());
+    out.extend_from_slice(value.as_bytes());
+}
+
+fn write_frame(out: &mut Vec<u8>, frame: &RecordedFrame) {
+    write_f32(out, frame.dt);
+    match frame.hash {
+        Some(hash) => {
+            out.push(1);
+            out.extend_from_slice(&hash.to_le_bytes());
+        }
+        None => out.push(0),
+    }
+
+    let keys = frame.input.keyboard.pressed_keys();
+    write_count(out, keys.len());
+    out.extend(keys.iter().map(|&key| key as u8));
+
+    let mouse = &frame.input.mouse;
+    for value in [
+        mouse.position.x,
+        mouse.position.y,
+        mouse.delta.x,
+        mouse.delta.y,
+        mouse.wheel.x,
+        mouse.wheel.y,
+    ] {
+        write_f32(out, value);
+    }
+    let buttons = MouseButton::ALL
+        .iter()
+        .enumerate()
+        .filter(|(_, button)| mouse.buttons.is_pressed(**button))
+        .fold(0u8, |bits, (i, _)| bits | (1 << i));
+    out.push(buttons);
+    out.push(mouse.inside_window as u8 | (mouse.has_position as u8) << 1);
+
+    let pads = &frame.input.gamepads;
+    let connected = pads.connected();
+    write_count(out, connected.len());
+    for id in connected {
+        let pad = pads.get(id).expect("connected gamepad must exist");
+        write_gamepad_id(out, id);
+        write_count(out, pad.pressed_buttons().len());
+        out.extend(pad.pressed_buttons().iter().map(|&b| b as u8));
+        for axis in GamepadAxis::ALL {
+            write_f32(out, pad.axis(axis));
+        }
+    }
+
+    write_count(out, pads.events().len());
+    for event in pads.events() {
+        match *event {
+            GamepadEvent::Connected(id) => {
+                out.push(0);
+                write_gamepad_id(out, id);
+            }
+            GamepadEvent::Disconnected(id) => {
+                out.push(1);
+                write_gamepad_id(out, id);
+            }
+            GamepadEvent::ButtonPressed(id, button) => {
+                out.push(2);
+                write_gamepad_id(out, id);
+                out.push(button as u8);
+            }
+            GamepadEvent::ButtonReleased(id, button) => {
+                out.push(3);
+                write_gamepad_id(out, id);
+                out.push(button as u8);
+            }
+            GamepadEvent::AxisMoved(id, axis, value) => {
+                out.push(4);
+                write_gamepad_id(out, id);
+                out.push(axis as u8);
+                write_f32(out, value);
+            }
+        }
+    }
+
+    let text = frame.input.text.events();
+    write_count(out, text.len());
+    for event in text {
+        match event {
+            TextEvent::Char(c) => {
+                out.push(0);
+                write_u32(out, *c as u32);
+            }
+            TextEvent::CompositionUpdate { text, cursor } => {
+                out.push(1);
+                write_str(out, text);
+                write_u32(out, *cursor as u32);
+            }
+            TextEvent::CompositionCommit(text) => {
+                out.push(2);
+                write_str(out, text);
+            }
+            TextEvent::CompositionCancel => out.push(3),
+        }
+    }
+}
+
+fn read_frame(reader: &mut Reader) -> Result<RecordedFrame, ReplayError> {
+    let dt = reader.f32()?;
+    let hash = match reader.u8()? {
+        0 => None,
+        1 => Some(reader.u64()?),
+        _ => return Err(ReplayError::Corrupt),
+    };
+
+    let key_count = reader.u32()?;
+    let mut keys = Vec::new();
+    for _ in 0..key_count {
+        keys.push(reader.index(&Key::ALL)?);
+    }
+
+    let mut floats = [0.0f32; 6];
+    for value in &mut floats {
+        *value = reader.f32()?;
+    }
+    let bits = reader.u8()?;
+    let mut buttons = MouseButtons::default();
+    for (i, button) in MouseButton::ALL.iter().enumerate() {
+        buttons.set(*button, bits & (1 << i) != 0);
+    }
+    let flags = reader.u8()?;
+    let mouse = MouseState {
+        position: MousePosition {
+            x: floats[0],
+            y: floats[1],
+        },
+        delta: MouseDelta {
+            x: floats[2],
+            y: floats[3],
+        },
+        wheel: MouseWheel {
+            x: floats[4],
+            y: floats[5],
+        },
+        buttons,
+        inside_window: flags & 1 != 0,
+        has_position: flags & 2 != 0,
+    };
+
+    let pad_count = reader.u32()?;
+    let mut gamepads = Vec::new();
+    for _ in 0..pad_count {
+        let id = reader.gamepad_id()?;
+        let pressed_count = reader.u32()?;
+        let mut pressed = Vec::new();
+        for _ in 0..pressed_count {
+            pressed.push(reader.index(&GamepadButton::ALL)?);
+        }
+        let mut axes = [0.0f32; 6];
+        for value in &mut axes {
+            *value = reader.f32()?;
+        }
+        gamepads.push((id, Gamepad::from_parts(pressed, axes)));
+    }
+
+    let event_count = reader.u32()?;
+    let mut events = Vec::new();
+    for _ in 0..event_count {
+        let tag = reader.u8()?;
+        let id = reader.gamepad_id()?;
+        events.push(match tag {
+            0 => GamepadEvent::Connected(id),
+            1 => GamepadEvent::Disconnected(id),
+            2 => GamepadEvent::ButtonPressed(id, reader.index(&GamepadButton::ALL)?),
+            3 => GamepadEvent::ButtonReleased(id, reader.index(&GamepadButton::ALL)?),
+            4 => GamepadEvent::AxisMoved(id, reader.index(&GamepadAxis::ALL)?, reader.f32()?),
+            _ => return Err(ReplayError::Corrupt),
+        });
+    }
+
+    let text_count = reader.u32()?;
+    let mut text = TextInput::new();
+    for _ in 0..text_count {
+        text.push(match reader.u8()? {
+            0 => TextEvent::Char(char::from_u32(reader.u32()?).ok_or(ReplayError::Corrupt)?),
+            1 => TextEvent::CompositionUpdate {
+                text: reader.string()?,
+                cursor: reader.u32()? as usize,
+            },
+            2 => TextEvent::CompositionCommit(reader.string()?),
+            3 => TextEvent::CompositionCancel,
+            _ => return Err(ReplayError::Corrupt),
+        });
+    }
+
+    Ok(RecordedFrame {
+        input: Input {
+            keyboard: KeyboardState::new(keys),
+            mouse,
+            gamepads: GamepadState::from_parts(gamepads, events),
+            text,
+        },
+        dt,
+        hash,
+    })
+}
+
+/// Sequential little-endian reader over a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8], ReplayError> {
+        let end = self.pos + len;
+        let slice = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(ReplayError::Truncated)?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, ReplayError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, ReplayError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn u64(&mut self) -> Result<u64, ReplayError> {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    /// Reads a gamepad id; one too large for this platform's `usize` is corrupt.
+    fn gamepad_id(&mut self) -> Result<GamepadId, ReplayError> {
+        let id = usize::try_from(self.u64()?).map_err(|_| ReplayError::Corrupt)?;
+        Ok(GamepadId(id))
+    }
+
+    fn f32(&mut self) -> Result<f32, ReplayError> {
+        Ok(f32::from_bits(self.u32()?))
+    }
+
+    /// Reads a length-prefixed UTF-8 string.
+    fn string(&mut self) -> Result<String, ReplayError> {
+        let len = self.u32()? as usize;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| ReplayError::Corrupt)
+    }
+
+    /// Reads a one-byte index into a table of enum variants.
+    fn index<T: Copy>(&mut self, table: &[T]) -> Result<T, ReplayError> {
+        let i = self.u8()? as usize;
+        table.get(i).copied().ok_or(ReplayError::Corrupt)
+    }
+}
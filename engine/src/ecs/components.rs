@@ -1,21 +1,79 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::math::{Vec2, Vec3};
+use crate::render::{
+    FontId, LinearColor, MaterialId, Materials, MeshId, TextAlign, TextureId, UvRect,
+};
+
 /// Position component: Represents an entity's 3D location.
 /// Used for spatial queries, rendering, and collision.
 /// For 2D compatibility, set z to 0.0.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Position {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+///
+/// Dereferences to [`crate::math::Vec3`], so `pos.x` and vector arithmetic work directly.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Position(pub Vec3);
+
+impl Position {
+    /// Creates a position from its coordinates.
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(Vec3::new(x, y, z))
+    }
+}
+
+impl Deref for Position {
+    type Target = Vec3;
+
+    fn deref(&self) -> &Vec3 {
+        &self.0
+    }
+}
+
+impl DerefMut for Position {
+    fn deref_mut(&mut self) -> &mut Vec3 {
+        &mut self.0
+    }
+}
+
+impl From<Vec3> for Position {
+    fn from(value: Vec3) -> Self {
+        Self(value)
+    }
 }
 
 /// Velocity component: Represents an entity's movement speed and direction in 3D.
 /// Used for physics/movement systems to update position over time.
-/// For 2D compatibility, set dz to 0.0.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Velocity {
-    pub dx: f32, // Change in x per frame/time unit
-    pub dy: f32, // Change in y per frame/time unit
-    pub dz: f32, // Change in z per frame/time unit
+/// For 2D compatibility, set z to 0.0.
+///
+/// Each axis holds the change in position per frame/time unit.
+/// Dereferences to [`crate::math::Vec3`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Velocity(pub Vec3);
+
+impl Velocity {
+    /// Creates a velocity from its per-axis rates.
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(Vec3::new(x, y, z))
+    }
+}
+
+impl Deref for Velocity {
+    type Target = Vec3;
+
+    fn deref(&self) -> &Vec3 {
+        &self.0
+    }
+}
+
+impl DerefMut for Velocity {
+    fn deref_mut(&mut self) -> &mut Vec3 {
+        &mut self.0
+    }
+}
+
+impl From<Vec3> for Velocity {
+    fn from(value: Vec3) -> Self {
+        Self(value)
+    }
 }
 
 /// Color component: Represents an entity's visual color for rendering.
@@ -27,3 +85,240 @@ pub struct Color {
     pub b: u8, // Blue (0-255)
     pub a: u8, // Alpha (0-255, for transparency)
 }
+
+impl Color {
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(128, 128, 128);
+    pub const RED: Color = Color::rgb(255, 0, 0);
+    pub const GREEN: Color = Color::rgb(0, 255, 0);
+    pub const BLUE: Color = Color::rgb(0, 0, 255);
+    pub const YELLOW: Color = Color::rgb(255, 255, 0);
+    pub const CYAN: Color = Color::rgb(0, 255, 255);
+    pub const MAGENTA: Color = Color::rgb(255, 0, 255);
+    pub const ORANGE: Color = Color::rgb(255, 165, 0);
+    pub const PURPLE: Color = Color::rgb(128, 0, 128);
+
+    /// Creates a color from sRGB channels and alpha.
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a fully opaque color from sRGB channels.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, 255)
+    }
+
+    /// Returns the color with another alpha.
+    pub const fn with_alpha(mut self, a: u8) -> Self {
+        self.a = a;
+        self
+    }
+
+    /// Returns the channels as `[r, g, b, a]`, the layout render commands use.
+    pub const fn to_array(self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Creates a color from `[r, g, b, a]`.
+    pub const fn from_array([r, g, b, a]: [u8; 4]) -> Self {
+        Self::rgba(r, g, b, a)
+    }
+
+    /// Decodes the color into linear light, see [`LinearColor`].
+    pub fn to_linear(self) -> LinearColor {
+        LinearColor::from(self)
+    }
+}
+
+impl Default for Color {
+    /// Returns [`Color::WHITE`], which leaves whatever it tints unchanged.
+    fn default() -> Self {
+        Color::WHITE
+    }
+}
+
+impl From<[u8; 4]> for Color {
+    fn from(value: [u8; 4]) -> Self {
+        Color::from_array(value)
+    }
+}
+
+impl From<Color> for [u8; 4] {
+    fn from(value: Color) -> Self {
+        value.to_array()
+    }
+}
+
+/// Renderable component: Marks an entity to be drawn with the given mesh.
+/// Picked up by render extraction together with its transform and color.
+///
+/// The transform comes from a [`crate::render::Transform`] component if present;
+/// a [`Position`] overrides its translation. A missing [`Color`] draws white.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Renderable {
+    pub mesh: MeshId,
+    /// How the mesh is drawn; the entity's color tints it.
+    pub material: MaterialId,
+    /// Draw layer; lower layers are drawn first.
+    pub layer: i32,
+}
+
+impl Renderable {
+    /// Creates a renderable with the default material on layer 0.
+    pub const fn new(mesh: MeshId) -> Self {
+        Self {
+            mesh,
+            material: Materials::DEFAULT,
+            layer: 0,
+        }
+    }
+
+    /// Returns the renderable drawn with another material.
+    pub const fn with_material(mut self, material: MaterialId) -> Self {
+        self.material = material;
+        self
+    }
+
+    /// Returns the renderable moved to another layer.
+    pub const fn with_layer(mut self, layer: i32) -> Self {
+        self.layer = layer;
+        self
+    }
+}
+
+/// Sprite component: Draws a textured rectangle of `size` world units.
+/// Picked up by render extraction like a [`Renderable`], drawn with the built-in
+/// quad mesh [`crate::render::Meshes::QUAD`].
+///
+/// The `anchor` is the point of the rectangle placed at the entity's position,
+/// from (0, 0) at the bottom-left to (1, 1) at the top-right.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sprite {
+    pub texture: TextureId,
+    /// Part of the texture shown, e.g. a region from a [`crate::render::TextureAtlas`].
+    pub region: UvRect,
+    pub size: Vec2,
+    pub anchor: Vec2,
+    pub flip_x: bool,
+    pub flip_y: bool,
+    /// Multiplied with the texture; its alpha fades the whole sprite.
+    pub tint: Color,
+    /// How the sprite is drawn; its texture replaces the material's.
+    pub material: MaterialId,
+    /// Draw layer; lower layers are drawn first.
+    pub layer: i32,
+}
+
+impl Sprite {
+    /// Creates a centered, untinted sprite showing the whole texture on layer 0.
+    pub const fn new(texture: TextureId, size: Vec2) -> Self {
+        Self {
+            texture,
+            region: UvRect::FULL,
+            size,
+            anchor: Vec2::new(0.5, 0.5),
+            flip_x: false,
+            flip_y: false,
+            tint: Color::WHITE,
+            material: Materials::DEFAULT,
+            layer: 0,
+        }
+    }
+
+    /// Returns the sprite showing another part of the texture.
+    pub const fn with_region(mut self, region: UvRect) -> Self {
+        self.region = region;
+        self
+    }
+
+    /// Returns the sprite anchored at another point.
+    pub const fn with_anchor(mut self, anchor: Vec2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Returns the sprite mirrored horizontally and/or vertically.
+    pub const fn with_flip(mut self, flip_x: bool, flip_y: bool) -> Self {
+        self.flip_x = flip_x;
+        self.flip_y = flip_y;
+        self
+    }
+
+    /// Returns the sprite with another tint.
+    pub const fn with_tint(mut self, tint: Color) -> Self {
+        self.tint = tint;
+        self
+    }
+
+    /// Returns the sprite drawn with another material, e.g. to blend it additively.
+    pub const fn with_material(mut self, material: MaterialId) -> Self {
+        self.material = material;
+        self
+    }
+
+    /// Returns the sprite moved to another layer.
+    pub const fn with_layer(mut self, layer: i32) -> Self {
+        self.layer = layer;
+        self
+    }
+}
+
+/// Text component: Draws a string with a font from the [`crate::render::Fonts`] resource.
+/// Each visible character becomes a quad like a [`Sprite`].
+///
+/// The entity's position is the top of the first line; `align` decides
+/// whether lines start, are centered or end there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Text {
+    pub content: String,
+    pub font: FontId,
+    /// Height of one line in world units; glyphs keep the font's proportions.
+    pub size: f32,
+    pub color: Color,
+    pub align: TextAlign,
+    /// Width in world units beyond which lines wrap between words.
+    pub max_width: Option<f32>,
+    /// Draw layer; lower layers are drawn first.
+    pub layer: i32,
+}
+
+impl Text {
+    /// Creates white, left-aligned, unwrapped text on layer 0.
+    pub fn new(content: impl Into<String>, font: FontId, size: f32) -> Self {
+        Self {
+            content: content.into(),
+            font,
+            size,
+            color: Color::WHITE,
+            align: TextAlign::Left,
+            max_width: None,
+            layer: 0,
+        }
+    }
+
+    /// Returns the text in another color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Returns the text with another alignment.
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Returns the text wrapped to lines at most `max_width` world units wide.
+    pub fn with_max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Returns the text moved to another layer.
+    pub fn with_layer(mut self, layer: i32) -> Self {
+        self.layer = layer;
+        self
+    }
+}
@@ -4,6 +4,8 @@
 //! Unique identifiers for game objects.
 //! Supports generational indexing for safety.
 
+use crate::math::{Random, Rng};
+
 /// A handle to an entity in the ECS world.
 /// Uses generational indexing to prevent stale references.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -17,4 +19,9 @@ impl Entity {
     pub fn new(id: usize, generation: usize) -> Self {
         Self { id, generation }
     }
+
+    /// Returns a fresh generator for this entity, see [`Random::keyed`].
+    pub fn rng(&self, random: &Random, salt: u64) -> Rng {
+        random.keyed(&[self.id as u64, self.generation as u64, salt])
+    }
 }
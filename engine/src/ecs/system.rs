@@ -7,8 +7,13 @@
 
 use crate::{
     World,
+    assets::Assets,
+    core::extract::world_transform,
     ecs::components::{Position, Velocity},
+    input::{ActionMap, Input, KeyboardState, TextField, TextFocus},
+    particles::ParticleEmitter,
     time::{FrameDelta, Time},
+    tween::{self, Tween, TweenEvent, TweenEvents},
 };
 
 /// A system is a function that operates on the ECS [`crate::ecs::world::World`].
@@ -20,7 +25,7 @@ pub type System = fn(&mut World);
 /// A basic movement system that updates [`crate::ecs::components::Position`] based on [`crate::ecs::components::Velocity`].
 ///
 /// Iterates over all entities with both `Position` and `Velocity` components,
-/// adding velocity to position for each axis. This is a simple example of a system
+/// adding velocity to position. This is a simple example of a system
 /// that operates on a subset of entities and mutates their state.
 ///
 /// # Panics
@@ -33,9 +38,7 @@ pub fn movement_system(world: &mut World) {
         let vel = *world.get_component::<Velocity>(entity).unwrap();
         let pos = world.get_component_mut::<Position>(entity).unwrap();
 
-        pos.x += vel.dx;
-        pos.y += vel.dy;
-        pos.z += vel.dz;
+        pos.0 += vel.0;
     }
 }
 
@@ -57,3 +60,147 @@ pub fn time_system(world: &mut World) {
 
     time.advance(dt);
 }
+
+/// A system that updates the [`crate::input::ActionMap`] resource from the current [`crate::input::Input`].
+///
+/// This system should run once per frame, after the input is published and
+/// before any gameplay system that reads actions. Keyboard bindings are ignored
+/// while a [`crate::input::TextFocus`] resource has a focused entity.
+///
+/// # Panics
+///
+/// Panics if either `Input` or `ActionMap` resources are missing from the world.
+pub fn action_system(world: &mut World) {
+    let mut input = world
+        .get_resource::<Input>()
+        .expect("Input must exist")
+        .clone();
+
+    // A focused text field swallows the keyboard so typing does not trigger actions
+    if world
+        .get_resource::<TextFocus>()
+        .is_ok_and(|focus| focus.is_active())
+    {
+        input.keyboard = KeyboardState::default();
+    }
+
+    let actions = world
+        .get_resource_mut::<ActionMap>()
+        .expect("ActionMap must exist");
+
+    actions.update(&input);
+}
+
+/// A system that feeds this frame's [`crate::input::TextInput`] into the focused [`crate::input::TextField`].
+///
+/// Clears [`crate::input::TextField::submitted`] on every other field first, so a
+/// submit does not outlive its frame when the field loses focus. Text goes nowhere
+/// if there is no [`crate::input::TextFocus`] resource, nothing is focused, or the
+/// focused entity has no `TextField`.
+///
+/// # Panics
+///
+/// Panics if the `Input` resource is missing from the world.
+pub fn text_input_system(world: &mut World) {
+    for entity in world.entities_with::<(TextField,)>() {
+        world
+            .get_component_mut::<TextField>(entity)
+            .unwrap()
+            .submitted = false;
+    }
+
+    let Some(entity) = world
+        .get_resource::<TextFocus>()
+        .ok()
+        .and_then(|focus| focus.focused)
+    else {
+        return;
+    };
+
+    let text = world
+        .get_resource::<Input>()
+        .expect("Input must exist")
+        .text
+        .clone();
+
+    if let Ok(field) = world.get_component_mut::<TextField>(entity) {
+        field.apply(&text);
+    }
+}
+
+/// A system that advances every [`crate::tween::Tween`] by [`crate::time::Time::delta`]
+/// and writes the animated values into their components.
+///
+/// Should run after [`time_system`]. Finished tweens stay on their entity but no
+/// longer change it. Loop and completion events are published in the
+/// [`crate::tween::TweenEvents`] resource, which is created on first run and
+/// cleared at the start of every run.
+///
+/// # Panics
+///
+/// Panics if the `Time` resource is missing from the world.
+pub fn tween_system(world: &mut World) {
+    let dt = world.get_resource::<Time>().expect("Time must exist").delta;
+
+    let mut events = world
+        .get_resource_mut::<TweenEvents>()
+        .map(std::mem::take)
+        .unwrap_or_default();
+    events.clear();
+
+    let mut entities = world.entities_with::<(Tween,)>();
+    // Sorted so events come out in the same order on every run
+    entities.sort_by_key(|entity| entity.id);
+
+    for entity in entities {
+        let tween = world.get_component_mut::<Tween>(entity).unwrap();
+        if tween.is_finished() {
+            continue;
+        }
+
+        let step = tween.advance(dt);
+        let tween = *tween;
+        if !tween.is_delayed() {
+            tween::apply(world, entity, &tween);
+        }
+
+        for _ in 0..step.looped {
+            events.push(TweenEvent::Looped(entity));
+        }
+        if step.completed {
+            events.push(TweenEvent::Completed(entity));
+        }
+    }
+
+    world.insert_resource(events);
+}
+
+/// A system that advances every [`crate::particles::ParticleEmitter`] by
+/// [`crate::time::Time::delta`], spawning around its entity's position.
+///
+/// Should run after [`time_system`] and after anything that moves emitters, so
+/// new particles start where the emitter is drawn this frame.
+///
+/// # Panics
+///
+/// Panics if the `Time` resource is missing from the world.
+pub fn particle_system(world: &mut World) {
+    let dt = world.get_resource::<Time>().expect("Time must exist").delta;
+
+    for entity in world.entities_with::<(ParticleEmitter,)>() {
+        let origin = world_transform(world, entity).translation;
+        let emitter = world.get_component_mut::<ParticleEmitter>(entity).unwrap();
+        emitter.update(dt, origin);
+    }
+}
+
+/// A system that unloads every asset in the [`crate::assets::Assets<T>`] resource
+/// that no strong handle refers to anymore.
+///
+/// Register it once per asset type, e.g. `asset_system::<Texture>`. Does nothing
+/// if the resource is missing.
+pub fn asset_system<T: 'static>(world: &mut World) {
+    if let Ok(assets) = world.get_resource_mut::<Assets<T>>() {
+        assets.unload_unused();
+    }
+}
@@ -94,6 +94,15 @@ impl World {
         self.components.len()
     }
 
+    /// Returns all alive entities, sorted by id.
+    ///
+    /// The order is stable across runs, unlike the underlying storage.
+    pub fn entities(&self) -> Vec<Entity> {
+        let mut entities: Vec<Entity> = self.components.keys().copied().collect();
+        entities.sort_by_key(|entity| entity.id);
+        entities
+    }
+
     /// Registers a system to be run later via [`Self::run_systems`].
     pub fn add_system(&mut self, system: System) {
         self.systems.push(system);
@@ -0,0 +1,560 @@
+//! Action mapping.
+//!
+//! Named actions bound to physical inputs.
+//! Digital buttons and analog axes with deadzones.
+//! Rebindable through a plain-text config file.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use super::{ActionMapError, GamepadAxis, GamepadButton, Input, Key, KeyboardState, MouseButton};
+
+/// A modifier used in key chords.
+///
+/// Matches either the left or the right physical key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modifier {
+    Ctrl,
+    Shift,
+    Alt,
+}
+
+impl Modifier {
+    /// Returns the left and right keys that satisfy this modifier.
+    pub fn keys(self) -> [Key; 2] {
+        match self {
+            Modifier::Ctrl => [Key::LeftControl, Key::RightControl],
+            Modifier::Shift => [Key::LeftShift, Key::RightShift],
+            Modifier::Alt => [Key::LeftAlt, Key::RightAlt],
+        }
+    }
+
+    /// Checks if either key of this modifier is pressed.
+    pub fn is_held(self, keyboard: &KeyboardState) -> bool {
+        self.keys().iter().any(|&key| keyboard.is_pressed(key))
+    }
+
+    /// Returns the name used in binding config files.
+    pub fn name(self) -> &'static str {
+        match self {
+            Modifier::Ctrl => "Ctrl",
+            Modifier::Shift => "Shift",
+            Modifier::Alt => "Alt",
+        }
+    }
+
+    /// Looks up a modifier by the name returned from [`Modifier::name`].
+    pub fn from_name(name: &str) -> Option<Modifier> {
+        match name {
+            "Ctrl" => Some(Modifier::Ctrl),
+            "Shift" => Some(Modifier::Shift),
+            "Alt" => Some(Modifier::Alt),
+            _ => None,
+        }
+    }
+}
+
+/// A physical input that is either on or off.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ButtonBinding {
+    /// A single keyboard key.
+    Key(Key),
+    /// A key pressed while all modifiers are held (e.g. Ctrl+S).
+    ///
+    /// While a chord is active, plain [`ButtonBinding::Key`] bindings on the
+    /// same key are suppressed so Ctrl+S does not also trigger "S".
+    Chord { modifiers: Vec<Modifier>, key: Key },
+    /// A mouse button.
+    Mouse(MouseButton),
+    /// The vertical wheel scrolled up this frame.
+    WheelUp,
+    /// The vertical wheel scrolled down this frame.
+    WheelDown,
+    /// A button on any connected gamepad.
+    Gamepad(GamepadButton),
+}
+
+impl ButtonBinding {
+    /// Returns the token used for this binding in config files.
+    pub fn to_token(&self) -> String {
+        match self {
+            ButtonBinding::Key(key) => key.name().to_string(),
+            ButtonBinding::Chord { modifiers, key } => {
+                let mut parts: Vec<&str> = modifiers.iter().map(|m| m.name()).collect();
+                parts.push(key.name());
+                parts.join("+")
+            }
+            ButtonBinding::Mouse(button) => format!("Mouse{}", button.name()),
+            ButtonBinding::WheelUp => "WheelUp".to_string(),
+            ButtonBinding::WheelDown => "WheelDown".to_string(),
+            ButtonBinding::Gamepad(button) => format!("Pad{}", button.name()),
+        }
+    }
+
+    /// Parses a token produced by [`ButtonBinding::to_token`].
+    pub fn from_token(token: &str) -> Option<ButtonBinding> {
+        if token.contains('+') {
+            let mut parts: Vec<&str> = token.split('+').collect();
+            let key = Key::from_name(parts.pop()?)?;
+            let modifiers = parts
+                .into_iter()
+                .map(Modifier::from_name)
+                .collect::<Option<Vec<_>>>()?;
+            return Some(ButtonBinding::Chord { modifiers, key });
+        }
+
+        match token {
+            "WheelUp" => Some(ButtonBinding::WheelUp),
+            "WheelDown" => Some(ButtonBinding::WheelDown),
+            _ => {
+                if let Some(name) = token.strip_prefix("Mouse") {
+                    MouseButton::from_name(name).map(ButtonBinding::Mouse)
+                } else if let Some(name) = token.strip_prefix("Pad") {
+                    GamepadButton::from_name(name).map(ButtonBinding::Gamepad)
+                } else {
+                    Key::from_name(token).map(ButtonBinding::Key)
+                }
+            }
+        }
+    }
+
+    /// Checks if this binding is active for the given input.
+    ///
+    /// `consumed` holds keys claimed by active chords this frame.
+    fn is_active(&self, input: &Input, consumed: &HashSet<Key>) -> bool {
+        match self {
+            ButtonBinding::Key(key) => input.keyboard.is_pressed(*key) && !consumed.contains(key),
+            ButtonBinding::Chord { modifiers, key } => chord_active(modifiers, *key, input),
+            ButtonBinding::Mouse(button) => input.mouse.buttons.is_pressed(*button),
+            ButtonBinding::WheelUp => input.mouse.wheel.y > 0.0,
+            ButtonBinding::WheelDown => input.mouse.wheel.y < 0.0,
+            ButtonBinding::Gamepad(button) => input.gamepads.any_pressed(*button),
+        }
+    }
+}
+
+/// A physical input that produces a continuous value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnalogSource {
+    /// Horizontal wheel movement this frame.
+    WheelX,
+    /// Vertical wheel movement this frame.
+    WheelY,
+    /// An axis on any connected gamepad; the largest deflection wins.
+    Gamepad(GamepadAxis),
+}
+
+impl AnalogSource {
+    /// Returns the token used for this source in config files.
+    pub fn to_token(self) -> String {
+        match self {
+            AnalogSource::WheelX => "WheelX".to_string(),
+            AnalogSource::WheelY => "WheelY".to_string(),
+            AnalogSource::Gamepad(axis) => format!("Pad{}", axis.name()),
+        }
+    }
+
+    /// Parses a token produced by [`AnalogSource::to_token`].
+    pub fn from_token(token: &str) -> Option<AnalogSource> {
+        match token {
+            "WheelX" => Some(AnalogSource::WheelX),
+            "WheelY" => Some(AnalogSource::WheelY),
+            _ => GamepadAxis::from_name(token.strip_prefix("Pad")?).map(AnalogSource::Gamepad),
+        }
+    }
+
+    /// Reads the raw value of this source from the input.
+    fn read(self, input: &Input) -> f32 {
+        match self {
+            AnalogSource::WheelX => input.mouse.wheel.x,
+            AnalogSource::WheelY => input.mouse.wheel.y,
+            AnalogSource::Gamepad(axis) => input.gamepads.any_axis(axis),
+        }
+    }
+}
+
+/// A binding that contributes to an axis action.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AxisBinding {
+    /// Two buttons mapped to -1.0 and +1.0 (e.g. A/D).
+    Buttons {
+        negative: ButtonBinding,
+        positive: ButtonBinding,
+    },
+    /// An analog source, filtered through the action's deadzone.
+    Analog(AnalogSource),
+}
+
+impl AxisBinding {
+    /// Returns the token used for this binding in config files.
+    ///
+    /// Button pairs are written as `negative/positive`.
+    pub fn to_token(&self) -> String {
+        match self {
+            AxisBinding::Buttons { negative, positive } => {
+                format!("{}/{}", negative.to_token(), positive.to_token())
+            }
+            AxisBinding::Analog(source) => source.to_token(),
+        }
+    }
+
+    /// Parses a token produced by [`AxisBinding::to_token`].
+    pub fn from_token(token: &str) -> Option<AxisBinding> {
+        match token.split_once('/') {
+            Some((negative, positive)) => Some(AxisBinding::Buttons {
+                negative: ButtonBinding::from_token(negative)?,
+                positive: ButtonBinding::from_token(positive)?,
+            }),
+            None => AnalogSource::from_token(token).map(AxisBinding::Analog),
+        }
+    }
+}
+
+/// The bindings of a single action, which determine its kind.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionBindings {
+    /// A digital action, pressed while any binding is active.
+    Button(Vec<ButtonBinding>),
+    /// An analog action in the range -1.0..=1.0.
+    Axis(Vec<AxisBinding>),
+}
+
+/// A named action and its state for the current frame.
+#[derive(Debug, Clone, PartialEq)]
+struct Action {
+    bindings: ActionBindings,
+    deadzone: f32,
+    value: f32,
+    previous_value: f32,
+}
+
+impl Action {
+    fn new(bindings: ActionBindings) -> Self {
+        Self {
+            bindings,
+            deadzone: 0.0,
+            value: 0.0,
+            previous_value: 0.0,
+        }
+    }
+}
+
+/// Maps named actions ("jump", "move_x") to physical inputs.
+///
+/// Call [`ActionMap::update`] once per frame with the frame's [`Input`]
+/// (or run [`crate::ecs::system::action_system`]) and then query actions by name.
+/// Actions are kept sorted by name so saved configs are deterministic.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ActionMap {
+    actions: BTreeMap<String, Action>,
+}
+
+impl ActionMap {
+    /// Creates an empty action map.
+    pub fn new() -> Self {
+        Self {
+            actions: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a binding to a button action, creating the action if needed.
+    ///
+    /// Returns `Err(ActionMapError::KindMismatch)` if `name` is an axis action.
+    pub fn bind_button(
+        &mut self,
+        name: &str,
+        binding: ButtonBinding,
+    ) -> Result<(), ActionMapError> {
+        let action = self
+            .actions
+            .entry(name.to_string())
+            .or_insert_with(|| Action::new(ActionBindings::Button(Vec::new())));
+        match &mut action.bindings {
+            ActionBindings::Button(bindings) => {
+                bindings.push(binding);
+                Ok(())
+            }
+            ActionBindings::Axis(_) => Err(ActionMapError::KindMismatch),
+        }
+    }
+
+    /// Adds a binding to an axis action, creating the action if needed.
+    ///
+    /// Returns `Err(ActionMapError::KindMismatch)` if `name` is a button action.
+    pub fn bind_axis(&mut self, name: &str, binding: AxisBinding) -> Result<(), ActionMapError> {
+        let action = self
+            .actions
+            .entry(name.to_string())
+            .or_insert_with(|| Action::new(ActionBindings::Axis(Vec::new())));
+        match &mut action.bindings {
+            ActionBindings::Axis(bindings) => {
+                bindings.push(binding);
+                Ok(())
+            }
+            ActionBindings::Button(_) => Err(ActionMapError::KindMismatch),
+        }
+    }
+
+    /// Replaces all bindings of an action, keeping its state and deadzone.
+    ///
+    /// Creates the action if it does not exist. Used for player rebinding.
+    pub fn set_bindings(&mut self, name: &str, bindings: ActionBindings) {
+        match self.actions.get_mut(name) {
+            Some(action) => action.bindings = bindings,
+            None => {
+                self.actions.insert(name.to_string(), Action::new(bindings));
+            }
+        }
+    }
+
+    /// Returns the bindings of an action, if it exists.
+    pub fn bindings(&self, name: &str) -> Option<&ActionBindings> {
+        self.actions.get(name).map(|action| &action.bindings)
+    }
+
+    /// Removes an action and its bindings. Returns `true` if it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.actions.remove(name).is_some()
+    }
+
+    /// Returns the names of all actions in sorted order.
+    pub fn action_names(&self) -> Vec<&str> {
+        self.actions.keys().map(String::as_str).collect()
+    }
+
+    /// Sets the deadzone applied to analog bindings of an action.
+    ///
+    /// Values with a magnitude below `deadzone` read as zero; values above it
+    /// are rescaled so the output still spans the full range.
+    pub fn set_deadzone(&mut self, name: &str, deadzone: f32) -> Result<(), ActionMapError> {
+        let action = self
+            .actions
+            .get_mut(name)
+            .ok_or(ActionMapError::UnknownAction)?;
+        action.deadzone = deadzone.clamp(0.0, 0.99);
+        Ok(())
+    }
+
+    /// Returns the deadzone of an action, if it exists.
+    pub fn deadzone(&self, name: &str) -> Option<f32> {
+        self.actions.get(name).map(|action| action.deadzone)
+    }
+
+    /// Updates every action from this frame's input.
+    ///
+    /// The previous frame's values are kept for [`Self::just_pressed`] and [`Self::just_released`].
+    pub fn update(&mut self, input: &Input) {
+        let consumed = self.consumed_keys(input);
+
+        for action in self.actions.values_mut() {
+            action.previous_value = action.value;
+            action.value = match &action.bindings {
+                ActionBindings::Button(bindings) => {
+                    if bindings.iter().any(|b| b.is_active(input, &consumed)) {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                }
+                ActionBindings::Axis(bindings) => {
+                    let sum: f32 = bindings
+                        .iter()
+                        .map(|binding| match binding {
+                            AxisBinding::Buttons { negative, positive } => {
+                                let neg = negative.is_active(input, &consumed) as i32;
+                                let pos = positive.is_active(input, &consumed) as i32;
+                                (pos - neg) as f32
+                            }
+                            AxisBinding::Analog(source) => {
+                                apply_deadzone(source.read(input), action.deadzone)
+                            }
+                        })
+                        .sum();
+                    sum.clamp(-1.0, 1.0)
+                }
+            };
+        }
+    }
+
+    /// Returns `true` while the action is active (non-zero value).
+    pub fn pressed(&self, name: &str) -> bool {
+        self.actions.get(name).is_some_and(|a| a.value != 0.0)
+    }
+
+    /// Returns `true` on the first frame the action becomes active.
+    pub fn just_pressed(&self, name: &str) -> bool {
+        self.actions
+            .get(name)
+            .is_some_and(|a| a.value != 0.0 && a.previous_value == 0.0)
+    }
+
+    /// Returns `true` on the first frame the action stops being active.
+    pub fn just_released(&self, name: &str) -> bool {
+        self.actions
+            .get(name)
+            .is_some_and(|a| a.value == 0.0 && a.previous_value != 0.0)
+    }
+
+    /// Returns the current value of the action.
+    ///
+    /// Button actions read 0.0 or 1.0; axis actions read -1.0..=1.0.
+    /// Unknown actions read 0.0.
+    pub fn value(&self, name: &str) -> f32 {
+        self.actions.get(name).map_or(0.0, |a| a.value)
+    }
+
+    /// Parses a binding config.
+    ///
+    /// Each non-empty line that does not start with `#` declares one action:
+    ///
+    /// ```text
+    /// button jump = Space, MouseLeft
+    /// button save = Ctrl+S
+    /// axis move_x deadzone=0.2 = A/D, Left/Right, WheelY
+    /// ```
+    pub fn from_config(text: &str) -> Result<ActionMap, ActionMapError> {
+        let mut map = ActionMap::new();
+
+        for (index, raw) in text.lines().enumerate() {
+            let line = index + 1;
+            let trimmed = raw.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let (head, tail) = trimmed
+                .split_once('=')
+                .ok_or(ActionMapError::InvalidLine { line })?;
+            let mut head = head.split_whitespace();
+            let kind = head.next().ok_or(ActionMapError::InvalidLine { line })?;
+            let name = head.next().ok_or(ActionMapError::InvalidLine { line })?;
+
+            // The deadzone option itself contains '=', so rejoin what split_once took apart.
+            let mut deadzone = 0.0;
+            let mut tail = tail;
+            if let Some(option) = head.next() {
+                if option != "deadzone" || head.next().is_some() {
+                    return Err(ActionMapError::InvalidLine { line });
+                }
+                let (value, rest) = tail
+                    .split_once('=')
+                    .ok_or(ActionMapError::InvalidLine { line })?;
+                deadzone = value
+                    .trim()
+                    .parse::<f32>()
+                    .map_err(|_| ActionMapError::InvalidLine { line })?;
+                tail = rest;
+            }
+
+            let tokens: Vec<&str> = tail
+                .split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .collect();
+            let unknown = |token: &str| ActionMapError::UnknownInput {
+                line,
+                token: token.to_string(),
+            };
+
+            let bindings = match kind {
+                "button" => ActionBindings::Button(
+                    tokens
+                        .iter()
+                        .map(|t| ButtonBinding::from_token(t).ok_or_else(|| unknown(t)))
+                        .collect::<Result<_, _>>()?,
+                ),
+                "axis" => ActionBindings::Axis(
+                    tokens
+                        .iter()
+                        .map(|t| AxisBinding::from_token(t).ok_or_else(|| unknown(t)))
+                        .collect::<Result<_, _>>()?,
+                ),
+                _ => return Err(ActionMapError::InvalidLine { line }),
+            };
+
+            map.set_bindings(name, bindings);
+            map.set_deadzone(name, deadzone)?;
+        }
+
+        Ok(map)
+    }
+
+    /// Serializes the bindings into the format read by [`Self::from_config`].
+    pub fn to_config(&self) -> String {
+        let mut out = String::new();
+
+        for (name, action) in &self.actions {
+            let (kind, tokens): (&str, Vec<String>) = match &action.bindings {
+                ActionBindings::Button(bindings) => {
+                    ("button", bindings.iter().map(|b| b.to_token()).collect())
+                }
+                ActionBindings::Axis(bindings) => {
+                    ("axis", bindings.iter().map(|b| b.to_token()).collect())
+                }
+            };
+
+            out.push_str(kind);
+            out.push(' ');
+            out.push_str(name);
+            if action.deadzone != 0.0 {
+                out.push_str(&format!(" deadzone={}", action.deadzone));
+            }
+            out.push_str(" = ");
+            out.push_str(&tokens.join(", "));
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Loads a binding config file.
+    pub fn load(path: impl AsRef<Path>) -> Result<ActionMap, ActionMapError> {
+        let text = std::fs::read_to_string(path).map_err(|e| ActionMapError::Io(e.kind()))?;
+        Self::from_config(&text)
+    }
+
+    /// Saves the bindings to a config file, overwriting it.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ActionMapError> {
+        std::fs::write(path, self.to_config()).map_err(|e| ActionMapError::Io(e.kind()))
+    }
+
+    /// Collects keys claimed by chords that are active this frame.
+    fn consumed_keys(&self, input: &Input) -> HashSet<Key> {
+        let mut consumed = HashSet::new();
+        for action in self.actions.values() {
+            let chords: Vec<&ButtonBinding> = match &action.bindings {
+                ActionBindings::Button(bindings) => bindings.iter().collect(),
+                ActionBindings::Axis(bindings) => bindings
+                    .iter()
+                    .flat_map(|binding| match binding {
+                        AxisBinding::Buttons { negative, positive } => vec![negative, positive],
+                        AxisBinding::Analog(_) => Vec::new(),
+                    })
+                    .collect(),
+            };
+            for binding in chords {
+                if let ButtonBinding::Chord { modifiers, key } = binding
+                    && chord_active(modifiers, *key, input)
+                {
+                    consumed.insert(*key);
+                }
+            }
+        }
+        consumed
+    }
+}
+
+/// Checks if `key` is pressed while every modifier is held.
+fn chord_active(modifiers: &[Modifier], key: Key, input: &Input) -> bool {
+    input.keyboard.is_pressed(key) && modifiers.iter().all(|m| m.is_held(&input.keyboard))
+}
+
+/// Zeroes values inside the deadzone and rescales the rest to keep the full range.
+fn apply_deadzone(value: f32, deadzone: f32) -> f32 {
+    let magnitude = value.abs();
+    if magnitude <= deadzone {
+        0.0
+    } else {
+        value.signum() * (magnitude - deadzone) / (1.0 - deadzone)
+    }
+}
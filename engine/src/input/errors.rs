@@ -0,0 +1,22 @@
+/// Error returned by [`crate::input::ActionMap`] operations.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ActionMapError {
+    /// No action with the given name exists in the map.
+    UnknownAction,
+    /// The action exists but is a button where an axis was expected, or vice versa.
+    KindMismatch,
+    /// A line of a binding config could not be parsed.
+    InvalidLine {
+        /// 1-based line number in the config text.
+        line: usize,
+    },
+    /// A binding token in a config line does not name a known input.
+    UnknownInput {
+        /// 1-based line number in the config text.
+        line: usize,
+        /// The token that could not be resolved.
+        token: String,
+    },
+    /// Reading or writing a config file failed.
+    Io(std::io::ErrorKind),
+}
@@ -0,0 +1,285 @@
+//! Gamepad input.
+//!
+//! Controller button, stick and trigger state.
+//! Multiple connected controllers.
+//! Driven by backend-agnostic events.
+
+use std::collections::BTreeMap;
+
+/// Identifies a connected gamepad.
+///
+/// Ids are assigned by the platform backend and stay stable while connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GamepadId(pub usize);
+
+/// A digital gamepad button, named by position rather than label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    South,
+    East,
+    West,
+    North,
+    LeftShoulder,
+    RightShoulder,
+    Select,
+    Start,
+    LeftStick,
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl GamepadButton {
+    /// Every button variant, in declaration order.
+    pub const ALL: [GamepadButton; 14] = [
+        GamepadButton::South,
+        GamepadButton::East,
+        GamepadButton::West,
+        GamepadButton::North,
+        GamepadButton::LeftShoulder,
+        GamepadButton::RightShoulder,
+        GamepadButton::Select,
+        GamepadButton::Start,
+        GamepadButton::LeftStick,
+        GamepadButton::RightStick,
+        GamepadButton::DPadUp,
+        GamepadButton::DPadDown,
+        GamepadButton::DPadLeft,
+        GamepadButton::DPadRight,
+    ];
+
+    /// Returns the stable name of the button, as used in binding config files.
+    pub fn name(self) -> &'static str {
+        match self {
+            GamepadButton::South => "South",
+            GamepadButton::East => "East",
+            GamepadButton::West => "West",
+            GamepadButton::North => "North",
+            GamepadButton::LeftShoulder => "LeftShoulder",
+            GamepadButton::RightShoulder => "RightShoulder",
+            GamepadButton::Select => "Select",
+            GamepadButton::Start => "Start",
+            GamepadButton::LeftStick => "LeftStick",
+            GamepadButton::RightStick => "RightStick",
+            GamepadButton::DPadUp => "DPadUp",
+            GamepadButton::DPadDown => "DPadDown",
+            GamepadButton::DPadLeft => "DPadLeft",
+            GamepadButton::DPadRight => "DPadRight",
+        }
+    }
+
+    /// Looks up a button by the name returned from [`GamepadButton::name`].
+    pub fn from_name(name: &str) -> Option<GamepadButton> {
+        GamepadButton::ALL
+            .iter()
+            .copied()
+            .find(|b| b.name() == name)
+    }
+}
+
+/// An analog gamepad axis.
+///
+/// Stick axes range over -1.0..=1.0 (positive is right/up).
+/// Trigger axes range over 0.0..=1.0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    LeftTrigger,
+    RightTrigger,
+}
+
+impl GamepadAxis {
+    /// Every axis variant, in declaration order.
+    pub const ALL: [GamepadAxis; 6] = [
+        GamepadAxis::LeftStickX,
+        GamepadAxis::LeftStickY,
+        GamepadAxis::RightStickX,
+        GamepadAxis::RightStickY,
+        GamepadAxis::LeftTrigger,
+        GamepadAxis::RightTrigger,
+    ];
+
+    /// Returns the stable name of the axis, as used in binding config files.
+    pub fn name(self) -> &'static str {
+        match self {
+            GamepadAxis::LeftStickX => "LeftStickX",
+            GamepadAxis::LeftStickY => "LeftStickY",
+            GamepadAxis::RightStickX => "RightStickX",
+            GamepadAxis::RightStickY => "RightStickY",
+            GamepadAxis::LeftTrigger => "LeftTrigger",
+            GamepadAxis::RightTrigger => "RightTrigger",
+        }
+    }
+
+    /// Looks up an axis by the name returned from [`GamepadAxis::name`].
+    pub fn from_name(name: &str) -> Option<GamepadAxis> {
+        GamepadAxis::ALL.iter().copied().find(|a| a.name() == name)
+    }
+
+    /// Returns the valid value range of the axis.
+    pub fn range(self) -> (f32, f32) {
+        match self {
+            GamepadAxis::LeftTrigger | GamepadAxis::RightTrigger => (0.0, 1.0),
+            _ => (-1.0, 1.0),
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// A change reported by a platform backend.
+///
+/// Backends translate their native events into these; tests construct them directly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GamepadEvent {
+    Connected(GamepadId),
+    Disconnected(GamepadId),
+    ButtonPressed(GamepadId, GamepadButton),
+    ButtonReleased(GamepadId, GamepadButton),
+    AxisMoved(GamepadId, GamepadAxis, f32),
+}
+
+/// The state of a single connected gamepad.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Gamepad {
+    pressed: Vec<GamepadButton>,
+    axes: [f32; 6],
+}
+
+impl Gamepad {
+    /// Checks if a button is currently pressed.
+    pub fn is_pressed(&self, button: GamepadButton) -> bool {
+        self.pressed.contains(&button)
+    }
+
+    /// Returns a slice of all currently pressed buttons.
+    pub fn pressed_buttons(&self) -> &[GamepadButton] {
+        &self.pressed
+    }
+
+    /// Returns the current value of an axis.
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        self.axes[axis.index()]
+    }
+
+    /// Rebuilds a gamepad from raw state, as stored in input recordings.
+    pub(crate) fn from_parts(pressed: Vec<GamepadButton>, axes: [f32; 6]) -> Self {
+        Self { pressed, axes }
+    }
+}
+
+/// Tracks all connected gamepads and the events received this frame.
+///
+/// The platform layer owns one of these, calls [`GamepadState::clear_events`]
+/// at the start of each frame, feeds it events with [`GamepadState::handle_event`],
+/// and hands a copy to the engine inside [`crate::input::Input`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GamepadState {
+    gamepads: BTreeMap<GamepadId, Gamepad>,
+    events: Vec<GamepadEvent>,
+}
+
+impl GamepadState {
+    /// Creates a state with no connected gamepads.
+    pub fn new() -> Self {
+        Self {
+            gamepads: BTreeMap::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Rebuilds a state from raw parts, as stored in input recordings.
+    ///
+    /// Unlike [`Self::handle_event`], the events are stored without being applied.
+    pub(crate) fn from_parts(
+        gamepads: Vec<(GamepadId, Gamepad)>,
+        events: Vec<GamepadEvent>,
+    ) -> Self {
+        Self {
+            gamepads: gamepads.into_iter().collect(),
+            events,
+        }
+    }
+
+    /// Applies an event and records it for this frame.
+    ///
+    /// Button and axis events for unknown gamepads implicitly connect them,
+    /// since some backends do not report connection separately.
+    /// Axis values are clamped to [`GamepadAxis::range`].
+    pub fn handle_event(&mut self, event: GamepadEvent) {
+        match event {
+            GamepadEvent::Connected(id) => {
+                self.gamepads.entry(id).or_default();
+            }
+            GamepadEvent::Disconnected(id) => {
+                self.gamepads.remove(&id);
+            }
+            GamepadEvent::ButtonPressed(id, button) => {
+                let pad = self.gamepads.entry(id).or_default();
+                if !pad.pressed.contains(&button) {
+                    pad.pressed.push(button);
+                }
+            }
+            GamepadEvent::ButtonReleased(id, button) => {
+                let pad = self.gamepads.entry(id).or_default();
+                pad.pressed.retain(|&b| b != button);
+            }
+            GamepadEvent::AxisMoved(id, axis, value) => {
+                let (min, max) = axis.range();
+                let pad = self.gamepads.entry(id).or_default();
+                pad.axes[axis.index()] = value.clamp(min, max);
+            }
+        }
+        self.events.push(event);
+    }
+
+    /// Forgets the events recorded for the previous frame. Held state is kept.
+    pub fn clear_events(&mut self) {
+        self.events.clear();
+    }
+
+    /// Returns the events received this frame, in arrival order.
+    pub fn events(&self) -> &[GamepadEvent] {
+        &self.events
+    }
+
+    /// Returns the gamepad with the given id, if connected.
+    pub fn get(&self, id: GamepadId) -> Option<&Gamepad> {
+        self.gamepads.get(&id)
+    }
+
+    /// Returns the ids of all connected gamepads in ascending order.
+    pub fn connected(&self) -> Vec<GamepadId> {
+        self.gamepads.keys().copied().collect()
+    }
+
+    /// Returns `true` if the gamepad connected this frame.
+    pub fn just_connected(&self, id: GamepadId) -> bool {
+        self.events.contains(&GamepadEvent::Connected(id))
+    }
+
+    /// Returns `true` if the gamepad disconnected this frame.
+    pub fn just_disconnected(&self, id: GamepadId) -> bool {
+        self.events.contains(&GamepadEvent::Disconnected(id))
+    }
+
+    /// Checks if a button is pressed on any connected gamepad.
+    pub fn any_pressed(&self, button: GamepadButton) -> bool {
+        self.gamepads.values().any(|pad| pad.is_pressed(button))
+    }
+
+    /// Returns the axis value with the largest magnitude across connected gamepads.
+    pub fn any_axis(&self, axis: GamepadAxis) -> f32 {
+        self.gamepads
+            .values()
+            .map(|pad| pad.axis(axis))
+            .fold(0.0, |best, v| if v.abs() > best.abs() { v } else { best })
+    }
+}
@@ -2,13 +2,238 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Key {
     A,
+    B,
+    C,
     D,
-    W,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
     S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Num0,
+    Num1,
+    Num2,
+    Num3,
+    Num4,
+    Num5,
+    Num6,
+    Num7,
+    Num8,
+    Num9,
     Space,
     Escape,
+    Enter,
+    Tab,
+    Backspace,
     Left,
     Right,
     Up,
     Down,
+    LeftShift,
+    RightShift,
+    LeftControl,
+    RightControl,
+    LeftAlt,
+    RightAlt,
+}
+
+impl Key {
+    /// Every key variant, in declaration order.
+    pub const ALL: [Key; 51] = [
+        Key::A,
+        Key::B,
+        Key::C,
+        Key::D,
+        Key::E,
+        Key::F,
+        Key::G,
+        Key::H,
+        Key::I,
+        Key::J,
+        Key::K,
+        Key::L,
+        Key::M,
+        Key::N,
+        Key::O,
+        Key::P,
+        Key::Q,
+        Key::R,
+        Key::S,
+        Key::T,
+        Key::U,
+        Key::V,
+        Key::W,
+        Key::X,
+        Key::Y,
+        Key::Z,
+        Key::Num0,
+        Key::Num1,
+        Key::Num2,
+        Key::Num3,
+        Key::Num4,
+        Key::Num5,
+        Key::Num6,
+        Key::Num7,
+        Key::Num8,
+        Key::Num9,
+        Key::Space,
+        Key::Escape,
+        Key::Enter,
+        Key::Tab,
+        Key::Backspace,
+        Key::Left,
+        Key::Right,
+        Key::Up,
+        Key::Down,
+        Key::LeftShift,
+        Key::RightShift,
+        Key::LeftControl,
+        Key::RightControl,
+        Key::LeftAlt,
+        Key::RightAlt,
+    ];
+
+    /// Returns the stable name of the key, as used in binding config files.
+    pub fn name(self) -> &'static str {
+        match self {
+            Key::A => "A",
+            Key::B => "B",
+            Key::C => "C",
+            Key::D => "D",
+            Key::E => "E",
+            Key::F => "F",
+            Key::G => "G",
+            Key::H => "H",
+            Key::I => "I",
+            Key::J => "J",
+            Key::K => "K",
+            Key::L => "L",
+            Key::M => "M",
+            Key::N => "N",
+            Key::O => "O",
+            Key::P => "P",
+            Key::Q => "Q",
+            Key::R => "R",
+            Key::S => "S",
+            Key::T => "T",
+            Key::U => "U",
+            Key::V => "V",
+            Key::W => "W",
+            Key::X => "X",
+            Key::Y => "Y",
+            Key::Z => "Z",
+            Key::Num0 => "Num0",
+            Key::Num1 => "Num1",
+            Key::Num2 => "Num2",
+            Key::Num3 => "Num3",
+            Key::Num4 => "Num4",
+            Key::Num5 => "Num5",
+            Key::Num6 => "Num6",
+            Key::Num7 => "Num7",
+            Key::Num8 => "Num8",
+            Key::Num9 => "Num9",
+            Key::Space => "Space",
+            Key::Escape => "Escape",
+            Key::Enter => "Enter",
+            Key::Tab => "Tab",
+            Key::Backspace => "Backspace",
+            Key::Left => "Left",
+            Key::Right => "Right",
+            Key::Up => "Up",
+            Key::Down => "Down",
+            Key::LeftShift => "LeftShift",
+            Key::RightShift => "RightShift",
+            Key::LeftControl => "LeftControl",
+            Key::RightControl => "RightControl",
+            Key::LeftAlt => "LeftAlt",
+            Key::RightAlt => "RightAlt",
+        }
+    }
+
+    /// Returns the character a US layout produces for this key, if any.
+    ///
+    /// A fallback for backends without text events; prefer [`crate::input::TextInput`],
+    /// which respects the user's layout and IME.
+    pub fn to_char(self, shift: bool) -> Option<char> {
+        let name = self.name();
+        match self {
+            Key::A
+            | Key::B
+            | Key::C
+            | Key::D
+            | Key::E
+            | Key::F
+            | Key::G
+            | Key::H
+            | Key::I
+            | Key::J
+            | Key::K
+            | Key::L
+            | Key::M
+            | Key::N
+            | Key::O
+            | Key::P
+            | Key::Q
+            | Key::R
+            | Key::S
+            | Key::T
+            | Key::U
+            | Key::V
+            | Key::W
+            | Key::X
+            | Key::Y
+            | Key::Z => {
+                let upper = name.chars().next()?;
+                Some(if shift {
+                    upper
+                } else {
+                    upper.to_ascii_lowercase()
+                })
+            }
+            Key::Num0
+            | Key::Num1
+            | Key::Num2
+            | Key::Num3
+            | Key::Num4
+            | Key::Num5
+            | Key::Num6
+            | Key::Num7
+            | Key::Num8
+            | Key::Num9 => {
+                let digit = name.chars().last()?;
+                if shift {
+                    ")!@#$%^&*(".chars().nth(digit.to_digit(10)? as usize)
+                } else {
+                    Some(digit)
+                }
+            }
+            Key::Space => Some(' '),
+            Key::Enter => Some('\r'),
+            Key::Tab => Some('\t'),
+            Key::Backspace => Some('\u{8}'),
+            _ => None,
+        }
+    }
+
+    /// Looks up a key by the name returned from [`Key::name`].
+    pub fn from_name(name: &str) -> Option<Key> {
+        Key::ALL.iter().copied().find(|key| key.name() == name)
+    }
 }
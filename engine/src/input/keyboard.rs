@@ -7,7 +7,7 @@
 use super::Key;
 
 /// Tracks the state of keyboard input.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct KeyboardState {
     pressed: Vec<Key>,
 }
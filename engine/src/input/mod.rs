@@ -5,17 +5,27 @@
 //! Platform-agnostic interface.
 //! No game-specific bindings.
 
+mod action;
+mod errors;
+mod gamepad;
 mod key;
 mod keyboard;
 mod mouse;
+mod text;
 
+pub use action::{ActionBindings, ActionMap, AnalogSource, AxisBinding, ButtonBinding, Modifier};
+pub use errors::ActionMapError;
+pub use gamepad::{Gamepad, GamepadAxis, GamepadButton, GamepadEvent, GamepadId, GamepadState};
 pub use key::Key;
 pub use keyboard::KeyboardState;
-pub use mouse::{MouseButtons, MousePosition, MouseState};
+pub use mouse::{MouseButton, MouseButtons, MouseDelta, MousePosition, MouseState, MouseWheel};
+pub use text::{TextEvent, TextField, TextFocus, TextInput};
 
 /// Aggregates all input state for a frame.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Input {
     pub keyboard: KeyboardState,
     pub mouse: MouseState,
+    pub gamepads: GamepadState,
+    pub text: TextInput,
 }
@@ -1,22 +1,173 @@
+//! Mouse input.
+//!
+//! Cursor position and per-frame motion.
+//! Button and wheel state.
+//! Screen to world conversion.
+
+use crate::math::Vec2;
+use crate::render::RenderView;
+
 /// Represents the position of the mouse cursor.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct MousePosition {
     pub x: f32,
     pub y: f32,
 }
 
+/// Cursor movement accumulated over one frame, in screen pixels.
+///
+/// Used for mouse-look, where absolute position does not matter.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MouseDelta {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Scroll accumulated over one frame.
+///
+/// Positive `y` scrolls up (away from the user); positive `x` scrolls right.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MouseWheel {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Identifies a single mouse button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    /// The side button usually mapped to "back" in browsers.
+    Back,
+    /// The side button usually mapped to "forward" in browsers.
+    Forward,
+}
+
+impl MouseButton {
+    /// Every button variant, in declaration order.
+    pub const ALL: [MouseButton; 5] = [
+        MouseButton::Left,
+        MouseButton::Right,
+        MouseButton::Middle,
+        MouseButton::Back,
+        MouseButton::Forward,
+    ];
+
+    /// Returns the stable name of the button, as used in binding config files.
+    pub fn name(self) -> &'static str {
+        match self {
+            MouseButton::Left => "Left",
+            MouseButton::Right => "Right",
+            MouseButton::Middle => "Middle",
+            MouseButton::Back => "Back",
+            MouseButton::Forward => "Forward",
+        }
+    }
+
+    /// Looks up a button by the name returned from [`MouseButton::name`].
+    pub fn from_name(name: &str) -> Option<MouseButton> {
+        MouseButton::ALL.iter().copied().find(|b| b.name() == name)
+    }
+}
+
 /// Represents the state of mouse buttons.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct MouseButtons {
     pub left: bool,
     pub right: bool,
     pub middle: bool,
+    pub back: bool,
+    pub forward: bool,
+}
+
+impl MouseButtons {
+    /// Checks if the given button is currently pressed.
+    pub fn is_pressed(&self, button: MouseButton) -> bool {
+        match button {
+            MouseButton::Left => self.left,
+            MouseButton::Right => self.right,
+            MouseButton::Middle => self.middle,
+            MouseButton::Back => self.back,
+            MouseButton::Forward => self.forward,
+        }
+    }
+
+    /// Sets the pressed state of the given button.
+    pub fn set(&mut self, button: MouseButton, pressed: bool) {
+        match button {
+            MouseButton::Left => self.left = pressed,
+            MouseButton::Right => self.right = pressed,
+            MouseButton::Middle => self.middle = pressed,
+            MouseButton::Back => self.back = pressed,
+            MouseButton::Forward => self.forward = pressed,
+        }
+    }
 }
 
 /// Tracks the state of mouse input.
-#[derive(Debug, Clone, Copy, Default)]
+///
+/// `delta` and `wheel` are per-frame accumulators: the platform layer calls
+/// [`MouseState::begin_frame`] before feeding the frame's events.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct MouseState {
     pub position: MousePosition,
+    pub delta: MouseDelta,
     pub buttons: MouseButtons,
-    pub wheel_delta: f32,
+    pub wheel: MouseWheel,
+    /// Whether the cursor is currently over the window.
+    pub inside_window: bool,
+    /// Whether `position` has been reported yet; until then [`MouseState::move_to`]
+    /// records no motion.
+    pub has_position: bool,
+}
+
+impl MouseState {
+    /// Resets the per-frame motion and scroll accumulators. Position and buttons are kept.
+    pub fn begin_frame(&mut self) {
+        self.delta = MouseDelta::default();
+        self.wheel = MouseWheel::default();
+    }
+
+    /// Moves the cursor to an absolute position, accumulating the motion into `delta`.
+    ///
+    /// The first position ever reported only places the cursor, so mouse-look
+    /// does not jump by the distance from the origin.
+    pub fn move_to(&mut self, x: f32, y: f32) {
+        if self.has_position {
+            self.delta.x += x - self.position.x;
+            self.delta.y += y - self.position.y;
+        }
+        self.position = MousePosition { x, y };
+        self.has_position = true;
+    }
+
+    /// Accumulates relative motion without moving the cursor.
+    ///
+    /// For backends that report raw motion while the cursor is grabbed.
+    pub fn add_motion(&mut self, dx: f32, dy: f32) {
+        self.delta.x += dx;
+        self.delta.y += dy;
+    }
+
+    /// Accumulates a scroll event.
+    pub fn scroll(&mut self, x: f32, y: f32) {
+        self.wheel.x += x;
+        self.wheel.y += y;
+    }
+
+    /// Converts the cursor position into world coordinates through a camera's view
+    /// of a target of the given size.
+    ///
+    /// See [`RenderView::screen_to_world`]; returns `None` where it does.
+    pub fn world_position(
+        &self,
+        view: &RenderView,
+        target_width: usize,
+        target_height: usize,
+    ) -> Option<Vec2> {
+        let screen = Vec2::new(self.position.x, self.position.y);
+        view.screen_to_world(screen, target_width, target_height)
+            .map(|world| world.truncate())
+    }
 }
@@ -0,0 +1,156 @@
+//! Text input.
+//!
+//! Unicode character stream, separate from key state.
+//! IME composition and commit events.
+//! Focus tracking for text fields.
+
+use crate::ecs::entity::Entity;
+
+/// A text event reported by the platform backend.
+///
+/// Backends with an input method editor (IME) report composition events while
+/// the user assembles a character, then a commit with the final text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextEvent {
+    /// A character typed directly, without composition.
+    Char(char),
+    /// The in-progress composition changed.
+    ///
+    /// `cursor` is the byte offset of the caret within `text`.
+    CompositionUpdate { text: String, cursor: usize },
+    /// The composition finished and produced this text.
+    CompositionCommit(String),
+    /// The composition was abandoned without producing text.
+    CompositionCancel,
+}
+
+/// The text events received during one frame.
+///
+/// The platform layer calls [`TextInput::clear`] at the start of each frame
+/// and [`TextInput::push`] for every event.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextInput {
+    events: Vec<TextEvent>,
+}
+
+impl TextInput {
+    /// Creates an empty text input.
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Records an event for this frame.
+    pub fn push(&mut self, event: TextEvent) {
+        self.events.push(event);
+    }
+
+    /// Forgets the events recorded for the previous frame.
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// Returns the events received this frame, in arrival order.
+    pub fn events(&self) -> &[TextEvent] {
+        &self.events
+    }
+
+    /// Returns the text committed this frame: typed characters and finished compositions.
+    pub fn committed(&self) -> String {
+        let mut text = String::new();
+        for event in &self.events {
+            match event {
+                TextEvent::Char(c) => text.push(*c),
+                TextEvent::CompositionCommit(committed) => text.push_str(committed),
+                _ => {}
+            }
+        }
+        text
+    }
+}
+
+/// Resource naming the entity that currently receives text input.
+///
+/// While an entity is focused, [`crate::ecs::system::action_system`] ignores the
+/// keyboard so typing into a text field does not trigger gameplay actions.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TextFocus {
+    pub focused: Option<Entity>,
+}
+
+impl TextFocus {
+    /// Gives text focus to an entity, taking it from any other.
+    pub fn focus(&mut self, entity: Entity) {
+        self.focused = Some(entity);
+    }
+
+    /// Removes text focus.
+    pub fn blur(&mut self) {
+        self.focused = None;
+    }
+
+    /// Returns `true` if any entity has text focus.
+    pub fn is_active(&self) -> bool {
+        self.focused.is_some()
+    }
+}
+
+/// Text field component: an editable string that receives input while focused.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextField {
+    /// The committed contents of the field.
+    pub value: String,
+    /// The in-progress IME composition, shown after `value` but not part of it.
+    pub composition: String,
+    /// Maximum number of characters in `value`, if limited.
+    pub max_chars: Option<usize>,
+    /// Set when Enter was typed this frame; cleared on the next frame, focused or not.
+    pub submitted: bool,
+}
+
+impl TextField {
+    /// Creates an empty text field with an optional character limit.
+    pub fn new(max_chars: Option<usize>) -> Self {
+        Self {
+            max_chars,
+            ..Self::default()
+        }
+    }
+
+    /// Applies this frame's text events to the field.
+    ///
+    /// Backspace (`'\u{8}'`) removes the last character and Enter (`'\r'` or `'\n'`)
+    /// sets [`Self::submitted`]; other control characters are ignored.
+    pub fn apply(&mut self, text: &TextInput) {
+        self.submitted = false;
+
+        for event in text.events() {
+            match event {
+                TextEvent::Char('\u{8}') => {
+                    self.value.pop();
+                }
+                TextEvent::Char('\r' | '\n') => self.submitted = true,
+                TextEvent::Char(c) if c.is_control() => {}
+                TextEvent::Char(c) => self.insert(&c.to_string()),
+                TextEvent::CompositionUpdate { text, .. } => self.composition = text.clone(),
+                TextEvent::CompositionCommit(committed) => {
+                    self.composition.clear();
+                    self.insert(committed);
+                }
+                TextEvent::CompositionCancel => self.composition.clear(),
+            }
+        }
+    }
+
+    /// Appends text, truncating at [`Self::max_chars`].
+    fn insert(&mut self, text: &str) {
+        for c in text.chars() {
+            if self
+                .max_chars
+                .is_some_and(|max| self.value.chars().count() >= max)
+            {
+                return;
+            }
+            self.value.push(c);
+        }
+    }
+}
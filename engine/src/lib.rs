@@ -4,11 +4,15 @@
 //! Defines what is considered stable and user-facing.
 //! Keeps internal implementation details private.
 
+pub mod assets;
 pub mod core;
 pub mod ecs;
 pub mod input;
+pub mod math;
+pub mod particles;
 pub mod render;
 pub mod time;
+pub mod tween;
 
 pub use core::engine::Engine;
 pub use ecs::World;
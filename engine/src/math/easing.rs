@@ -0,0 +1,175 @@
+//! Easing curves.
+//!
+//! Standard Penner-style curves.
+//! Map linear progress to eased progress.
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+/// An easing curve mapping linear progress `t` in `0.0..=1.0` to eased progress.
+///
+/// Every curve returns exactly 0.0 at `t = 0` and 1.0 at `t = 1`.
+/// `Back` and `Elastic` overshoot that range in between.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl Easing {
+    /// Every curve, in declaration order.
+    pub const ALL: [Easing; 28] = [
+        Easing::Linear,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::QuartIn,
+        Easing::QuartOut,
+        Easing::QuartInOut,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::ExpoIn,
+        Easing::ExpoOut,
+        Easing::ExpoInOut,
+        Easing::CircIn,
+        Easing::CircOut,
+        Easing::CircInOut,
+        Easing::BackIn,
+        Easing::BackOut,
+        Easing::BackInOut,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::ElasticInOut,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::BounceInOut,
+    ];
+
+    /// Applies the curve. `t` is clamped to `0.0..=1.0`.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        if t == 0.0 || t == 1.0 {
+            return t;
+        }
+
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => out(t, |t| t * t),
+            Easing::QuadInOut => in_out(t, |t| t * t),
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => out(t, |t| t * t * t),
+            Easing::CubicInOut => in_out(t, |t| t * t * t),
+            Easing::QuartIn => t * t * t * t,
+            Easing::QuartOut => out(t, |t| t * t * t * t),
+            Easing::QuartInOut => in_out(t, |t| t * t * t * t),
+            Easing::SineIn => sine_in(t),
+            Easing::SineOut => out(t, sine_in),
+            Easing::SineInOut => in_out(t, sine_in),
+            Easing::ExpoIn => expo_in(t),
+            Easing::ExpoOut => out(t, expo_in),
+            Easing::ExpoInOut => in_out(t, expo_in),
+            Easing::CircIn => circ_in(t),
+            Easing::CircOut => out(t, circ_in),
+            Easing::CircInOut => in_out(t, circ_in),
+            Easing::BackIn => back_in(t),
+            Easing::BackOut => out(t, back_in),
+            Easing::BackInOut => in_out(t, back_in),
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => out(t, elastic_in),
+            Easing::ElasticInOut => in_out(t, elastic_in),
+            Easing::BounceIn => out(t, bounce_out),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+        }
+    }
+}
+
+/// Mirrors an ease-in curve into the matching ease-out.
+fn out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    1.0 - ease_in(1.0 - t)
+}
+
+/// Runs an ease-in curve over the first half and its mirror over the second.
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) * 0.5
+    } else {
+        1.0 - ease_in((1.0 - t) * 2.0) * 0.5
+    }
+}
+
+fn sine_in(t: f32) -> f32 {
+    1.0 - (t * FRAC_PI_2).cos()
+}
+
+fn expo_in(t: f32) -> f32 {
+    if t == 0.0 {
+        0.0
+    } else {
+        2f32.powf(10.0 * t - 10.0)
+    }
+}
+
+fn circ_in(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).max(0.0).sqrt()
+}
+
+fn back_in(t: f32) -> f32 {
+    const OVERSHOOT: f32 = 1.70158;
+    t * t * ((OVERSHOOT + 1.0) * t - OVERSHOOT)
+}
+
+fn elastic_in(t: f32) -> f32 {
+    if t == 0.0 || t == 1.0 {
+        return t;
+    }
+    -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * (TAU / 3.0)).sin()
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
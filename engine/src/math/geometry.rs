@@ -0,0 +1,463 @@
+//! Geometric primitives.
+//!
+//! Bounding volumes, rays, planes and frustums.
+//! 2D circles, oriented rectangles and polygons.
+//! Intersection and containment tests; touching counts as intersecting.
+
+use super::{EPSILON, Mat4, Vec2, Vec3, Vec4};
+
+/// An axis-aligned bounding box.
+///
+/// A box with `min == max` is a valid, degenerate box containing a single point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// Creates a box from two opposite corners, in any order.
+    pub fn new(a: Vec3, b: Vec3) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Creates a box from its center and half size along each axis.
+    pub fn from_center_half_extents(center: Vec3, half_extents: Vec3) -> Self {
+        let half_extents = half_extents.abs();
+        Self {
+            min: center - half_extents,
+            max: center + half_extents,
+        }
+    }
+
+    /// Returns the smallest box containing every point, or `None` if there are none.
+    pub fn from_points(points: &[Vec3]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(
+            rest.iter()
+                .fold(Self::new(*first, *first), |aabb, &p| Self {
+                    min: aabb.min.min(p),
+                    max: aabb.max.max(p),
+                }),
+        )
+    }
+
+    /// Returns the center point.
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns the half size along each axis.
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Returns `true` if the point lies inside or on the boundary.
+    pub fn contains_point(&self, p: Vec3) -> bool {
+        p.x >= self.min.x
+            && p.x <= self.max.x
+            && p.y >= self.min.y
+            && p.y <= self.max.y
+            && p.z >= self.min.z
+            && p.z <= self.max.z
+    }
+
+    /// Returns `true` if `other` lies entirely inside this box.
+    pub fn contains_aabb(&self, other: &Aabb) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// Returns `true` if the boxes overlap or touch.
+    pub fn intersects_aabb(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    /// Returns `true` if the box and sphere overlap or touch.
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        let closest = sphere.center.max(self.min).min(self.max);
+        closest.distance(sphere.center) <= sphere.radius
+    }
+
+    /// Returns the smallest box containing both boxes.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Returns the bounding box of this box after an affine transform.
+    pub fn transformed(&self, matrix: &Mat4) -> Aabb {
+        let corners = [
+            Vec3::new(self.min.x, self.min.y, self.min.z),
+            Vec3::new(self.max.x, self.min.y, self.min.z),
+            Vec3::new(self.min.x, self.max.y, self.min.z),
+            Vec3::new(self.max.x, self.max.y, self.min.z),
+            Vec3::new(self.min.x, self.min.y, self.max.z),
+            Vec3::new(self.max.x, self.min.y, self.max.z),
+            Vec3::new(self.min.x, self.max.y, self.max.z),
+            Vec3::new(self.max.x, self.max.y, self.max.z),
+        ];
+        let points = corners.map(|c| matrix.transform_point3(c));
+        Aabb::from_points(&points).expect("eight corners")
+    }
+}
+
+/// A bounding sphere.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// Creates a sphere from its center and radius.
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns `true` if the point lies inside or on the surface.
+    pub fn contains_point(&self, p: Vec3) -> bool {
+        self.center.distance(p) <= self.radius
+    }
+
+    /// Returns `true` if the spheres overlap or touch.
+    pub fn intersects_sphere(&self, other: &Sphere) -> bool {
+        self.center.distance(other.center) <= self.radius + other.radius
+    }
+}
+
+/// A half-line starting at `origin`.
+///
+/// `direction` need not be normalized; hit distances are in multiples of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl Ray {
+    /// Creates a ray from its origin and direction.
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the point at parameter `t` along the ray.
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.direction * t
+    }
+
+    /// Returns the smallest `t >= 0` where the ray enters the box.
+    ///
+    /// Returns `Some(0.0)` if the origin is inside the box. Uses the slab method;
+    /// axes where the direction is zero only hit if the origin lies within that slab.
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+        let mut t_min = 0.0f32;
+        let mut t_max = f32::INFINITY;
+
+        let axes = [
+            (self.origin.x, self.direction.x, aabb.min.x, aabb.max.x),
+            (self.origin.y, self.direction.y, aabb.min.y, aabb.max.y),
+            (self.origin.z, self.direction.z, aabb.min.z, aabb.max.z),
+        ];
+        for (origin, direction, min, max) in axes {
+            if direction.abs() <= EPSILON {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+            let t1 = (min - origin) / direction;
+            let t2 = (max - origin) / direction;
+            t_min = t_min.max(t1.min(t2));
+            t_max = t_max.min(t1.max(t2));
+            if t_min > t_max {
+                return None;
+            }
+        }
+        Some(t_min)
+    }
+
+    /// Returns the smallest `t >= 0` where the ray meets the sphere.
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<f32> {
+        let offset = self.origin - sphere.center;
+        let a = self.direction.length_squared();
+        if a <= EPSILON {
+            return sphere.contains_point(self.origin).then_some(0.0);
+        }
+        let b = offset.dot(self.direction);
+        let c = offset.length_squared() - sphere.radius * sphere.radius;
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let near = (-b - root) / a;
+        let far = (-b + root) / a;
+        if far < 0.0 { None } else { Some(near.max(0.0)) }
+    }
+
+    /// Returns the `t >= 0` where the ray crosses the plane, if it does.
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f32> {
+        let denominator = plane.normal.dot(self.direction);
+        if denominator.abs() <= EPSILON {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denominator;
+        (t >= 0.0).then_some(t)
+    }
+}
+
+/// A plane of points `p` with `normal.dot(p) + d == 0`.
+///
+/// The normal points to the positive side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    pub normal: Vec3,
+    pub d: f32,
+}
+
+impl Plane {
+    /// Creates a plane through `point` with the given normal (normalized here).
+    pub fn from_point_normal(point: Vec3, normal: Vec3) -> Self {
+        let normal = normal.normalize_or_zero();
+        Self {
+            normal,
+            d: -normal.dot(point),
+        }
+    }
+
+    /// Creates a plane from `(a, b, c, d)` coefficients, normalizing them.
+    ///
+    /// Returns `None` if the normal part is zero.
+    pub fn from_coefficients(coefficients: Vec4) -> Option<Self> {
+        let length = coefficients.truncate().length();
+        if length <= EPSILON {
+            return None;
+        }
+        let n = coefficients / length;
+        Some(Self {
+            normal: n.truncate(),
+            d: n.w,
+        })
+    }
+
+    /// Returns the signed distance from the plane; positive on the normal's side.
+    pub fn signed_distance(&self, p: Vec3) -> f32 {
+        self.normal.dot(p) + self.d
+    }
+}
+
+/// A view volume bounded by six inward-facing planes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    /// Left, right, bottom, top, near and far planes, normals pointing inside.
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Extracts the frustum of a view-projection matrix (clip depth -1..=1).
+    ///
+    /// Returns `None` for degenerate matrices.
+    pub fn from_view_projection(matrix: &Mat4) -> Option<Self> {
+        let t = matrix.transpose();
+        let (r0, r1, r2, r3) = (t.x_axis, t.y_axis, t.z_axis, t.w_axis);
+        Some(Self {
+            planes: [
+                Plane::from_coefficients(r3 + r0)?,
+                Plane::from_coefficients(r3 - r0)?,
+                Plane::from_coefficients(r3 + r1)?,
+                Plane::from_coefficients(r3 - r1)?,
+                Plane::from_coefficients(r3 + r2)?,
+                Plane::from_coefficients(r3 - r2)?,
+            ],
+        })
+    }
+
+    /// Returns `true` if the point is inside or on the boundary.
+    pub fn contains_point(&self, p: Vec3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(p) >= 0.0)
+    }
+
+    /// Returns `true` if the sphere is at least partly inside.
+    ///
+    /// Conservative: spheres near frustum corners may be reported as visible.
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(sphere.center) >= -sphere.radius)
+    }
+
+    /// Returns `true` if the box is at least partly inside.
+    ///
+    /// Conservative in the same way as [`Self::intersects_sphere`].
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            // The box corner furthest along the plane normal
+            let corner = Vec3::new(
+                if plane.normal.x >= 0.0 {
+                    aabb.max.x
+                } else {
+                    aabb.min.x
+                },
+                if plane.normal.y >= 0.0 {
+                    aabb.max.y
+                } else {
+                    aabb.min.y
+                },
+                if plane.normal.z >= 0.0 {
+                    aabb.max.z
+                } else {
+                    aabb.min.z
+                },
+            );
+            plane.signed_distance(corner) >= 0.0
+        })
+    }
+}
+
+/// A 2D circle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+impl Circle {
+    /// Creates a circle from its center and radius.
+    pub fn new(center: Vec2, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns `true` if the point lies inside or on the edge.
+    pub fn contains_point(&self, p: Vec2) -> bool {
+        self.center.distance(p) <= self.radius
+    }
+
+    /// Returns `true` if the circles overlap or touch.
+    pub fn intersects_circle(&self, other: &Circle) -> bool {
+        self.center.distance(other.center) <= self.radius + other.radius
+    }
+}
+
+/// A 2D rectangle rotated about its center.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientedRect {
+    pub center: Vec2,
+    pub half_extents: Vec2,
+    /// Counter-clockwise rotation in radians.
+    pub angle: f32,
+}
+
+impl OrientedRect {
+    /// Creates a rectangle from its center, half size and rotation.
+    pub fn new(center: Vec2, half_extents: Vec2, angle: f32) -> Self {
+        Self {
+            center,
+            half_extents: half_extents.abs(),
+            angle,
+        }
+    }
+
+    /// Returns the rectangle's local x and y axes in world space.
+    pub fn axes(&self) -> [Vec2; 2] {
+        let x = Vec2::from_angle(self.angle);
+        [x, x.perp()]
+    }
+
+    /// Returns the four corners, counter-clockwise.
+    pub fn corners(&self) -> [Vec2; 4] {
+        let [x, y] = self.axes();
+        let hx = x * self.half_extents.x;
+        let hy = y * self.half_extents.y;
+        [
+            self.center - hx - hy,
+            self.center + hx - hy,
+            self.center + hx + hy,
+            self.center - hx + hy,
+        ]
+    }
+
+    /// Converts a world point into the rectangle's unrotated local frame.
+    fn local_point(&self, p: Vec2) -> Vec2 {
+        let [x, y] = self.axes();
+        let offset = p - self.center;
+        Vec2::new(offset.dot(x), offset.dot(y))
+    }
+
+    /// Returns `true` if the point lies inside or on the edge.
+    pub fn contains_point(&self, p: Vec2) -> bool {
+        let local = self.local_point(p);
+        local.x.abs() <= self.half_extents.x && local.y.abs() <= self.half_extents.y
+    }
+
+    /// Returns `true` if the rectangle and circle overlap or touch.
+    pub fn intersects_circle(&self, circle: &Circle) -> bool {
+        let local = self.local_point(circle.center);
+        let closest = local.max(-self.half_extents).min(self.half_extents);
+        closest.distance(local) <= circle.radius
+    }
+
+    /// Returns `true` if the rectangles overlap or touch (separating axis test).
+    pub fn intersects_rect(&self, other: &OrientedRect) -> bool {
+        let a = self.corners();
+        let b = other.corners();
+        self.axes().iter().chain(other.axes().iter()).all(|&axis| {
+            let (a_min, a_max) = project(&a, axis);
+            let (b_min, b_max) = project(&b, axis);
+            a_min <= b_max && b_min <= a_max
+        })
+    }
+}
+
+/// Projects points onto an axis and returns the covered interval.
+fn project(points: &[Vec2], axis: Vec2) -> (f32, f32) {
+    points
+        .iter()
+        .map(|p| p.dot(axis))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), d| {
+            (min.min(d), max.max(d))
+        })
+}
+
+/// Returns `true` if the point lies inside the polygon (even-odd rule).
+///
+/// The polygon is given as a list of vertices in either winding order and may be
+/// concave. Points exactly on an edge count as inside. Fewer than three vertices
+/// never contain anything.
+pub fn point_in_polygon(point: Vec2, polygon: &[Vec2]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[j]);
+
+        // On-edge check: collinear and within the segment's bounds
+        let edge = b - a;
+        if edge.perp_dot(point - a).abs() <= EPSILON && (point - a).dot(point - b) <= 0.0 {
+            return true;
+        }
+
+        if (a.y > point.y) != (b.y > point.y) {
+            let x_cross = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x_cross {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
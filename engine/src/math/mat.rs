@@ -0,0 +1,441 @@
+//! Matrix types.
+//!
+//! Column-major 3x3 and 4x4 `f32` matrices.
+//! Affine transforms, inverse, look-at and projections.
+//! Right-handed; clip-space depth spans -1..=1.
+
+use std::ops::Mul;
+
+use super::{Quat, Vec2, Vec3, Vec4};
+
+/// A 3x3 column-major matrix, used for 2D affine transforms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat3 {
+    pub x_axis: Vec3,
+    pub y_axis: Vec3,
+    pub z_axis: Vec3,
+}
+
+/// A 4x4 column-major matrix, used for 3D transforms and projections.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4 {
+    pub x_axis: Vec4,
+    pub y_axis: Vec4,
+    pub z_axis: Vec4,
+    pub w_axis: Vec4,
+}
+
+impl Mat3 {
+    pub const IDENTITY: Mat3 = Mat3::from_cols(Vec3::X, Vec3::Y, Vec3::Z);
+    pub const ZERO: Mat3 = Mat3::from_cols(Vec3::ZERO, Vec3::ZERO, Vec3::ZERO);
+
+    /// Creates a matrix from its columns.
+    pub const fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a 2D translation.
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self::from_cols(Vec3::X, Vec3::Y, translation.extend(1.0))
+    }
+
+    /// Creates a 2D rotation of `angle` radians counter-clockwise.
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::from_cols(Vec3::new(cos, sin, 0.0), Vec3::new(-sin, cos, 0.0), Vec3::Z)
+    }
+
+    /// Creates a 2D non-uniform scale.
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self::from_cols(Vec3::X * scale.x, Vec3::Y * scale.y, Vec3::Z)
+    }
+
+    /// Creates a 2D transform that scales, then rotates, then translates.
+    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Self {
+        Self::from_translation(translation) * Self::from_angle(angle) * Self::from_scale(scale)
+    }
+
+    /// Returns row `i` as a vector.
+    fn row(&self, i: usize) -> Vec3 {
+        let pick = |v: Vec3| [v.x, v.y, v.z][i];
+        Vec3::new(pick(self.x_axis), pick(self.y_axis), pick(self.z_axis))
+    }
+
+    /// Returns the transpose.
+    pub fn transpose(&self) -> Mat3 {
+        Mat3::from_cols(self.row(0), self.row(1), self.row(2))
+    }
+
+    /// Returns the determinant.
+    pub fn determinant(&self) -> f32 {
+        self.z_axis.dot(self.x_axis.cross(self.y_axis))
+    }
+
+    /// Returns the inverse, or `None` if the matrix is singular.
+    ///
+    /// Only an exactly zero or non-finite determinant counts as singular: a fixed
+    /// tolerance would reject small scales and pixel-sized projections.
+    pub fn inverse(&self) -> Option<Mat3> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        // Rows of the inverse are the cross products of column pairs over the determinant
+        let r0 = self.y_axis.cross(self.z_axis) / det;
+        let r1 = self.z_axis.cross(self.x_axis) / det;
+        let r2 = self.x_axis.cross(self.y_axis) / det;
+        Some(Mat3::from_cols(r0, r1, r2).transpose())
+    }
+
+    /// Multiplies a column vector.
+    pub fn mul_vec3(&self, v: Vec3) -> Vec3 {
+        self.x_axis * v.x + self.y_axis * v.y + self.z_axis * v.z
+    }
+
+    /// Transforms a 2D point (applies translation).
+    pub fn transform_point2(&self, p: Vec2) -> Vec2 {
+        self.mul_vec3(p.extend(1.0)).truncate()
+    }
+
+    /// Transforms a 2D direction (ignores translation).
+    pub fn transform_vector2(&self, v: Vec2) -> Vec2 {
+        self.mul_vec3(v.extend(0.0)).truncate()
+    }
+
+    /// Returns `true` if every element differs from `rhs` by at most `epsilon`.
+    pub fn abs_diff_eq(&self, rhs: &Mat3, epsilon: f32) -> bool {
+        self.x_axis.abs_diff_eq(rhs.x_axis, epsilon)
+            && self.y_axis.abs_diff_eq(rhs.y_axis, epsilon)
+            && self.z_axis.abs_diff_eq(rhs.z_axis, epsilon)
+    }
+}
+
+impl Default for Mat3 {
+    /// Returns the identity matrix.
+    fn default() -> Self {
+        Mat3::IDENTITY
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Mat3;
+
+    fn mul(self, rhs: Mat3) -> Mat3 {
+        Mat3::from_cols(
+            self.mul_vec3(rhs.x_axis),
+            self.mul_vec3(rhs.y_axis),
+            self.mul_vec3(rhs.z_axis),
+        )
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl Mat4 {
+    pub const IDENTITY: Mat4 = Mat4::from_cols(
+        Vec4::new(1.0, 0.0, 0.0, 0.0),
+        Vec4::new(0.0, 1.0, 0.0, 0.0),
+        Vec4::new(0.0, 0.0, 1.0, 0.0),
+        Vec4::new(0.0, 0.0, 0.0, 1.0),
+    );
+    pub const ZERO: Mat4 = Mat4::from_cols(Vec4::ZERO, Vec4::ZERO, Vec4::ZERO, Vec4::ZERO);
+
+    /// Creates a matrix from its columns.
+    pub const fn from_cols(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+
+    /// Creates a matrix from a column-major array of 16 elements.
+    pub fn from_cols_array(m: &[f32; 16]) -> Self {
+        Self::from_cols(
+            Vec4::new(m[0], m[1], m[2], m[3]),
+            Vec4::new(m[4], m[5], m[6], m[7]),
+            Vec4::new(m[8], m[9], m[10], m[11]),
+            Vec4::new(m[12], m[13], m[14], m[15]),
+        )
+    }
+
+    /// Returns the elements as a column-major array.
+    pub fn to_cols_array(&self) -> [f32; 16] {
+        let (x, y, z, w) = (self.x_axis, self.y_axis, self.z_axis, self.w_axis);
+        [
+            x.x, x.y, x.z, x.w, y.x, y.y, y.z, y.w, z.x, z.y, z.z, z.w, w.x, w.y, w.z, w.w,
+        ]
+    }
+
+    /// Creates a translation.
+    pub fn from_translation(translation: Vec3) -> Self {
+        let mut m = Self::IDENTITY;
+        m.w_axis = translation.extend(1.0);
+        m
+    }
+
+    /// Creates a non-uniform scale.
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self::from_cols(
+            Vec4::new(scale.x, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, scale.y, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, scale.z, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Creates a rotation from a unit quaternion.
+    pub fn from_quat(rotation: Quat) -> Self {
+        Self::from_cols(
+            (rotation * Vec3::X).extend(0.0),
+            (rotation * Vec3::Y).extend(0.0),
+            (rotation * Vec3::Z).extend(0.0),
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Creates a transform that scales, then rotates, then translates.
+    pub fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Self {
+        let rotation = Self::from_quat(rotation);
+        Self::from_cols(
+            rotation.x_axis * scale.x,
+            rotation.y_axis * scale.y,
+            rotation.z_axis * scale.z,
+            translation.extend(1.0),
+        )
+    }
+
+    /// Creates a right-handed view matrix looking from `eye` towards `target`.
+    ///
+    /// The camera looks down its local -z axis with `up` roughly +y.
+    pub fn look_at_rh(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        let forward = (target - eye).normalize_or_zero();
+        let side = forward.cross(up).normalize_or_zero();
+        let up = side.cross(forward);
+        Self::from_cols(
+            Vec4::new(side.x, up.x, -forward.x, 0.0),
+            Vec4::new(side.y, up.y, -forward.y, 0.0),
+            Vec4::new(side.z, up.z, -forward.z, 0.0),
+            Vec4::new(-side.dot(eye), -up.dot(eye), forward.dot(eye), 1.0),
+        )
+    }
+
+    /// Creates a right-handed perspective projection.
+    ///
+    /// `fov_y` is the vertical field of view in radians; `near` and `far` are
+    /// positive distances mapped to clip depth -1 and 1.
+    pub fn perspective_rh(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / (fov_y * 0.5).tan();
+        let range = near - far;
+        Self::from_cols(
+            Vec4::new(f / aspect, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, f, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, (far + near) / range, -1.0),
+            Vec4::new(0.0, 0.0, 2.0 * far * near / range, 0.0),
+        )
+    }
+
+    /// Creates a right-handed orthographic projection.
+    ///
+    /// Maps the box `left..right`, `bottom..top`, `-near..-far` to clip space.
+    pub fn orthographic_rh(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let width = right - left;
+        let height = top - bottom;
+        let depth = far - near;
+        Self::from_cols(
+            Vec4::new(2.0 / width, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 2.0 / height, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, -2.0 / depth, 0.0),
+            Vec4::new(
+                -(right + left) / width,
+                -(top + bottom) / height,
+                -(far + near) / depth,
+                1.0,
+            ),
+        )
+    }
+
+    /// Returns row `i` as a vector.
+    fn row(&self, i: usize) -> Vec4 {
+        let pick = |v: Vec4| [v.x, v.y, v.z, v.w][i];
+        Vec4::new(
+            pick(self.x_axis),
+            pick(self.y_axis),
+            pick(self.z_axis),
+            pick(self.w_axis),
+        )
+    }
+
+    /// Returns the transpose.
+    pub fn transpose(&self) -> Mat4 {
+        Mat4::from_cols(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    /// Returns the determinant.
+    pub fn determinant(&self) -> f32 {
+        let (_, det) = self.adjugate();
+        det
+    }
+
+    /// Returns the inverse, or `None` if the matrix is singular.
+    ///
+    /// Singular means an exactly zero or non-finite determinant, as for [`Mat3::inverse`].
+    pub fn inverse(&self) -> Option<Mat4> {
+        let (adjugate, det) = self.adjugate();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        let m = adjugate.map(|value| value * inv_det);
+        Some(Mat4::from_cols_array(&m))
+    }
+
+    /// Returns the adjugate (column-major) and the determinant via cofactor expansion.
+    fn adjugate(&self) -> ([f32; 16], f32) {
+        let m = self.to_cols_array();
+        let mut inv = [0.0f32; 16];
+
+        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14]
+            + m[13] * m[6] * m[11]
+            - m[13] * m[7] * m[10];
+        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14]
+            - m[12] * m[6] * m[11]
+            + m[12] * m[7] * m[10];
+        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13]
+            + m[12] * m[5] * m[11]
+            - m[12] * m[7] * m[9];
+        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13]
+            - m[12] * m[5] * m[10]
+            + m[12] * m[6] * m[9];
+        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14]
+            - m[13] * m[2] * m[11]
+            + m[13] * m[3] * m[10];
+        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14]
+            + m[12] * m[2] * m[11]
+            - m[12] * m[3] * m[10];
+        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13]
+            - m[12] * m[1] * m[11]
+            + m[12] * m[3] * m[9];
+        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13]
+            + m[12] * m[1] * m[10]
+            - m[12] * m[2] * m[9];
+        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14]
+            + m[13] * m[2] * m[7]
+            - m[13] * m[3] * m[6];
+        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14]
+            - m[12] * m[2] * m[7]
+            + m[12] * m[3] * m[6];
+        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13]
+            + m[12] * m[1] * m[7]
+            - m[12] * m[3] * m[5];
+        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13]
+            - m[12] * m[1] * m[6]
+            + m[12] * m[2] * m[5];
+        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10]
+            - m[9] * m[2] * m[7]
+            + m[9] * m[3] * m[6];
+        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10]
+            + m[8] * m[2] * m[7]
+            - m[8] * m[3] * m[6];
+        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9]
+            - m[8] * m[1] * m[7]
+            + m[8] * m[3] * m[5];
+        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9]
+            + m[8] * m[1] * m[6]
+            - m[8] * m[2] * m[5];
+
+        let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+        (inv, det)
+    }
+
+    /// Multiplies a column vector.
+    pub fn mul_vec4(&self, v: Vec4) -> Vec4 {
+        self.x_axis * v.x + self.y_axis * v.y + self.z_axis * v.z + self.w_axis * v.w
+    }
+
+    /// Transforms a point, dividing by w (so it also works for projections).
+    pub fn project_point3(&self, p: Vec3) -> Vec3 {
+        let clip = self.mul_vec4(p.extend(1.0));
+        clip.truncate() / clip.w
+    }
+
+    /// Transforms a point by an affine matrix (applies translation, no w divide).
+    pub fn transform_point3(&self, p: Vec3) -> Vec3 {
+        self.mul_vec4(p.extend(1.0)).truncate()
+    }
+
+    /// Transforms a direction (ignores translation).
+    pub fn transform_vector3(&self, v: Vec3) -> Vec3 {
+        self.mul_vec4(v.extend(0.0)).truncate()
+    }
+
+    /// Returns `true` if every element differs from `rhs` by at most `epsilon`.
+    pub fn abs_diff_eq(&self, rhs: &Mat4, epsilon: f32) -> bool {
+        self.x_axis.abs_diff_eq(rhs.x_axis, epsilon)
+            && self.y_axis.abs_diff_eq(rhs.y_axis, epsilon)
+            && self.z_axis.abs_diff_eq(rhs.z_axis, epsilon)
+            && self.w_axis.abs_diff_eq(rhs.w_axis, epsilon)
+    }
+}
+
+impl Default for Mat4 {
+    /// Returns the identity matrix.
+    fn default() -> Self {
+        Mat4::IDENTITY
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: Mat4) -> Mat4 {
+        Mat4::from_cols(
+            self.mul_vec4(rhs.x_axis),
+            self.mul_vec4(rhs.y_axis),
+            self.mul_vec4(rhs.z_axis),
+            self.mul_vec4(rhs.w_axis),
+        )
+    }
+}
+
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        self.mul_vec4(rhs)
+    }
+}
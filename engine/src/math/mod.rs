@@ -4,5 +4,37 @@
 //! Common math helpers.
 //! Keeps math usage consistent across engine.
 
-// pub struct Vec2 { ... }
-// pub struct Transform { ... }
+mod easing;
+mod geometry;
+mod mat;
+mod noise;
+mod quat;
+mod random;
+mod vec;
+
+pub use easing::Easing;
+pub use geometry::{Aabb, Circle, Frustum, OrientedRect, Plane, Ray, Sphere, point_in_polygon};
+pub use mat::{Mat3, Mat4};
+pub use noise::{Fbm, Noise};
+pub use quat::Quat;
+pub use random::{Random, Rng, mix64};
+pub use vec::{Vec2, Vec3, Vec4};
+
+/// Tolerance used to treat lengths and determinants as zero.
+pub const EPSILON: f32 = 1e-6;
+
+/// Linearly interpolates between `a` (t = 0) and `b` (t = 1).
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Returns where `value` lies between `a` and `b`, as `t` in the sense of [`lerp`].
+///
+/// Returns 0.0 when `a == b`.
+pub fn inverse_lerp(a: f32, b: f32, value: f32) -> f32 {
+    if (b - a).abs() <= EPSILON {
+        0.0
+    } else {
+        (value - a) / (b - a)
+    }
+}
@@ -0,0 +1,351 @@
+//! Procedural noise.
+//!
+//! Seeded value, Perlin and simplex noise in 1D to 3D.
+//! Fractal Brownian motion and domain warping.
+//! Same seed and input give the same output on every run.
+
+use super::{Rng, Vec2, Vec3, lerp};
+
+/// 2D gradients: the axes and diagonals, all unit length.
+const GRAD2: [(f32, f32); 8] = [
+    (1.0, 0.0),
+    (-1.0, 0.0),
+    (0.0, 1.0),
+    (0.0, -1.0),
+    (
+        std::f32::consts::FRAC_1_SQRT_2,
+        std::f32::consts::FRAC_1_SQRT_2,
+    ),
+    (
+        -std::f32::consts::FRAC_1_SQRT_2,
+        std::f32::consts::FRAC_1_SQRT_2,
+    ),
+    (
+        std::f32::consts::FRAC_1_SQRT_2,
+        -std::f32::consts::FRAC_1_SQRT_2,
+    ),
+    (
+        -std::f32::consts::FRAC_1_SQRT_2,
+        -std::f32::consts::FRAC_1_SQRT_2,
+    ),
+];
+
+/// 3D gradients: the midpoints of the cube edges.
+const GRAD3: [(f32, f32, f32); 12] = [
+    (1.0, 1.0, 0.0),
+    (-1.0, 1.0, 0.0),
+    (1.0, -1.0, 0.0),
+    (-1.0, -1.0, 0.0),
+    (1.0, 0.0, 1.0),
+    (-1.0, 0.0, 1.0),
+    (1.0, 0.0, -1.0),
+    (-1.0, 0.0, -1.0),
+    (0.0, 1.0, 1.0),
+    (0.0, -1.0, 1.0),
+    (0.0, 1.0, -1.0),
+    (0.0, -1.0, -1.0),
+];
+
+/// Settings for fractal Brownian motion: several octaves of noise summed together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fbm {
+    /// Number of layers. Zero octaves always yields 0.0.
+    pub octaves: u32,
+    /// Frequency multiplier between octaves.
+    pub lacunarity: f32,
+    /// Amplitude multiplier between octaves.
+    pub gain: f32,
+}
+
+impl Default for Fbm {
+    /// Returns 5 octaves, lacunarity 2.0, gain 0.5.
+    fn default() -> Self {
+        Self {
+            octaves: 5,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+}
+
+impl Fbm {
+    /// Sums octaves of `noise` at `point`, normalized back to the range of `noise`.
+    pub fn sample<P>(&self, point: P, noise: impl Fn(P) -> f32) -> f32
+    where
+        P: Copy + std::ops::Mul<f32, Output = P>,
+    {
+        let mut sum = 0.0;
+        let mut norm = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        for _ in 0..self.octaves {
+            sum += noise(point * frequency) * amplitude;
+            norm += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+        if norm > 0.0 { sum / norm } else { 0.0 }
+    }
+}
+
+/// A seeded source of coherent noise.
+///
+/// All functions return values in `-1.0..=1.0` and are continuous in their input.
+/// Perlin noise is 0.0 at every integer lattice point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Noise {
+    seed: u64,
+    perm: [u8; 512],
+}
+
+impl Noise {
+    /// Creates a noise source. Different seeds give unrelated patterns.
+    pub fn new(seed: u64) -> Self {
+        let mut table: Vec<u8> = (0..=255).collect();
+        Rng::new(seed).shuffle(&mut table);
+
+        let mut perm = [0u8; 512];
+        for (i, slot) in perm.iter_mut().enumerate() {
+            *slot = table[i & 255];
+        }
+        Self { seed, perm }
+    }
+
+    /// Returns the seed the source was created with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    fn hash1(&self, x: i32) -> usize {
+        self.perm[(x & 255) as usize] as usize
+    }
+
+    fn hash2(&self, x: i32, y: i32) -> usize {
+        self.perm[self.hash1(x) + (y & 255) as usize] as usize
+    }
+
+    fn hash3(&self, x: i32, y: i32, z: i32) -> usize {
+        self.perm[self.hash2(x, y) + (z & 255) as usize] as usize
+    }
+
+    /// Maps a hash to a lattice value in `-1.0..=1.0`.
+    fn lattice_value(hash: usize) -> f32 {
+        hash as f32 / 127.5 - 1.0
+    }
+
+    /// 1D value noise: smoothly interpolated random values at integer points.
+    pub fn value1(&self, x: f32) -> f32 {
+        let x0 = x.floor();
+        let i = x0 as i32;
+        let a = Self::lattice_value(self.hash1(i));
+        let b = Self::lattice_value(self.hash1(i + 1));
+        lerp(a, b, fade(x - x0))
+    }
+
+    /// 2D value noise.
+    pub fn value2(&self, p: Vec2) -> f32 {
+        let (x0, y0) = (p.x.floor(), p.y.floor());
+        let (i, j) = (x0 as i32, y0 as i32);
+        let (u, v) = (fade(p.x - x0), fade(p.y - y0));
+        let corner = |di, dj| Self::lattice_value(self.hash2(i + di, j + dj));
+        lerp(
+            lerp(corner(0, 0), corner(1, 0), u),
+            lerp(corner(0, 1), corner(1, 1), u),
+            v,
+        )
+    }
+
+    /// 3D value noise.
+    pub fn value3(&self, p: Vec3) -> f32 {
+        let (x0, y0, z0) = (p.x.floor(), p.y.floor(), p.z.floor());
+        let (i, j, k) = (x0 as i32, y0 as i32, z0 as i32);
+        let (u, v, w) = (fade(p.x - x0), fade(p.y - y0), fade(p.z - z0));
+        let corner = |di, dj, dk| Self::lattice_value(self.hash3(i + di, j + dj, k + dk));
+        let face = |dk| {
+            lerp(
+                lerp(corner(0, 0, dk), corner(1, 0, dk), u),
+                lerp(corner(0, 1, dk), corner(1, 1, dk), u),
+                v,
+            )
+        };
+        lerp(face(0), face(1), w)
+    }
+
+    /// 1D gradient (Perlin) noise.
+    pub fn perlin1(&self, x: f32) -> f32 {
+        let x0 = x.floor();
+        let i = x0 as i32;
+        let f = x - x0;
+        let grad = |i: i32, d: f32| Self::lattice_value(self.hash1(i)) * d;
+        // A unit gradient peaks at 0.5 halfway between lattice points
+        2.0 * lerp(grad(i, f), grad(i + 1, f - 1.0), fade(f))
+    }
+
+    /// 2D gradient (Perlin) noise.
+    pub fn perlin2(&self, p: Vec2) -> f32 {
+        let (x0, y0) = (p.x.floor(), p.y.floor());
+        let (i, j) = (x0 as i32, y0 as i32);
+        let (fx, fy) = (p.x - x0, p.y - y0);
+        let grad = |di: i32, dj: i32| {
+            let (gx, gy) = GRAD2[self.hash2(i + di, j + dj) & 7];
+            gx * (fx - di as f32) + gy * (fy - dj as f32)
+        };
+        let (u, v) = (fade(fx), fade(fy));
+        let n = lerp(
+            lerp(grad(0, 0), grad(1, 0), u),
+            lerp(grad(0, 1), grad(1, 1), u),
+            v,
+        );
+        // Unit gradients reach at most sqrt(1/2)
+        n * std::f32::consts::SQRT_2
+    }
+
+    /// 3D gradient (Perlin) noise.
+    pub fn perlin3(&self, p: Vec3) -> f32 {
+        let (x0, y0, z0) = (p.x.floor(), p.y.floor(), p.z.floor());
+        let (i, j, k) = (x0 as i32, y0 as i32, z0 as i32);
+        let (fx, fy, fz) = (p.x - x0, p.y - y0, p.z - z0);
+        let grad = |di: i32, dj: i32, dk: i32| {
+            let (gx, gy, gz) = GRAD3[self.hash3(i + di, j + dj, k + dk) % 12];
+            gx * (fx - di as f32) + gy * (fy - dj as f32) + gz * (fz - dk as f32)
+        };
+        let (u, v, w) = (fade(fx), fade(fy), fade(fz));
+        let face = |dk| {
+            lerp(
+                lerp(grad(0, 0, dk), grad(1, 0, dk), u),
+                lerp(grad(0, 1, dk), grad(1, 1, dk), u),
+                v,
+            )
+        };
+        lerp(face(0), face(1), w).clamp(-1.0, 1.0)
+    }
+
+    /// 2D simplex noise: fewer directional artifacts and cheaper than Perlin in 2D.
+    pub fn simplex2(&self, p: Vec2) -> f32 {
+        const F2: f32 = 0.366_025_42; // (sqrt(3) - 1) / 2
+        const G2: f32 = 0.211_324_87; // (3 - sqrt(3)) / 6
+
+        // Skew into the simplex grid to find the containing cell
+        let s = (p.x + p.y) * F2;
+        let i = (p.x + s).floor();
+        let j = (p.y + s).floor();
+        let t = (i + j) * G2;
+        let x0 = p.x - (i - t);
+        let y0 = p.y - (j - t);
+
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+        let corners = [
+            (0, 0, x0, y0),
+            (i1, j1, x0 - i1 as f32 + G2, y0 - j1 as f32 + G2),
+            (1, 1, x0 - 1.0 + 2.0 * G2, y0 - 1.0 + 2.0 * G2),
+        ];
+
+        let (i, j) = (i as i32, j as i32);
+        let mut n = 0.0;
+        for (di, dj, x, y) in corners {
+            let falloff = 0.5 - x * x - y * y;
+            if falloff > 0.0 {
+                let (gx, gy) = GRAD2[self.hash2(i + di, j + dj) & 7];
+                let f2 = falloff * falloff;
+                n += f2 * f2 * (gx * x + gy * y);
+            }
+        }
+        (n * 99.2).clamp(-1.0, 1.0)
+    }
+
+    /// 3D simplex noise.
+    pub fn simplex3(&self, p: Vec3) -> f32 {
+        const F3: f32 = 1.0 / 3.0;
+        const G3: f32 = 1.0 / 6.0;
+
+        let s = (p.x + p.y + p.z) * F3;
+        let i = (p.x + s).floor();
+        let j = (p.y + s).floor();
+        let k = (p.z + s).floor();
+        let t = (i + j + k) * G3;
+        let x0 = p.x - (i - t);
+        let y0 = p.y - (j - t);
+        let z0 = p.z - (k - t);
+
+        // Pick the two middle corners of the tetrahedron by ordering the offsets
+        let (first, second) = if x0 >= y0 {
+            if y0 >= z0 {
+                ((1, 0, 0), (1, 1, 0))
+            } else if x0 >= z0 {
+                ((1, 0, 0), (1, 0, 1))
+            } else {
+                ((0, 0, 1), (1, 0, 1))
+            }
+        } else if y0 < z0 {
+            ((0, 0, 1), (0, 1, 1))
+        } else if x0 < z0 {
+            ((0, 1, 0), (0, 1, 1))
+        } else {
+            ((0, 1, 0), (1, 1, 0))
+        };
+
+        let corner = |(a, b, c): (i32, i32, i32), offset: f32| {
+            (
+                (a, b, c),
+                x0 - a as f32 + offset,
+                y0 - b as f32 + offset,
+                z0 - c as f32 + offset,
+            )
+        };
+        let corners = [
+            corner((0, 0, 0), 0.0),
+            corner(first, G3),
+            corner(second, 2.0 * G3),
+            corner((1, 1, 1), 3.0 * G3),
+        ];
+
+        let (i, j, k) = (i as i32, j as i32, k as i32);
+        let mut n = 0.0;
+        for ((di, dj, dk), x, y, z) in corners {
+            let falloff = 0.6 - x * x - y * y - z * z;
+            if falloff > 0.0 {
+                let (gx, gy, gz) = GRAD3[self.hash3(i + di, j + dj, k + dk) % 12];
+                let f2 = falloff * falloff;
+                n += f2 * f2 * (gx * x + gy * y + gz * z);
+            }
+        }
+        (n * 32.0).clamp(-1.0, 1.0)
+    }
+
+    /// Fractal Brownian motion over 2D simplex noise.
+    pub fn fbm2(&self, p: Vec2, fbm: &Fbm) -> f32 {
+        fbm.sample(p, |p| self.simplex2(p))
+    }
+
+    /// Fractal Brownian motion over 3D simplex noise.
+    pub fn fbm3(&self, p: Vec3, fbm: &Fbm) -> f32 {
+        fbm.sample(p, |p| self.simplex3(p))
+    }
+
+    /// Domain-warped 2D fBm: the input is displaced by fBm before sampling again.
+    ///
+    /// `strength` is the displacement in input units; 0.0 gives plain [`Self::fbm2`].
+    pub fn warp2(&self, p: Vec2, strength: f32, fbm: &Fbm) -> f32 {
+        // Offsets decorrelate the two displacement channels from the final sample
+        let offset = Vec2::new(
+            self.fbm2(p + Vec2::new(5.2, 1.3), fbm),
+            self.fbm2(p + Vec2::new(-8.3, 2.8), fbm),
+        );
+        self.fbm2(p + offset * strength, fbm)
+    }
+
+    /// Domain-warped 3D fBm, see [`Self::warp2`].
+    pub fn warp3(&self, p: Vec3, strength: f32, fbm: &Fbm) -> f32 {
+        let offset = Vec3::new(
+            self.fbm3(p + Vec3::new(5.2, 1.3, -2.1), fbm),
+            self.fbm3(p + Vec3::new(-8.3, 2.8, 4.4), fbm),
+            self.fbm3(p + Vec3::new(1.7, -9.2, 3.6), fbm),
+        );
+        self.fbm3(p + offset * strength, fbm)
+    }
+}
+
+/// Perlin's quintic fade curve, with zero first and second derivatives at 0 and 1.
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
@@ -0,0 +1,169 @@
+//! Quaternions.
+//!
+//! Unit quaternions for 3D rotation.
+//! Composition, vector rotation and interpolation.
+
+use std::ops::Mul;
+
+use super::{EPSILON, Vec3, Vec4};
+
+/// A rotation quaternion `x*i + y*j + z*k + w`.
+///
+/// Rotation methods assume the quaternion is normalized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat {
+    /// The identity rotation.
+    pub const IDENTITY: Quat = Quat::from_xyzw(0.0, 0.0, 0.0, 1.0);
+
+    /// Creates a quaternion from raw components. Does not normalize.
+    pub const fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a rotation of `angle` radians around a unit `axis`.
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        let v = axis * sin;
+        Self::from_xyzw(v.x, v.y, v.z, cos)
+    }
+
+    /// Creates a rotation of `angle` radians around the x axis.
+    pub fn from_rotation_x(angle: f32) -> Self {
+        Self::from_axis_angle(Vec3::X, angle)
+    }
+
+    /// Creates a rotation of `angle` radians around the y axis.
+    pub fn from_rotation_y(angle: f32) -> Self {
+        Self::from_axis_angle(Vec3::Y, angle)
+    }
+
+    /// Creates a rotation of `angle` radians around the z axis (the 2D rotation).
+    pub fn from_rotation_z(angle: f32) -> Self {
+        Self::from_axis_angle(Vec3::Z, angle)
+    }
+
+    fn to_vec4(self) -> Vec4 {
+        Vec4::new(self.x, self.y, self.z, self.w)
+    }
+
+    fn from_vec4(v: Vec4) -> Self {
+        Self::from_xyzw(v.x, v.y, v.z, v.w)
+    }
+
+    /// Returns the 4D dot product, the cosine of half the angle between two rotations.
+    pub fn dot(self, rhs: Quat) -> f32 {
+        self.to_vec4().dot(rhs.to_vec4())
+    }
+
+    /// Returns the length of the quaternion.
+    pub fn length(self) -> f32 {
+        self.to_vec4().length()
+    }
+
+    /// Returns the quaternion scaled to unit length, or identity for a zero quaternion.
+    pub fn normalize(self) -> Quat {
+        self.to_vec4()
+            .try_normalize()
+            .map_or(Quat::IDENTITY, Quat::from_vec4)
+    }
+
+    /// Returns the conjugate, which is the inverse for unit quaternions.
+    pub fn conjugate(self) -> Quat {
+        Quat::from_xyzw(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the inverse rotation, or `None` for a zero quaternion.
+    pub fn inverse(self) -> Option<Quat> {
+        let length_squared = self.dot(self);
+        if length_squared <= EPSILON {
+            return None;
+        }
+        Some(Quat::from_vec4(self.conjugate().to_vec4() / length_squared))
+    }
+
+    /// Rotates a vector.
+    pub fn mul_vec3(self, v: Vec3) -> Vec3 {
+        let u = Vec3::new(self.x, self.y, self.z);
+        let t = u.cross(v) * 2.0;
+        v + t * self.w + u.cross(t)
+    }
+
+    /// Normalized linear interpolation: fast, but not constant angular speed.
+    pub fn lerp(self, rhs: Quat, t: f32) -> Quat {
+        let rhs = if self.dot(rhs) < 0.0 { -rhs } else { rhs };
+        Quat::from_vec4(self.to_vec4().lerp(rhs.to_vec4(), t)).normalize()
+    }
+
+    /// Spherical linear interpolation along the shortest arc, at constant angular speed.
+    pub fn slerp(self, rhs: Quat, t: f32) -> Quat {
+        let mut cos = self.dot(rhs);
+        let mut rhs = rhs;
+        if cos < 0.0 {
+            cos = -cos;
+            rhs = -rhs;
+        }
+
+        // Nearly parallel: the sine below approaches zero, so fall back to lerp
+        if cos > 1.0 - 1e-4 {
+            return self.lerp(rhs, t);
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((1.0 - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+        Quat::from_vec4(self.to_vec4() * a + rhs.to_vec4() * b)
+    }
+
+    /// Returns `true` if both quaternions represent the same rotation within `epsilon`.
+    ///
+    /// `q` and `-q` are the same rotation, so both signs are accepted.
+    pub fn abs_diff_eq(self, rhs: Quat, epsilon: f32) -> bool {
+        self.to_vec4().abs_diff_eq(rhs.to_vec4(), epsilon)
+            || self.to_vec4().abs_diff_eq(-rhs.to_vec4(), epsilon)
+    }
+}
+
+impl Default for Quat {
+    /// Returns the identity rotation.
+    fn default() -> Self {
+        Quat::IDENTITY
+    }
+}
+
+impl Mul for Quat {
+    type Output = Quat;
+
+    /// Composes rotations: `(a * b).mul_vec3(v)` rotates by `b` first, then `a`.
+    fn mul(self, rhs: Quat) -> Quat {
+        Quat::from_xyzw(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl std::ops::Neg for Quat {
+    type Output = Quat;
+
+    fn neg(self) -> Quat {
+        Quat::from_xyzw(-self.x, -self.y, -self.z, -self.w)
+    }
+}
@@ -0,0 +1,234 @@
+//! Deterministic random numbers.
+//!
+//! Seedable PCG32 generator.
+//! Ranges, weighted choice, shuffling and direction sampling.
+//! Keyed sub-streams that do not depend on call order.
+
+use std::collections::BTreeMap;
+
+use super::{Vec2, Vec3};
+
+/// A small, fast, seedable pseudo-random generator (PCG-XSH-RR 64/32).
+///
+/// The same seed always produces the same sequence on every platform.
+/// Not suitable for cryptography.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+    increment: u64,
+}
+
+impl Rng {
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+    /// Creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, 0)
+    }
+
+    /// Creates a generator from a seed and a stream selector.
+    ///
+    /// Different streams with the same seed produce unrelated sequences.
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+
+    /// Returns the next 32 random bits.
+    pub fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rotation = (old >> 59) as u32;
+        xorshifted.rotate_right(rotation)
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+
+    /// Returns a float uniformly distributed in `0.0..1.0`.
+    pub fn next_f32(&mut self) -> f32 {
+        // 24 bits fill the f32 mantissa exactly, so every value is representable
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Returns a float uniformly distributed in `min..max`.
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    /// Returns an integer uniformly distributed in `min..max`.
+    ///
+    /// Returns `min` if the range is empty. Unbiased (uses rejection sampling).
+    pub fn range_i32(&mut self, min: i32, max: i32) -> i32 {
+        if max <= min {
+            return min;
+        }
+        let span = (max as i64 - min as i64) as u32;
+        min.wrapping_add(self.below(span) as i32)
+    }
+
+    /// Returns an index uniformly distributed in `0..len`. `len` must be non-zero.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "cannot pick an index from an empty range");
+        self.below(len as u32) as usize
+    }
+
+    /// Returns a value in `0..bound` without modulo bias.
+    fn below(&mut self, bound: u32) -> u32 {
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let r = self.next_u32();
+            if r >= threshold {
+                return r % bound;
+            }
+        }
+    }
+
+    /// Returns `true` with the given probability.
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+
+    /// Returns a random element, or `None` if the slice is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.index(items.len())])
+        }
+    }
+
+    /// Picks an index with probability proportional to its weight.
+    ///
+    /// Negative and non-finite weights count as zero. Returns `None` if no weight is positive.
+    pub fn weighted_index(&mut self, weights: &[f32]) -> Option<usize> {
+        let weight = |w: f32| if w.is_finite() && w > 0.0 { w } else { 0.0 };
+        let total: f32 = weights.iter().map(|&w| weight(w)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut target = self.next_f32() * total;
+        let mut last_positive = None;
+        for (i, &w) in weights.iter().enumerate() {
+            let w = weight(w);
+            if w <= 0.0 {
+                continue;
+            }
+            if target < w {
+                return Some(i);
+            }
+            target -= w;
+            last_positive = Some(i);
+        }
+        // Rounding can leave a sliver past the last bucket
+        last_positive
+    }
+
+    /// Shuffles the slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+
+    /// Returns a uniformly distributed 2D unit vector.
+    pub fn unit_vec2(&mut self) -> Vec2 {
+        Vec2::from_angle(self.range_f32(0.0, std::f32::consts::TAU))
+    }
+
+    /// Returns a uniformly distributed 3D unit vector.
+    pub fn unit_vec3(&mut self) -> Vec3 {
+        let z = self.range_f32(-1.0, 1.0);
+        let ring = (1.0 - z * z).max(0.0).sqrt();
+        let xy = self.unit_vec2() * ring;
+        Vec3::new(xy.x, xy.y, z)
+    }
+
+    /// Returns a point uniformly distributed inside the unit circle.
+    pub fn in_unit_circle(&mut self) -> Vec2 {
+        self.unit_vec2() * self.next_f32().sqrt()
+    }
+}
+
+/// Mixes a 64-bit value into a well-distributed one (SplitMix64 finalizer).
+pub fn mix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Hashes a name into a stream key (FNV-1a), stable across runs and platforms.
+fn name_key(name: &str) -> u64 {
+    name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Resource providing all randomness for a deterministic simulation.
+///
+/// Systems should draw from a named stream (see [`Random::stream`]) instead of the
+/// shared one, so adding or reordering systems does not change what other systems
+/// see. Per-entity values come from [`Random::keyed`] with the entity as a key,
+/// which depends only on the seed and the keys, never on iteration order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Random {
+    seed: u64,
+    shared: Rng,
+    streams: BTreeMap<String, Rng>,
+}
+
+impl Random {
+    /// Creates the resource from a master seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            shared: Rng::new(seed),
+            streams: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the master seed.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the shared generator.
+    pub fn rng(&mut self) -> &mut Rng {
+        &mut self.shared
+    }
+
+    /// Returns the persistent generator for a named stream, creating it on first use.
+    ///
+    /// Each name yields its own sequence, derived only from the seed and the name.
+    pub fn stream(&mut self, name: &str) -> &mut Rng {
+        let seed = self.seed;
+        self.streams
+            .entry(name.to_string())
+            .or_insert_with(|| Rng::with_stream(mix64(seed ^ name_key(name)), name_key(name)))
+    }
+
+    /// Returns a fresh generator determined by the seed and `keys` alone.
+    ///
+    /// Calling it twice with the same keys gives the same sequence, so include
+    /// something like the frame number when new values are wanted each frame.
+    pub fn keyed(&self, keys: &[u64]) -> Rng {
+        let mixed = keys
+            .iter()
+            .fold(mix64(self.seed), |acc, &key| mix64(acc ^ mix64(key)));
+        Rng::with_stream(mixed, mix64(mixed))
+    }
+}
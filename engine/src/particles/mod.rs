@@ -0,0 +1,416 @@
+//! Particles.
+//!
+//! Emitters simulating many short-lived particles.
+//! Compact per-emitter storage instead of one entity per particle.
+//! Color and size curves over each particle's life.
+//! Deterministic spawning from a seeded generator.
+
+use std::ops::Range;
+
+use crate::ecs::components::Color;
+use crate::math::{Rng, Vec3};
+use crate::render::{
+    LinearColor, MaterialId, Materials, Meshes, RenderCommand, TextureId, Transform, UvRect,
+};
+
+/// A value over a particle's life, from 0.0 (spawn) to 1.0 (death).
+///
+/// Keys are interpolated linearly; before the first and after the last key the
+/// nearest key holds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curve {
+    keys: Vec<(f32, f32)>,
+}
+
+impl Curve {
+    /// Creates a curve that is `value` throughout.
+    pub fn constant(value: f32) -> Self {
+        Self {
+            keys: vec![(0.0, value)],
+        }
+    }
+
+    /// Creates a curve going from `start` at spawn to `end` at death.
+    pub fn linear(start: f32, end: f32) -> Self {
+        Self {
+            keys: vec![(0.0, start), (1.0, end)],
+        }
+    }
+
+    /// Returns the curve with a key added at life fraction `t`, replacing any key at the same `t`.
+    pub fn with_key(mut self, t: f32, value: f32) -> Self {
+        insert_key(&mut self.keys, t, value);
+        self
+    }
+
+    /// Returns the value at life fraction `t`.
+    pub fn sample(&self, t: f32) -> f32 {
+        sample_keys(&self.keys, t, |a, b, f| a + (b - a) * f)
+    }
+}
+
+/// A color over a particle's life, from 0.0 (spawn) to 1.0 (death).
+///
+/// Keys are interpolated in linear light, like [`Curve`] otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    keys: Vec<(f32, LinearColor)>,
+}
+
+impl Gradient {
+    /// Creates a gradient that is `color` throughout.
+    pub fn constant(color: Color) -> Self {
+        Self {
+            keys: vec![(0.0, color.to_linear())],
+        }
+    }
+
+    /// Creates a gradient going from `start` at spawn to `end` at death.
+    pub fn linear(start: Color, end: Color) -> Self {
+        Self {
+            keys: vec![(0.0, start.to_linear()), (1.0, end.to_linear())],
+        }
+    }
+
+    /// Returns the gradient with a key added at life fraction `t`, replacing any key at the same `t`.
+    pub fn with_key(mut self, t: f32, color: Color) -> Self {
+        insert_key(&mut self.keys, t, color.to_linear());
+        self
+    }
+
+    /// Returns the color at life fraction `t`.
+    pub fn sample(&self, t: f32) -> Color {
+        Color::from(sample_keys(&self.keys, t, LinearColor::lerp))
+    }
+}
+
+/// A live particle, as returned by [`ParticleEmitter::particles`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    /// Seconds since the particle spawned.
+    pub age: f32,
+    /// Seconds the particle lives in total.
+    pub lifetime: f32,
+}
+
+impl Particle {
+    /// Returns how far through its life the particle is, from 0.0 to 1.0.
+    pub fn life_fraction(&self) -> f32 {
+        if self.lifetime <= 0.0 {
+            1.0
+        } else {
+            (self.age / self.lifetime).min(1.0)
+        }
+    }
+}
+
+/// Live particles stored field by field, so updates walk tightly packed arrays.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ParticleStorage {
+    positions: Vec<Vec3>,
+    velocities: Vec<Vec3>,
+    ages: Vec<f32>,
+    lifetimes: Vec<f32>,
+}
+
+impl ParticleStorage {
+    fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    fn push(&mut self, particle: Particle) {
+        self.positions.push(particle.position);
+        self.velocities.push(particle.velocity);
+        self.ages.push(particle.age);
+        self.lifetimes.push(particle.lifetime);
+    }
+
+    fn get(&self, i: usize) -> Particle {
+        Particle {
+            position: self.positions[i],
+            velocity: self.velocities[i],
+            age: self.ages[i],
+            lifetime: self.lifetimes[i],
+        }
+    }
+
+    /// Removes a particle by moving the last one into its slot.
+    fn swap_remove(&mut self, i: usize) {
+        self.positions.swap_remove(i);
+        self.velocities.swap_remove(i);
+        self.ages.swap_remove(i);
+        self.lifetimes.swap_remove(i);
+    }
+
+    fn clear(&mut self) {
+        self.positions.clear();
+        self.velocities.clear();
+        self.ages.clear();
+        self.lifetimes.clear();
+    }
+}
+
+/// Emitter component: Spawns and simulates particles at its entity's position.
+///
+/// Particles live in the emitter rather than as entities, and are advanced by
+/// [`crate::ecs::system::particle_system`]. They move in world space, so a
+/// moving emitter leaves a trail. Each particle is drawn as a quad facing the
+/// camera's z axis, tinted by `color` and scaled by `size` over its life.
+///
+/// Spawning draws from the emitter's own generator, so the same seed and
+/// settings give the same particles on every run. Give each emitter its own
+/// seed, e.g. from [`crate::ecs::entity::Entity::rng`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticleEmitter {
+    /// Particles spawned per second while emitting.
+    pub rate: f32,
+    /// Whether `rate` spawns particles; bursts spawn either way.
+    pub emitting: bool,
+    /// Seconds each particle lives, chosen uniformly from the range.
+    pub lifetime: Range<f32>,
+    /// Initial velocity, chosen uniformly per axis between the two corners.
+    pub velocity: Range<Vec3>,
+    /// Acceleration applied to every particle, in units per second squared.
+    pub gravity: Vec3,
+    /// Particles spawn uniformly within this distance of the emitter in the xy plane.
+    pub spawn_radius: f32,
+    pub color: Gradient,
+    /// Edge length of the particle's quad in world units.
+    pub size: Curve,
+    /// Spawning stops while this many particles are alive.
+    pub max_particles: usize,
+    pub texture: Option<TextureId>,
+    pub material: MaterialId,
+    /// Draw layer; lower layers are drawn first.
+    pub layer: i32,
+    rng: Rng,
+    /// Fraction of a particle carried over between updates at the spawn rate.
+    spawn_debt: f32,
+    pending_burst: usize,
+    particles: ParticleStorage,
+}
+
+impl ParticleEmitter {
+    /// Creates an emitter with seed 0 spawning 10 white, 0.1 unit particles per
+    /// second that live 1 second and stay in place.
+    pub fn new() -> Self {
+        Self {
+            rate: 10.0,
+            emitting: true,
+            lifetime: 1.0..1.0,
+            velocity: Vec3::ZERO..Vec3::ZERO,
+            gravity: Vec3::ZERO,
+            spawn_radius: 0.0,
+            color: Gradient::constant(Color::WHITE),
+            size: Curve::constant(0.1),
+            max_particles: 1024,
+            texture: None,
+            material: Materials::DEFAULT,
+            layer: 0,
+            rng: Rng::new(0),
+            spawn_debt: 0.0,
+            pending_burst: 0,
+            particles: ParticleStorage::default(),
+        }
+    }
+
+    /// Returns the emitter spawning from a generator seeded with `seed`.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Rng::new(seed);
+        self
+    }
+
+    /// Sets the spawn rate, in particles per second.
+    pub fn with_rate(mut self, rate: f32) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Sets the range particle lifetimes are chosen from, in seconds.
+    pub fn with_lifetime(mut self, lifetime: Range<f32>) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
+
+    /// Sets the range initial velocities are chosen from.
+    pub fn with_velocity(mut self, velocity: Range<Vec3>) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Sets the acceleration applied to every particle.
+    pub fn with_gravity(mut self, gravity: Vec3) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Sets the distance from the emitter particles spawn within.
+    pub fn with_spawn_radius(mut self, spawn_radius: f32) -> Self {
+        self.spawn_radius = spawn_radius;
+        self
+    }
+
+    /// Sets the color over life.
+    pub fn with_color(mut self, color: Gradient) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the size over life.
+    pub fn with_size(mut self, size: Curve) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the most particles alive at once.
+    pub fn with_max_particles(mut self, max_particles: usize) -> Self {
+        self.max_particles = max_particles;
+        self
+    }
+
+    /// Sets the texture drawn on each particle.
+    pub fn with_texture(mut self, texture: TextureId) -> Self {
+        self.texture = Some(texture);
+        self
+    }
+
+    /// Sets the material particles are drawn with.
+    pub fn with_material(mut self, material: MaterialId) -> Self {
+        self.material = material;
+        self
+    }
+
+    /// Sets the draw layer.
+    pub fn with_layer(mut self, layer: i32) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Queues `count` particles to spawn on the next update, regardless of the rate.
+    pub fn burst(&mut self, count: usize) {
+        self.pending_burst += count;
+    }
+
+    /// Returns the number of live particles.
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Returns `true` if no particle is alive.
+    pub fn is_empty(&self) -> bool {
+        self.particles.len() == 0
+    }
+
+    /// Returns the live particles.
+    pub fn particles(&self) -> impl Iterator<Item = Particle> + '_ {
+        (0..self.particles.len()).map(|i| self.particles.get(i))
+    }
+
+    /// Removes every live particle and any queued burst.
+    pub fn clear(&mut self) {
+        self.particles.clear();
+        self.pending_burst = 0;
+        self.spawn_debt = 0.0;
+    }
+
+    /// Advances the simulation by `dt` seconds: ages and moves live particles,
+    /// drops those past their lifetime, then spawns new ones around `origin`.
+    pub fn update(&mut self, dt: f32, origin: Vec3) {
+        let storage = &mut self.particles;
+        let mut i = 0;
+        while i < storage.len() {
+            storage.ages[i] += dt;
+            if storage.ages[i] >= storage.lifetimes[i] {
+                storage.swap_remove(i);
+                continue;
+            }
+            storage.velocities[i] += self.gravity * dt;
+            storage.positions[i] += storage.velocities[i] * dt;
+            i += 1;
+        }
+
+        let mut count = std::mem::take(&mut self.pending_burst);
+        if self.emitting && self.rate > 0.0 {
+            self.spawn_debt += self.rate * dt;
+            let due = self.spawn_debt.floor();
+            self.spawn_debt -= due;
+            count += due as usize;
+        }
+        let free = self.max_particles.saturating_sub(self.particles.len());
+        for _ in 0..count.min(free) {
+            let particle = self.spawn(origin);
+            self.particles.push(particle);
+        }
+    }
+
+    /// Appends one quad command per live particle, all sharing mesh, material,
+    /// texture and layer so they batch together.
+    pub fn render_commands(&self, commands: &mut Vec<RenderCommand>) {
+        let sort_key = RenderCommand::sort_key_for(self.layer, self.material, Meshes::QUAD);
+        commands.extend(self.particles().map(|particle| {
+            let t = particle.life_fraction();
+            RenderCommand {
+                transform: Transform {
+                    translation: particle.position,
+                    scale: Vec3::splat(self.size.sample(t)),
+                    ..Transform::IDENTITY
+                },
+                mesh: Meshes::QUAD,
+                color: self.color.sample(t).to_array(),
+                material: self.material,
+                texture: self.texture,
+                uv: UvRect::FULL,
+                layer: self.layer,
+                sort_key,
+            }
+        }));
+    }
+
+    fn spawn(&mut self, origin: Vec3) -> Particle {
+        let rng = &mut self.rng;
+        let offset = rng.in_unit_circle() * self.spawn_radius;
+        let (low, high) = (self.velocity.start, self.velocity.end);
+        let velocity = Vec3::new(
+            rng.range_f32(low.x, high.x),
+            rng.range_f32(low.y, high.y),
+            rng.range_f32(low.z, high.z),
+        );
+        Particle {
+            position: origin + offset.extend(0.0),
+            velocity,
+            age: 0.0,
+            lifetime: rng.range_f32(self.lifetime.start, self.lifetime.end),
+        }
+    }
+}
+
+impl Default for ParticleEmitter {
+    /// Returns [`ParticleEmitter::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Inserts a key keeping `keys` ordered by `t`.
+fn insert_key<T>(keys: &mut Vec<(f32, T)>, t: f32, value: T) {
+    match keys.binary_search_by(|(key, _)| key.total_cmp(&t)) {
+        Ok(i) => keys[i].1 = value,
+        Err(i) => keys.insert(i, (t, value)),
+    }
+}
+
+/// Interpolates between the keys around `t`, holding the ends.
+fn sample_keys<T: Copy>(keys: &[(f32, T)], t: f32, lerp: impl Fn(T, T, f32) -> T) -> T {
+    let next = keys.partition_point(|(key, _)| *key <= t);
+    if next == 0 {
+        return keys[0].1;
+    }
+    if next == keys.len() {
+        return keys[next - 1].1;
+    }
+    let (t0, a) = keys[next - 1];
+    let (t1, b) = keys[next];
+    lerp(a, b, (t - t0) / (t1 - t0))
+}
@@ -0,0 +1,253 @@
+//! Texture atlases.
+//!
+//! Packs many images into one texture.
+//! Named and indexed regions with UV lookup.
+//! Grid slicing for sprite sheets.
+
+use std::collections::BTreeMap;
+
+use super::{AtlasError, Texture};
+use crate::math::Vec2;
+
+/// A rectangle of texture coordinates, with (0, 0) at the top-left of the texture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UvRect {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl UvRect {
+    /// The whole texture.
+    pub const FULL: UvRect = UvRect::new(Vec2::ZERO, Vec2::ONE);
+
+    /// Creates a rectangle from its top-left and bottom-right corners.
+    pub const fn new(min: Vec2, max: Vec2) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns the rectangle mirrored horizontally and/or vertically.
+    pub fn flipped(self, flip_x: bool, flip_y: bool) -> Self {
+        let mut flipped = self;
+        if flip_x {
+            std::mem::swap(&mut flipped.min.x, &mut flipped.max.x);
+        }
+        if flip_y {
+            std::mem::swap(&mut flipped.min.y, &mut flipped.max.y);
+        }
+        flipped
+    }
+
+    /// Maps a coordinate in `0.0..=1.0` across the rectangle onto the texture.
+    pub fn map(&self, uv: Vec2) -> Vec2 {
+        self.min + (self.max - self.min) * uv
+    }
+}
+
+impl Default for UvRect {
+    /// Returns [`UvRect::FULL`].
+    fn default() -> Self {
+        UvRect::FULL
+    }
+}
+
+/// A packed image's place in an atlas, in pixels from the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtlasRegion {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// One texture holding many images, each found by name or index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextureAtlas {
+    texture: Texture,
+    regions: Vec<AtlasRegion>,
+    names: BTreeMap<String, usize>,
+}
+
+impl TextureAtlas {
+    /// Slices a sprite sheet into `columns` by `rows` equal cells, indexed row by row.
+    pub fn from_grid(texture: Texture, columns: usize, rows: usize) -> Self {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+        let (cell_width, cell_height) = (texture.width() / columns, texture.height() / rows);
+        let regions = (0..rows)
+            .flat_map(|row| {
+                (0..columns).map(move |column| AtlasRegion {
+                    x: column * cell_width,
+                    y: row * cell_height,
+                    width: cell_width,
+                    height: cell_height,
+                })
+            })
+            .collect();
+        Self {
+            texture,
+            regions,
+            names: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the packed texture.
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Consumes the atlas and returns its texture, e.g. to add it to the [`super::Textures`].
+    pub fn into_texture(self) -> Texture {
+        self.texture
+    }
+
+    /// Returns the number of regions.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Returns `true` if the atlas has no regions.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Returns the index of the region added under `name`.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.get(name).copied()
+    }
+
+    /// Returns the region at `index`.
+    pub fn region(&self, index: usize) -> Option<AtlasRegion> {
+        self.regions.get(index).copied()
+    }
+
+    /// Returns the region added under `name`.
+    pub fn region_named(&self, name: &str) -> Option<AtlasRegion> {
+        self.region(self.index_of(name)?)
+    }
+
+    /// Returns the texture coordinates of the region at `index`.
+    pub fn uv(&self, index: usize) -> Option<UvRect> {
+        let region = self.region(index)?;
+        let (width, height) = (
+            self.texture.width().max(1) as f32,
+            self.texture.height().max(1) as f32,
+        );
+        Some(UvRect::new(
+            Vec2::new(region.x as f32 / width, region.y as f32 / height),
+            Vec2::new(
+                (region.x + region.width) as f32 / width,
+                (region.y + region.height) as f32 / height,
+            ),
+        ))
+    }
+
+    /// Returns the texture coordinates of the region added under `name`.
+    pub fn uv_named(&self, name: &str) -> Option<UvRect> {
+        self.uv(self.index_of(name)?)
+    }
+}
+
+/// Collects named images and packs them into a [`TextureAtlas`].
+///
+/// Images are placed on shelves, tallest first. Each is surrounded by
+/// `padding` pixels repeating its edge, so sampling at a region's border never
+/// picks up a neighbour.
+#[derive(Debug, Clone)]
+pub struct AtlasBuilder {
+    max_width: usize,
+    padding: usize,
+    images: Vec<(String, Texture)>,
+}
+
+impl AtlasBuilder {
+    /// Creates a builder for an atlas at most `max_width` pixels wide, with 1 pixel of padding.
+    pub fn new(max_width: usize) -> Self {
+        Self {
+            max_width,
+            padding: 1,
+            images: Vec::new(),
+        }
+    }
+
+    /// Sets the padding around each image, in pixels.
+    pub fn with_padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Adds an image; its region keeps the index of this call among all additions.
+    pub fn add(&mut self, name: impl Into<String>, image: Texture) -> &mut Self {
+        self.images.push((name.into(), image));
+        self
+    }
+
+    /// Packs the images into one texture.
+    pub fn build(self) -> Result<TextureAtlas, AtlasError> {
+        let mut names = BTreeMap::new();
+        for (index, (name, image)) in self.images.iter().enumerate() {
+            if names.insert(name.clone(), index).is_some() {
+                return Err(AtlasError::DuplicateName(name.clone()));
+            }
+            if image.width() + 2 * self.padding > self.max_width {
+                return Err(AtlasError::TooWide(name.clone()));
+            }
+        }
+
+        // Tallest first keeps shelves tight; the sort is stable for equal heights
+        let mut order: Vec<usize> = (0..self.images.len()).collect();
+        order.sort_by_key(|&index| std::cmp::Reverse(self.images[index].1.height()));
+
+        let padding = self.padding;
+        let mut regions = vec![
+            AtlasRegion {
+                x: 0,
+                y: 0,
+                width: 0,
+                height: 0,
+            };
+            self.images.len()
+        ];
+        let (mut x, mut y, mut shelf_height, mut width) = (0, 0, 0, 0);
+        for &index in &order {
+            let image = &self.images[index].1;
+            let (cell_width, cell_height) =
+                (image.width() + 2 * padding, image.height() + 2 * padding);
+            if x + cell_width > self.max_width {
+                y += shelf_height;
+                x = 0;
+                shelf_height = 0;
+            }
+            regions[index] = AtlasRegion {
+                x: x + padding,
+                y: y + padding,
+                width: image.width(),
+                height: image.height(),
+            };
+            x += cell_width;
+            width = width.max(x);
+            shelf_height = shelf_height.max(cell_height);
+        }
+        let height = y + shelf_height;
+
+        let mut pixels = vec![[0; 4]; width * height];
+        for (region, (_, image)) in regions.iter().zip(&self.images) {
+            if image.width() == 0 || image.height() == 0 {
+                continue;
+            }
+            // Write the padded cell, clamping to the image so its edges repeat
+            for cy in 0..region.height + 2 * padding {
+                for cx in 0..region.width + 2 * padding {
+                    let sx = cx.saturating_sub(padding).min(image.width() - 1);
+                    let sy = cy.saturating_sub(padding).min(image.height() - 1);
+                    let (px, py) = (region.x - padding + cx, region.y - padding + cy);
+                    pixels[py * width + px] = image.pixels()[sy * image.width() + sx];
+                }
+            }
+        }
+
+        Ok(TextureAtlas {
+            texture: Texture::new(width, height, pixels).expect("pixel count matches size"),
+            regions,
+            names,
+        })
+    }
+}
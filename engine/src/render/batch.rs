@@ -0,0 +1,160 @@
+//! Draw ordering and batching.
+//!
+//! Opaque and transparent passes per layer.
+//! Back-to-front sorting for blended materials.
+//! Instanced batches and per-frame statistics.
+
+use std::ops::{AddAssign, Range};
+
+use super::{MaterialId, Materials, MeshId, Meshes, RenderCommand, TextureId};
+use crate::math::Mat4;
+
+/// The pass a command is drawn in within its layer.
+///
+/// Opaque commands come first, grouped by material and mesh; transparent ones
+/// follow, farthest first, so they blend over everything behind them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RenderPass {
+    Opaque,
+    Transparent,
+}
+
+impl RenderPass {
+    /// Returns the pass for a command, decided by its material, see [`super::Material::pass`].
+    ///
+    /// Commands naming an unknown material use the default one.
+    pub fn of(command: &RenderCommand, materials: &Materials) -> Self {
+        materials
+            .resolve(command.material)
+            .pass(command.color, command.texture)
+    }
+}
+
+/// A run of commands drawing the same mesh with the same material and texture,
+/// submitted as one instanced draw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrawBatch {
+    pub mesh: MeshId,
+    pub material: MaterialId,
+    /// The texture sampled, whether it comes from the commands or their material.
+    pub texture: Option<TextureId>,
+    pub layer: i32,
+    pub pass: RenderPass,
+    /// The batched commands, as a range into the sorted command list.
+    pub instances: Range<usize>,
+}
+
+impl DrawBatch {
+    /// Returns the number of commands in the batch.
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Returns `true` if the batch holds no commands.
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+}
+
+/// Counters describing the work of a frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Instanced draws issued, one per batch whose mesh exists.
+    pub draw_calls: usize,
+    /// Draws that merged two or more commands.
+    pub batches: usize,
+    /// Commands drawn.
+    pub instances: usize,
+    /// Triangles drawn, over all instances.
+    pub triangles: usize,
+}
+
+impl FrameStats {
+    /// Counts the work of drawing `batches`; batches naming a missing mesh are not drawn.
+    pub fn for_batches(batches: &[DrawBatch], meshes: &Meshes) -> Self {
+        let mut stats = FrameStats::default();
+        for batch in batches {
+            let Some(mesh) = meshes.get(batch.mesh) else {
+                continue;
+            };
+            stats.draw_calls += 1;
+            if batch.len() > 1 {
+                stats.batches += 1;
+            }
+            stats.instances += batch.len();
+            stats.triangles += mesh.triangle_count() * batch.len();
+        }
+        stats
+    }
+}
+
+impl AddAssign for FrameStats {
+    fn add_assign(&mut self, other: FrameStats) {
+        self.draw_calls += other.draw_calls;
+        self.batches += other.batches;
+        self.instances += other.instances;
+        self.triangles += other.triangles;
+    }
+}
+
+/// Sorts commands into draw order for a camera with the given view matrix.
+///
+/// Layers are drawn in ascending order. Within a layer, opaque commands are
+/// ordered by sort key and texture so equal materials and meshes are adjacent,
+/// then transparent commands from back to front by view-space depth; for 2D
+/// cameras this makes z the z-order within a layer. The sort is stable, so ties
+/// keep their input order.
+pub fn sort_for_view(commands: &mut [RenderCommand], view: Mat4, materials: &Materials) {
+    commands.sort_by(|a, b| {
+        let (pass_a, pass_b) = (RenderPass::of(a, materials), RenderPass::of(b, materials));
+        a.layer
+            .cmp(&b.layer)
+            .then(pass_a.cmp(&pass_b))
+            .then_with(|| {
+                if pass_a == RenderPass::Opaque {
+                    // Full ids break ties between ids that share their low key bits
+                    a.sort_key
+                        .cmp(&b.sort_key)
+                        .then((a.material, a.mesh).cmp(&(b.material, b.mesh)))
+                        .then(materials.texture_of(a).cmp(&materials.texture_of(b)))
+                } else {
+                    // Cameras look down -z, so farther means more negative
+                    let depth = |command: &RenderCommand| {
+                        view.transform_point3(command.transform.translation).z
+                    };
+                    depth(a).total_cmp(&depth(b))
+                }
+            })
+    });
+}
+
+/// Groups consecutive commands with the same layer, pass, mesh, material and texture into batches.
+///
+/// Expects commands in draw order, as left by [`sort_for_view`].
+pub fn build_batches(commands: &[RenderCommand], materials: &Materials) -> Vec<DrawBatch> {
+    let mut batches: Vec<DrawBatch> = Vec::new();
+    for (index, command) in commands.iter().enumerate() {
+        let pass = RenderPass::of(command, materials);
+        let texture = materials.texture_of(command);
+        match batches.last_mut() {
+            Some(batch)
+                if batch.mesh == command.mesh
+                    && batch.material == command.material
+                    && batch.texture == texture
+                    && batch.layer == command.layer
+                    && batch.pass == pass =>
+            {
+                batch.instances.end = index + 1;
+            }
+            _ => batches.push(DrawBatch {
+                mesh: command.mesh,
+                material: command.material,
+                texture,
+                layer: command.layer,
+                pass,
+                instances: index..index + 1,
+            }),
+        }
+    }
+    batches
+}
@@ -0,0 +1,322 @@
+//! Cameras.
+//!
+//! Orthographic and perspective projections.
+//! Viewports, render targets and layer masks.
+//! Per-camera views of the frame's render commands.
+
+use super::{
+    DrawBatch, FrameStats, Materials, Meshes, RenderCommand, Transform, build_batches,
+    sort_for_view,
+};
+use crate::math::{Mat4, Vec2, Vec3};
+
+/// How a camera maps view space onto its viewport.
+///
+/// The horizontal extent follows from the viewport's aspect ratio, so the same
+/// camera works for any window or split-screen layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Projection {
+    /// Parallel projection showing `height` world units from bottom to top.
+    Orthographic { height: f32, near: f32, far: f32 },
+    /// Perspective projection with a vertical field of view in radians.
+    Perspective { fov_y: f32, near: f32, far: f32 },
+}
+
+impl Projection {
+    /// Returns the projection matrix for a viewport of the given width / height ratio.
+    pub fn matrix(&self, aspect: f32) -> Mat4 {
+        match *self {
+            Projection::Orthographic { height, near, far } => {
+                let half_height = height * 0.5;
+                let half_width = half_height * aspect;
+                Mat4::orthographic_rh(
+                    -half_width,
+                    half_width,
+                    -half_height,
+                    half_height,
+                    near,
+                    far,
+                )
+            }
+            Projection::Perspective { fov_y, near, far } => {
+                Mat4::perspective_rh(fov_y, aspect, near, far)
+            }
+        }
+    }
+}
+
+/// The part of a render target a camera draws into, in fractions of its size.
+///
+/// The origin is the top-left corner, like screen pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Viewport {
+    /// The whole target.
+    pub const FULL: Viewport = Viewport::new(0.0, 0.0, 1.0, 1.0);
+
+    /// Creates a viewport from fractions of the target size.
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the pixel rectangle `(x, y, width, height)` covered in a target of the given size.
+    ///
+    /// Edges are rounded so that adjacent viewports share their boundary without gaps.
+    pub fn to_pixels(
+        &self,
+        target_width: usize,
+        target_height: usize,
+    ) -> (usize, usize, usize, usize) {
+        let (tw, th) = (target_width as f32, target_height as f32);
+        let left = (self.x * tw).round().clamp(0.0, tw) as usize;
+        let top = (self.y * th).round().clamp(0.0, th) as usize;
+        let right = ((self.x + self.width) * tw).round().clamp(0.0, tw) as usize;
+        let bottom = ((self.y + self.height) * th).round().clamp(0.0, th) as usize;
+        (
+            left,
+            top,
+            right.saturating_sub(left),
+            bottom.saturating_sub(top),
+        )
+    }
+
+    /// Returns the width / height ratio of the viewport in a target of the given size.
+    pub fn aspect(&self, target_width: usize, target_height: usize) -> f32 {
+        let (_, _, width, height) = self.to_pixels(target_width, target_height);
+        if height == 0 {
+            1.0
+        } else {
+            width as f32 / height as f32
+        }
+    }
+}
+
+impl Default for Viewport {
+    /// Returns [`Viewport::FULL`].
+    fn default() -> Self {
+        Viewport::FULL
+    }
+}
+
+/// Where a camera's image ends up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RenderTarget {
+    /// The window, or the main framebuffer when running headless.
+    #[default]
+    Primary,
+    /// An offscreen image identified by the application.
+    Offscreen(usize),
+}
+
+/// Set of draw layers a camera shows.
+///
+/// Bit `n` selects layer `n` for layers 0 to 31; only those draw layers can be
+/// picked one by one. Layers outside that range, including negative ones, are
+/// shown by [`LayerMask::ALL`] alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayerMask(pub u32);
+
+impl LayerMask {
+    /// Shows every layer.
+    pub const ALL: LayerMask = LayerMask(u32::MAX);
+    /// Shows nothing.
+    pub const NONE: LayerMask = LayerMask(0);
+
+    /// Creates a mask showing only `layer`; a layer outside `0..32` gives [`Self::NONE`].
+    pub const fn only(layer: i32) -> Self {
+        LayerMask::NONE.with(layer)
+    }
+
+    /// Returns the mask with `layer` added; a layer outside `0..32` is ignored.
+    pub const fn with(self, layer: i32) -> Self {
+        if layer < 0 || layer >= 32 {
+            return self;
+        }
+        LayerMask(self.0 | (1 << layer))
+    }
+
+    /// Returns `true` if the mask shows `layer`.
+    pub fn contains(self, layer: i32) -> bool {
+        if self == LayerMask::ALL {
+            return true;
+        }
+        (0..32).contains(&layer) && self.0 & (1 << layer) != 0
+    }
+}
+
+impl Default for LayerMask {
+    /// Returns [`LayerMask::ALL`].
+    fn default() -> Self {
+        LayerMask::ALL
+    }
+}
+
+/// Camera component: Renders the world from its entity's transform.
+///
+/// The camera looks down its local -z axis with +y up. Cameras are drawn in
+/// ascending `order`, so a higher order draws on top when viewports overlap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    pub projection: Projection,
+    pub viewport: Viewport,
+    pub target: RenderTarget,
+    pub layers: LayerMask,
+    pub order: i32,
+    /// Color the viewport is cleared to first, or `None` to draw over what is there.
+    pub clear_color: Option<[u8; 4]>,
+    /// Inactive cameras produce no view.
+    pub active: bool,
+}
+
+impl Camera {
+    /// Creates a camera with the given projection covering the primary target.
+    pub fn new(projection: Projection) -> Self {
+        Self {
+            projection,
+            viewport: Viewport::FULL,
+            target: RenderTarget::Primary,
+            layers: LayerMask::ALL,
+            order: 0,
+            clear_color: Some([0, 0, 0, 255]),
+            active: true,
+        }
+    }
+
+    /// Creates a 2D camera showing `height` world units vertically.
+    pub fn orthographic(height: f32) -> Self {
+        Self::new(Projection::Orthographic {
+            height,
+            near: -1000.0,
+            far: 1000.0,
+        })
+    }
+
+    /// Creates a 3D camera with a vertical field of view in radians.
+    pub fn perspective(fov_y: f32, near: f32, far: f32) -> Self {
+        Self::new(Projection::Perspective { fov_y, near, far })
+    }
+
+    /// Sets the viewport.
+    pub fn with_viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = viewport;
+        self
+    }
+
+    /// Sets the render target.
+    pub fn with_target(mut self, target: RenderTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Sets the visible layers.
+    pub fn with_layers(mut self, layers: LayerMask) -> Self {
+        self.layers = layers;
+        self
+    }
+
+    /// Sets the draw order among cameras.
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the clear color, or `None` to keep what is already in the viewport.
+    pub fn with_clear_color(mut self, clear_color: Option<[u8; 4]>) -> Self {
+        self.clear_color = clear_color;
+        self
+    }
+}
+
+/// Returns the view matrix for a camera placed by `transform`.
+///
+/// Scale is ignored; only position and orientation define the view.
+pub fn view_matrix(transform: &Transform) -> Mat4 {
+    Mat4::from_quat(transform.rotation.conjugate()) * Mat4::from_translation(-transform.translation)
+}
+
+/// One camera's view of a frame: its settings and the commands it sees, sorted and batched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderView {
+    pub view: Mat4,
+    pub projection: Projection,
+    pub viewport: Viewport,
+    pub target: RenderTarget,
+    pub clear_color: Option<[u8; 4]>,
+    /// The commands the camera sees, in draw order.
+    pub render_commands: Vec<RenderCommand>,
+    /// Batches covering `render_commands` in order.
+    pub batches: Vec<DrawBatch>,
+}
+
+impl RenderView {
+    /// Builds a camera's view: keeps the commands on its layers, sorts them for
+    /// drawing and batches them by material.
+    pub fn new(
+        camera: &Camera,
+        view: Mat4,
+        commands: &[RenderCommand],
+        materials: &Materials,
+    ) -> Self {
+        let mut render_commands: Vec<RenderCommand> = commands
+            .iter()
+            .filter(|command| camera.layers.contains(command.layer))
+            .copied()
+            .collect();
+        sort_for_view(&mut render_commands, view, materials);
+        let batches = build_batches(&render_commands, materials);
+        Self {
+            view,
+            projection: camera.projection,
+            viewport: camera.viewport,
+            target: camera.target,
+            clear_color: camera.clear_color,
+            render_commands,
+            batches,
+        }
+    }
+
+    /// Returns the work of drawing this view with the given meshes.
+    pub fn stats(&self, meshes: &Meshes) -> FrameStats {
+        FrameStats::for_batches(&self.batches, meshes)
+    }
+
+    /// Returns the combined view-projection matrix for a target of the given size.
+    pub fn view_projection(&self, target_width: usize, target_height: usize) -> Mat4 {
+        let aspect = self.viewport.aspect(target_width, target_height);
+        self.projection.matrix(aspect) * self.view
+    }
+
+    /// Converts a pixel position in a target of the given size to the world-space
+    /// point on the camera's near plane. For orthographic cameras, its x and y are
+    /// the world position under the cursor.
+    ///
+    /// Returns `None` if the matrix cannot be inverted.
+    pub fn screen_to_world(
+        &self,
+        screen: Vec2,
+        target_width: usize,
+        target_height: usize,
+    ) -> Option<Vec3> {
+        let (x, y, width, height) = self.viewport.to_pixels(target_width, target_height);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let ndc_x = (screen.x - x as f32) / width as f32 * 2.0 - 1.0;
+        let ndc_y = 1.0 - (screen.y - y as f32) / height as f32 * 2.0;
+        let inverse = self
+            .view_projection(target_width, target_height)
+            .inverse()?;
+        Some(inverse.project_point3(Vec3::new(ndc_x, ndc_y, -1.0)))
+    }
+}
@@ -0,0 +1,351 @@
+//! Colors.
+//!
+//! Linear floating-point colors for lighting and HDR.
+//! sRGB transfer functions and conversions.
+//! HSV / HSL, premultiplied alpha and blending.
+
+use std::ops::{Add, Mul, Sub};
+
+use super::BlendMode;
+use crate::ecs::components::Color;
+use crate::math::Vec4;
+
+/// Decodes an sRGB-encoded channel in `0.0..=1.0` to linear light.
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes a linear channel in `0.0..=1.0` with the sRGB transfer function.
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// A color in linear light with straight (non-premultiplied) alpha.
+///
+/// Channels are not clamped, so values above 1.0 describe HDR intensities.
+/// Converting to [`Color`] clamps them and applies the sRGB encoding; alpha is
+/// linear in both.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl LinearColor {
+    pub const TRANSPARENT: LinearColor = LinearColor::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: LinearColor = LinearColor::rgb(0.0, 0.0, 0.0);
+    pub const WHITE: LinearColor = LinearColor::rgb(1.0, 1.0, 1.0);
+
+    /// Creates a color from linear channels and alpha.
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a fully opaque color from linear channels.
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    /// Returns the color with another alpha.
+    pub const fn with_alpha(mut self, a: f32) -> Self {
+        self.a = a;
+        self
+    }
+
+    /// Returns the color with its channels scaled by `intensity`, keeping alpha.
+    pub fn scaled(self, intensity: f32) -> Self {
+        Self::new(
+            self.r * intensity,
+            self.g * intensity,
+            self.b * intensity,
+            self.a,
+        )
+    }
+
+    /// Returns the relative luminance (Rec. 709 weights).
+    pub fn luminance(self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    /// Returns `true` if any channel is brighter than what a display can show.
+    pub fn is_hdr(self) -> bool {
+        self.r > 1.0 || self.g > 1.0 || self.b > 1.0
+    }
+
+    /// Returns the color with its channels multiplied by alpha.
+    pub fn premultiplied(self) -> Self {
+        self.scaled(self.a)
+    }
+
+    /// Undoes [`Self::premultiplied`]; a fully transparent color becomes [`Self::TRANSPARENT`].
+    pub fn unpremultiplied(self) -> Self {
+        if self.a <= 0.0 {
+            Self::TRANSPARENT
+        } else {
+            self.scaled(1.0 / self.a)
+        }
+    }
+
+    /// Interpolates every channel, including alpha, from `self` (t = 0) to `to` (t = 1).
+    pub fn lerp(self, to: Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+
+    /// Combines `self` as the source with `dst` as the destination.
+    ///
+    /// Uses the same equations per [`BlendMode`] as the software renderer, but
+    /// the results differ: the renderer blends 8-bit sRGB-encoded channels, as
+    /// render commands carry `[u8; 4]` colors, while this blends in linear light
+    /// without rounding or clamping. Half-transparent white over black, for
+    /// example, encodes to about 188 here but is 128 on the framebuffer.
+    pub fn blend(self, dst: Self, mode: BlendMode) -> Self {
+        match mode {
+            BlendMode::Opaque => self.with_alpha(1.0),
+            BlendMode::Alpha => {
+                let src = self.premultiplied();
+                let dst = dst.premultiplied();
+                let a = src.a + dst.a * (1.0 - self.a);
+                Self::new(
+                    src.r + dst.r * (1.0 - self.a),
+                    src.g + dst.g * (1.0 - self.a),
+                    src.b + dst.b * (1.0 - self.a),
+                    a,
+                )
+                .unpremultiplied()
+            }
+            BlendMode::Additive => {
+                let src = self.premultiplied();
+                Self::new(dst.r + src.r, dst.g + src.g, dst.b + src.b, dst.a)
+            }
+            BlendMode::Multiply => {
+                let factor = |s: f32| 1.0 + (s - 1.0) * self.a;
+                Self::new(
+                    dst.r * factor(self.r),
+                    dst.g * factor(self.g),
+                    dst.b * factor(self.b),
+                    dst.a,
+                )
+            }
+        }
+    }
+
+    /// Returns the channels as `[r, g, b, a]`.
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
+
+impl Default for LinearColor {
+    /// Returns [`LinearColor::WHITE`].
+    fn default() -> Self {
+        LinearColor::WHITE
+    }
+}
+
+impl Add for LinearColor {
+    type Output = LinearColor;
+
+    fn add(self, rhs: LinearColor) -> LinearColor {
+        LinearColor::new(
+            self.r + rhs.r,
+            self.g + rhs.g,
+            self.b + rhs.b,
+            self.a + rhs.a,
+        )
+    }
+}
+
+impl Sub for LinearColor {
+    type Output = LinearColor;
+
+    fn sub(self, rhs: LinearColor) -> LinearColor {
+        LinearColor::new(
+            self.r - rhs.r,
+            self.g - rhs.g,
+            self.b - rhs.b,
+            self.a - rhs.a,
+        )
+    }
+}
+
+impl Mul<f32> for LinearColor {
+    type Output = LinearColor;
+
+    /// Scales every channel, including alpha.
+    fn mul(self, rhs: f32) -> LinearColor {
+        LinearColor::new(self.r * rhs, self.g * rhs, self.b * rhs, self.a * rhs)
+    }
+}
+
+impl Mul for LinearColor {
+    type Output = LinearColor;
+
+    /// Multiplies channel by channel, tinting one color by another.
+    fn mul(self, rhs: LinearColor) -> LinearColor {
+        LinearColor::new(
+            self.r * rhs.r,
+            self.g * rhs.g,
+            self.b * rhs.b,
+            self.a * rhs.a,
+        )
+    }
+}
+
+impl From<Color> for LinearColor {
+    fn from(value: Color) -> Self {
+        let channel = |c: u8| srgb_to_linear(c as f32 / 255.0);
+        LinearColor::new(
+            channel(value.r),
+            channel(value.g),
+            channel(value.b),
+            value.a as f32 / 255.0,
+        )
+    }
+}
+
+impl From<LinearColor> for Color {
+    /// Clamps to the displayable range and encodes as sRGB.
+    fn from(value: LinearColor) -> Self {
+        let channel = |c: f32| (linear_to_srgb(c.clamp(0.0, 1.0)) * 255.0).round() as u8;
+        Color::rgba(
+            channel(value.r),
+            channel(value.g),
+            channel(value.b),
+            (value.a.clamp(0.0, 1.0) * 255.0).round() as u8,
+        )
+    }
+}
+
+impl From<LinearColor> for Vec4 {
+    fn from(value: LinearColor) -> Self {
+        Vec4::new(value.r, value.g, value.b, value.a)
+    }
+}
+
+impl From<Vec4> for LinearColor {
+    fn from(value: Vec4) -> Self {
+        LinearColor::new(value.x, value.y, value.z, value.w)
+    }
+}
+
+/// A color as hue, saturation and value, over the sRGB-encoded channels.
+///
+/// Hue is in degrees in `0.0..360.0`; saturation, value and alpha are in `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+    pub a: f32,
+}
+
+impl Hsv {
+    /// Creates a fully opaque color.
+    pub const fn new(h: f32, s: f32, v: f32) -> Self {
+        Self { h, s, v, a: 1.0 }
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(value: Color) -> Self {
+        let (h, max, min, a) = hue_of(value);
+        let s = if max <= 0.0 { 0.0 } else { (max - min) / max };
+        Hsv { h, s, v: max, a }
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(value: Hsv) -> Self {
+        let v = value.v.clamp(0.0, 1.0);
+        let chroma = v * value.s.clamp(0.0, 1.0);
+        from_hue(value.h, chroma, v - chroma, value.a)
+    }
+}
+
+/// A color as hue, saturation and lightness, over the sRGB-encoded channels.
+///
+/// Hue is in degrees in `0.0..360.0`; saturation, lightness and alpha are in `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+    pub a: f32,
+}
+
+impl Hsl {
+    /// Creates a fully opaque color.
+    pub const fn new(h: f32, s: f32, l: f32) -> Self {
+        Self { h, s, l, a: 1.0 }
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(value: Color) -> Self {
+        let (h, max, min, a) = hue_of(value);
+        let l = (max + min) * 0.5;
+        let s = if max - min <= 0.0 {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Hsl { h, s, l, a }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(value: Hsl) -> Self {
+        let l = value.l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * value.s.clamp(0.0, 1.0);
+        from_hue(value.h, chroma, l - chroma * 0.5, value.a)
+    }
+}
+
+/// Returns the hue in degrees, the largest and smallest channel and alpha, all but hue in `0.0..=1.0`.
+fn hue_of(color: Color) -> (f32, f32, f32, f32) {
+    let [r, g, b, a] = color.to_array().map(|c| c as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+    let sector = if chroma <= 0.0 {
+        0.0
+    } else if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+    (sector * 60.0, max, min, a)
+}
+
+/// Builds a color from its hue in degrees, chroma and the amount added to every channel.
+fn from_hue(hue: f32, chroma: f32, offset: f32, alpha: f32) -> Color {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let byte = |c: f32| ((c + offset).clamp(0.0, 1.0) * 255.0).round() as u8;
+    Color::rgba(
+        byte(r),
+        byte(g),
+        byte(b),
+        (alpha.clamp(0.0, 1.0) * 255.0).round() as u8,
+    )
+}
@@ -0,0 +1,250 @@
+//! Debug drawing.
+//!
+//! Gizmos queued by systems during a tick.
+//! Lines, boxes, circles, arrows and labels with lifetimes.
+//! Flushed into an overlay drawn on top of the frame.
+//! Disabled without the `debug-draw` feature.
+
+use std::f32::consts::TAU;
+
+use crate::math::Vec3;
+
+/// Line segments used to outline a circle.
+const CIRCLE_SEGMENTS: usize = 24;
+/// Length of an arrow's head relative to the whole arrow.
+const ARROW_HEAD_RATIO: f32 = 0.25;
+
+/// A gizmo queued with [`DebugDraw::add`], in world space.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DebugShape {
+    Line {
+        start: Vec3,
+        end: Vec3,
+    },
+    /// The edges of an axis-aligned box.
+    Aabb {
+        min: Vec3,
+        max: Vec3,
+    },
+    /// A circle in the xy plane.
+    Circle {
+        center: Vec3,
+        radius: f32,
+    },
+    /// A line with a head at `end`; the head lies flat in the xy plane unless
+    /// the arrow points along z.
+    Arrow {
+        start: Vec3,
+        end: Vec3,
+    },
+    /// Text drawn at a fixed pixel size with its top-left corner at `position`.
+    Label {
+        position: Vec3,
+        text: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct QueuedShape {
+    shape: DebugShape,
+    color: [u8; 4],
+    /// Seconds left to show the shape after the current frame.
+    remaining: f32,
+}
+
+/// Resource collecting gizmos from any system during a tick.
+///
+/// [`crate::Engine::tick`] flushes it into [`crate::core::frame::FrameOutput::debug`]
+/// after extraction. A shape is shown in the frame it is queued in and in later
+/// frames until its duration has passed, so a duration of 0 draws it once.
+///
+/// Without the `debug-draw` cargo feature, or while disabled, queuing does
+/// nothing and the overlay stays empty. The types and calls still exist in
+/// such builds, so callers need no `cfg`; the queuing methods return before
+/// storing or allocating anything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugDraw {
+    enabled: bool,
+    shapes: Vec<QueuedShape>,
+}
+
+impl DebugDraw {
+    /// Creates an enabled, empty queue.
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            shapes: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if queued shapes will be drawn.
+    pub fn is_enabled(&self) -> bool {
+        cfg!(feature = "debug-draw") && self.enabled
+    }
+
+    /// Turns drawing on or off; turning it off drops every queued shape.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.shapes.clear();
+        }
+    }
+
+    /// Queues a shape shown for `duration` seconds after this frame.
+    pub fn add(&mut self, shape: DebugShape, color: [u8; 4], duration: f32) {
+        if self.is_enabled() {
+            self.shapes.push(QueuedShape {
+                shape,
+                color,
+                remaining: duration,
+            });
+        }
+    }
+
+    /// Queues a line for this frame.
+    pub fn line(&mut self, start: Vec3, end: Vec3, color: [u8; 4]) {
+        self.add(DebugShape::Line { start, end }, color, 0.0);
+    }
+
+    /// Queues the edges of an axis-aligned box for this frame.
+    pub fn aabb(&mut self, min: Vec3, max: Vec3, color: [u8; 4]) {
+        self.add(DebugShape::Aabb { min, max }, color, 0.0);
+    }
+
+    /// Queues a circle in the xy plane for this frame.
+    pub fn circle(&mut self, center: Vec3, radius: f32, color: [u8; 4]) {
+        self.add(DebugShape::Circle { center, radius }, color, 0.0);
+    }
+
+    /// Queues an arrow from `start` to `end` for this frame.
+    pub fn arrow(&mut self, start: Vec3, end: Vec3, color: [u8; 4]) {
+        self.add(DebugShape::Arrow { start, end }, color, 0.0);
+    }
+
+    /// Queues a text label for this frame.
+    pub fn label(&mut self, position: Vec3, text: impl Into<String>, color: [u8; 4]) {
+        // Checked first so disabled builds skip allocating the text
+        if !self.is_enabled() {
+            return;
+        }
+        let text = text.into();
+        self.add(DebugShape::Label { position, text }, color, 0.0);
+    }
+
+    /// Returns the number of queued shapes.
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    /// Returns `true` if no shape is queued.
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Builds the overlay for this frame, then ages the queue by `dt` seconds
+    /// and drops shapes whose duration has passed.
+    pub fn flush(&mut self, dt: f32) -> DebugOverlay {
+        let mut overlay = DebugOverlay::default();
+        for queued in &self.shapes {
+            overlay.push_shape(&queued.shape, queued.color);
+        }
+        self.shapes.retain_mut(|queued| {
+            queued.remaining -= dt;
+            queued.remaining > 0.0
+        });
+        overlay
+    }
+}
+
+impl Default for DebugDraw {
+    /// Returns [`DebugDraw::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A world-space line segment of the overlay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugLine {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub color: [u8; 4],
+}
+
+/// A text label of the overlay, anchored at a world-space point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugLabel {
+    pub position: Vec3,
+    pub text: String,
+    pub color: [u8; 4],
+}
+
+/// The gizmos of one frame, broken down into lines and labels.
+///
+/// Drawn after every view, on top of it and without depth testing, see
+/// [`super::SoftwareRenderer::draw_debug`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DebugOverlay {
+    pub lines: Vec<DebugLine>,
+    pub labels: Vec<DebugLabel>,
+}
+
+impl DebugOverlay {
+    /// Returns `true` if there is nothing to draw.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty() && self.labels.is_empty()
+    }
+
+    fn push_shape(&mut self, shape: &DebugShape, color: [u8; 4]) {
+        let mut line = |start: Vec3, end: Vec3| self.lines.push(DebugLine { start, end, color });
+        match shape {
+            DebugShape::Line { start, end } => line(*start, *end),
+            DebugShape::Aabb { min, max } => {
+                let corner = |i: usize| {
+                    Vec3::new(
+                        if i & 1 == 0 { min.x } else { max.x },
+                        if i & 2 == 0 { min.y } else { max.y },
+                        if i & 4 == 0 { min.z } else { max.z },
+                    )
+                };
+                // Each edge joins two corners differing in exactly one axis
+                for i in 0..8 {
+                    for axis in [1, 2, 4] {
+                        if i & axis == 0 {
+                            line(corner(i), corner(i | axis));
+                        }
+                    }
+                }
+            }
+            DebugShape::Circle { center, radius } => {
+                let point = |i: usize| {
+                    let (sin, cos) = (i as f32 / CIRCLE_SEGMENTS as f32 * TAU).sin_cos();
+                    *center + Vec3::new(cos * radius, sin * radius, 0.0)
+                };
+                for i in 0..CIRCLE_SEGMENTS {
+                    line(point(i), point(i + 1));
+                }
+            }
+            DebugShape::Arrow { start, end } => {
+                line(*start, *end);
+                let shaft = *end - *start;
+                let Some(direction) = shaft.try_normalize() else {
+                    return;
+                };
+                let side = direction
+                    .cross(Vec3::Z)
+                    .try_normalize()
+                    .unwrap_or_else(|| direction.cross(Vec3::X).normalize_or_zero());
+                let head = shaft.length() * ARROW_HEAD_RATIO;
+                let back = *end - direction * head;
+                line(*end, back + side * (head * 0.5));
+                line(*end, back - side * (head * 0.5));
+            }
+            DebugShape::Label { position, text } => self.labels.push(DebugLabel {
+                position: *position,
+                text: text.clone(),
+                color,
+            }),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+/// Error returned when decoding or reading an image fails.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImageError {
+    /// The data does not start with a recognized image signature or header.
+    InvalidFormat,
+    /// The image uses a feature the decoder does not handle (e.g. 16-bit or interlaced PNG).
+    Unsupported,
+    /// The data ends before the image is complete.
+    Truncated,
+    /// A checksum does not match or compressed data is malformed.
+    Corrupt,
+    /// The header describes an image too large to address in memory.
+    TooLarge,
+    /// Reading or writing an image file failed.
+    Io(std::io::ErrorKind),
+}
+
+/// Error returned when a mesh id does not name a registered mesh.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MeshError {
+    /// No mesh with this id exists in the [`super::Meshes`] registry.
+    UnknownMesh(super::MeshId),
+}
+
+/// Error returned when packing a texture atlas fails.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AtlasError {
+    /// Two images were added under the same name.
+    DuplicateName(String),
+    /// The named image, with padding, is wider than the atlas.
+    TooWide(String),
+}
+
+/// Error returned when parsing a BMFont descriptor fails.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FontError {
+    /// A line has a malformed or missing value.
+    InvalidLine {
+        /// 1-based line number in the descriptor.
+        line: usize,
+    },
+    /// The descriptor has no `common` line with the line height and texture size.
+    MissingCommon,
+    /// The font spreads its glyphs over more than one texture page.
+    MultiplePages,
+}
+
+/// Error returned when editing a tilemap or loading one from Tiled fails.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TilemapError {
+    /// The tile coordinates lie outside the map.
+    OutOfBounds { x: u32, y: u32 },
+    /// No layer exists at this index.
+    UnknownLayer(usize),
+    /// The XML or JSON is malformed at this byte offset.
+    Syntax { offset: usize },
+    /// A required element, attribute or field is absent, e.g. `"map.tilewidth"`.
+    Missing(String),
+    /// A value cannot be read as the expected type, e.g. `"layer.width"`.
+    InvalidValue(String),
+    /// The map uses a Tiled feature the loader does not handle, e.g. compressed data.
+    Unsupported(String),
+    /// A layer's data does not hold one entry per tile.
+    LayerSizeMismatch {
+        layer: String,
+        expected: usize,
+        found: usize,
+    },
+    /// A global tile id lies outside the map's tileset.
+    InvalidTile { gid: u32 },
+    /// The map has more cells than a layer can hold in memory.
+    TooLarge { width: u32, height: u32 },
+}
@@ -0,0 +1,448 @@
+//! Bitmap fonts.
+//!
+//! BMFont text descriptors and a built-in 5x7 font.
+//! Text layout with alignment, wrapping and kerning.
+//! Glyph quads ready to be drawn from a font texture.
+
+use std::collections::BTreeMap;
+
+use super::font_data::{FIRST_CHAR, GLYPH_HEIGHT, GLYPH_WIDTH, GLYPHS};
+use super::{AtlasRegion, FontError, Texture, TextureId, UvRect};
+use crate::math::Vec2;
+
+/// Spacing of the built-in font: each glyph sits in a cell one pixel larger in both directions.
+const BUILTIN_CELL: (usize, usize) = (GLYPH_WIDTH + 1, GLYPH_HEIGHT + 1);
+/// Glyphs per row of the built-in font texture.
+const BUILTIN_COLUMNS: usize = 16;
+
+/// Horizontal alignment of each line relative to the text's origin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TextAlign {
+    /// Lines start at the origin.
+    #[default]
+    Left,
+    /// Lines are centered on the origin.
+    Center,
+    /// Lines end at the origin.
+    Right,
+}
+
+/// Where one character is found in the font texture and how it is placed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glyph {
+    /// Pixels of the glyph in the font texture.
+    pub region: AtlasRegion,
+    /// Offset from the pen position to the glyph's top-left corner, y down.
+    pub offset: Vec2,
+    /// Distance the pen moves after the glyph.
+    pub advance: f32,
+}
+
+/// One glyph placed by [`BitmapFont::layout`], in font pixels from the text's
+/// origin with y pointing down.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphQuad {
+    pub ch: char,
+    /// Top-left corner.
+    pub min: Vec2,
+    pub size: Vec2,
+    pub uv: UvRect,
+}
+
+/// The result of laying out a string.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextLayout {
+    /// Visible glyphs in reading order; whitespace produces none.
+    pub glyphs: Vec<GlyphQuad>,
+    /// Width of the widest line, in font pixels.
+    pub width: f32,
+    /// Height of all lines together, in font pixels.
+    pub height: f32,
+    /// Number of lines after wrapping.
+    pub lines: usize,
+}
+
+/// A font whose glyphs are regions of a single texture.
+///
+/// Characters without a glyph are drawn as `?` if the font has one, and
+/// otherwise skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitmapFont {
+    texture: TextureId,
+    texture_size: (usize, usize),
+    line_height: f32,
+    base: f32,
+    glyphs: BTreeMap<char, Glyph>,
+    kerning: BTreeMap<(char, char), f32>,
+}
+
+impl BitmapFont {
+    /// Returns the texture of the built-in font: white glyphs on transparent pixels.
+    ///
+    /// Add it to the [`super::Textures`] and pass its id to [`Self::builtin`].
+    pub fn builtin_texture() -> Texture {
+        let (cell_width, cell_height) = BUILTIN_CELL;
+        let rows = GLYPHS.len().div_ceil(BUILTIN_COLUMNS);
+        let width = BUILTIN_COLUMNS * cell_width;
+        let mut pixels = vec![[0; 4]; width * rows * cell_height];
+        for (index, glyph) in GLYPHS.iter().enumerate() {
+            let (left, top) = (
+                index % BUILTIN_COLUMNS * cell_width,
+                index / BUILTIN_COLUMNS * cell_height,
+            );
+            for (y, bits) in glyph.iter().enumerate() {
+                for x in 0..GLYPH_WIDTH {
+                    if bits & (1 << (GLYPH_WIDTH - 1 - x)) != 0 {
+                        pixels[(top + y) * width + left + x] = [255; 4];
+                    }
+                }
+            }
+        }
+        Texture::new(width, rows * cell_height, pixels).expect("pixel count matches size")
+    }
+
+    /// Creates the built-in fixed-width font covering printable ASCII.
+    ///
+    /// Glyphs are 5x7 pixels with one pixel of spacing; a line is 8 pixels high.
+    /// `texture` must hold [`Self::builtin_texture`].
+    pub fn builtin(texture: TextureId) -> Self {
+        let (cell_width, cell_height) = BUILTIN_CELL;
+        let glyphs = (0..GLYPHS.len())
+            .map(|index| {
+                let ch = char::from(FIRST_CHAR as u8 + index as u8);
+                let glyph = Glyph {
+                    region: AtlasRegion {
+                        x: index % BUILTIN_COLUMNS * cell_width,
+                        y: index / BUILTIN_COLUMNS * cell_height,
+                        width: GLYPH_WIDTH,
+                        height: GLYPH_HEIGHT,
+                    },
+                    offset: Vec2::ZERO,
+                    advance: cell_width as f32,
+                };
+                (ch, glyph)
+            })
+            .collect();
+        Self {
+            texture,
+            texture_size: (
+                BUILTIN_COLUMNS * cell_width,
+                GLYPHS.len().div_ceil(BUILTIN_COLUMNS) * cell_height,
+            ),
+            line_height: cell_height as f32,
+            base: GLYPH_HEIGHT as f32,
+            glyphs,
+            kerning: BTreeMap::new(),
+        }
+    }
+
+    /// Parses a BMFont text descriptor (`.fnt`) whose single page is `texture`.
+    ///
+    /// Reads the `common`, `char` and `kerning` lines; other lines are ignored.
+    pub fn parse_bmfont(source: &str, texture: TextureId) -> Result<Self, FontError> {
+        let mut common = None;
+        let mut glyphs = BTreeMap::new();
+        let mut kerning = BTreeMap::new();
+
+        for (index, raw) in source.lines().enumerate() {
+            let line = index + 1;
+            let mut tokens = raw.split_whitespace();
+            let Some(tag) = tokens.next() else {
+                continue;
+            };
+            let attributes = parse_attributes(tokens);
+            let number = |key: &str| -> Result<i64, FontError> {
+                attributes
+                    .get(key)
+                    .and_then(|value| value.parse().ok())
+                    .ok_or(FontError::InvalidLine { line })
+            };
+            let size = |key: &str| -> Result<usize, FontError> {
+                usize::try_from(number(key)?).map_err(|_| FontError::InvalidLine { line })
+            };
+
+            match tag {
+                "common" => {
+                    if attributes.contains_key("pages") && number("pages")? > 1 {
+                        return Err(FontError::MultiplePages);
+                    }
+                    common = Some((
+                        number("lineHeight")? as f32,
+                        number("base")? as f32,
+                        (size("scaleW")?, size("scaleH")?),
+                    ));
+                }
+                "char" => {
+                    if attributes.contains_key("page") && number("page")? != 0 {
+                        return Err(FontError::MultiplePages);
+                    }
+                    let ch = u32::try_from(number("id")?)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or(FontError::InvalidLine { line })?;
+                    let glyph = Glyph {
+                        region: AtlasRegion {
+                            x: size("x")?,
+                            y: size("y")?,
+                            width: size("width")?,
+                            height: size("height")?,
+                        },
+                        offset: Vec2::new(number("xoffset")? as f32, number("yoffset")? as f32),
+                        advance: number("xadvance")? as f32,
+                    };
+                    glyphs.insert(ch, glyph);
+                }
+                "kerning" => {
+                    let to_char = |key: &str| -> Result<char, FontError> {
+                        u32::try_from(number(key)?)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or(FontError::InvalidLine { line })
+                    };
+                    kerning.insert(
+                        (to_char("first")?, to_char("second")?),
+                        number("amount")? as f32,
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        let (line_height, base, texture_size) = common.ok_or(FontError::MissingCommon)?;
+        Ok(Self {
+            texture,
+            texture_size,
+            line_height,
+            base,
+            glyphs,
+            kerning,
+        })
+    }
+
+    /// Returns the texture holding the glyphs.
+    pub fn texture(&self) -> TextureId {
+        self.texture
+    }
+
+    /// Returns the distance between the tops of two lines, in font pixels.
+    pub fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    /// Returns the distance from the top of a line to its baseline, in font pixels.
+    pub fn base(&self) -> f32 {
+        self.base
+    }
+
+    /// Returns the glyph for `ch`, if the font has one.
+    pub fn glyph(&self, ch: char) -> Option<&Glyph> {
+        self.glyphs.get(&ch)
+    }
+
+    /// Returns the extra advance between two consecutive characters.
+    pub fn kerning(&self, first: char, second: char) -> f32 {
+        self.kerning.get(&(first, second)).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the width of a single line of text, in font pixels.
+    pub fn measure(&self, text: &str) -> f32 {
+        let chars: Vec<char> = text.chars().collect();
+        self.line_width(&chars)
+    }
+
+    /// Lays out `text` line by line from the origin downwards.
+    ///
+    /// Lines break at `\n` and, with a `max_width` in font pixels, between words
+    /// that would not fit; words longer than a line are broken between characters.
+    pub fn layout(&self, text: &str, align: TextAlign, max_width: Option<f32>) -> TextLayout {
+        let mut layout = TextLayout::default();
+        let lines = text
+            .split('\n')
+            .flat_map(|paragraph| self.wrap(paragraph, max_width));
+
+        for (row, line) in lines.enumerate() {
+            let width = self.line_width(&line);
+            let mut pen = match align {
+                TextAlign::Left => 0.0,
+                TextAlign::Center => -width * 0.5,
+                TextAlign::Right => -width,
+            };
+            let top = row as f32 * self.line_height;
+            let mut previous = None;
+            for &ch in &line {
+                let Some((shown, glyph)) = self.resolve(ch) else {
+                    continue;
+                };
+                if let Some(previous) = previous {
+                    pen += self.kerning(previous, shown);
+                }
+                let region = glyph.region;
+                if !ch.is_whitespace() && region.width > 0 && region.height > 0 {
+                    layout.glyphs.push(GlyphQuad {
+                        ch,
+                        min: Vec2::new(pen, top) + glyph.offset,
+                        size: Vec2::new(region.width as f32, region.height as f32),
+                        uv: self.uv(region),
+                    });
+                }
+                pen += glyph.advance;
+                previous = Some(shown);
+            }
+            layout.width = layout.width.max(width);
+            layout.lines += 1;
+        }
+        layout.height = layout.lines as f32 * self.line_height;
+        layout
+    }
+
+    /// Returns the glyph drawn for `ch`, falling back to `?`.
+    fn resolve(&self, ch: char) -> Option<(char, &Glyph)> {
+        self.glyphs
+            .get(&ch)
+            .map(|glyph| (ch, glyph))
+            .or_else(|| self.glyphs.get(&'?').map(|glyph| ('?', glyph)))
+    }
+
+    fn line_width(&self, line: &[char]) -> f32 {
+        let mut width = 0.0;
+        let mut previous = None;
+        for &ch in line {
+            if let Some((shown, glyph)) = self.resolve(ch) {
+                if let Some(previous) = previous {
+                    width += self.kerning(previous, shown);
+                }
+                width += glyph.advance;
+                previous = Some(shown);
+            }
+        }
+        width
+    }
+
+    /// Splits one paragraph into lines no wider than `max_width`, where possible.
+    fn wrap(&self, paragraph: &str, max_width: Option<f32>) -> Vec<Vec<char>> {
+        let Some(max_width) = max_width else {
+            return vec![paragraph.chars().collect()];
+        };
+
+        let mut lines = Vec::new();
+        let mut line: Vec<char> = Vec::new();
+        for word in paragraph.split(' ') {
+            let mut candidate = line.clone();
+            if !line.is_empty() {
+                candidate.push(' ');
+            }
+            candidate.extend(word.chars());
+            if line.is_empty() || self.line_width(&candidate) <= max_width {
+                line = candidate;
+            } else {
+                lines.push(std::mem::take(&mut line));
+                line = word.chars().collect();
+            }
+
+            // A single word wider than a line is broken between characters
+            while line.len() > 1 && self.line_width(&line) > max_width {
+                let split = (1..line.len())
+                    .rev()
+                    .find(|&count| self.line_width(&line[..count]) <= max_width)
+                    .unwrap_or(1);
+                let rest = line.split_off(split);
+                lines.push(std::mem::replace(&mut line, rest));
+            }
+        }
+        lines.push(line);
+        lines
+    }
+
+    fn uv(&self, region: AtlasRegion) -> UvRect {
+        let (width, height) = (
+            self.texture_size.0.max(1) as f32,
+            self.texture_size.1.max(1) as f32,
+        );
+        UvRect::new(
+            Vec2::new(region.x as f32 / width, region.y as f32 / height),
+            Vec2::new(
+                (region.x + region.width) as f32 / width,
+                (region.y + region.height) as f32 / height,
+            ),
+        )
+    }
+}
+
+/// Splits `key=value` tokens, joining quoted values that contain spaces.
+fn parse_attributes<'a>(tokens: impl Iterator<Item = &'a str>) -> BTreeMap<&'a str, String> {
+    let mut attributes = BTreeMap::new();
+    let mut open: Option<(&str, String)> = None;
+    for token in tokens {
+        if let Some((key, mut value)) = open.take() {
+            value.push(' ');
+            value.push_str(token.trim_end_matches('"'));
+            if token.ends_with('"') {
+                attributes.insert(key, value);
+            } else {
+                open = Some((key, value));
+            }
+            continue;
+        }
+        let Some((key, value)) = token.split_once('=') else {
+            continue;
+        };
+        match value.strip_prefix('"') {
+            Some(rest) => match rest.strip_suffix('"') {
+                Some(complete) => {
+                    attributes.insert(key, complete.to_string());
+                }
+                None => open = Some((key, rest.to_string())),
+            },
+            None => {
+                attributes.insert(key, value.to_string());
+            }
+        }
+    }
+    attributes
+}
+
+/// An identifier for a font in the [`Fonts`] registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FontId(pub usize);
+
+/// Resource owning every font text can be drawn with.
+///
+/// [`Self::add`] hands out [`FontId`]s in insertion order. Fonts are never
+/// removed, so an id stays valid for the lifetime of the registry.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Fonts {
+    fonts: Vec<BitmapFont>,
+}
+
+impl Fonts {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores a font and returns its id.
+    pub fn add(&mut self, font: BitmapFont) -> FontId {
+        self.fonts.push(font);
+        FontId(self.fonts.len() - 1)
+    }
+
+    /// Returns the font with the given id.
+    pub fn get(&self, id: FontId) -> Option<&BitmapFont> {
+        self.fonts.get(id.0)
+    }
+
+    /// Returns `true` if `id` names a registered font.
+    pub fn contains(&self, id: FontId) -> bool {
+        id.0 < self.fonts.len()
+    }
+
+    /// Returns the number of registered fonts.
+    pub fn len(&self) -> usize {
+        self.fonts.len()
+    }
+
+    /// Returns `true` if no font is registered.
+    pub fn is_empty(&self) -> bool {
+        self.fonts.is_empty()
+    }
+}
@@ -0,0 +1,188 @@
+//! Framebuffer.
+//!
+//! CPU-side color and depth storage.
+//! Pixel access for renderers and tests.
+
+use super::BlendMode;
+
+/// An RGBA color buffer with a matching depth buffer.
+///
+/// Pixels are stored row by row from the top-left corner. Depth runs from
+/// 0.0 (near) to 1.0 (far); cleared depth is 1.0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Framebuffer {
+    width: usize,
+    height: usize,
+    color: Vec<[u8; 4]>,
+    depth: Vec<f32>,
+}
+
+impl Framebuffer {
+    /// Creates a framebuffer cleared to transparent black and far depth.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            color: vec![[0; 4]; width * height],
+            depth: vec![1.0; width * height],
+        }
+    }
+
+    /// Returns the width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Fills the color buffer with `color` and resets depth to far.
+    pub fn clear(&mut self, color: [u8; 4]) {
+        self.color.fill(color);
+        self.depth.fill(1.0);
+    }
+
+    /// Fills the pixels in `left..right` and `top..bottom` with `color` and resets their depth.
+    ///
+    /// The rectangle is clamped to the buffer.
+    pub fn clear_rect(
+        &mut self,
+        left: usize,
+        top: usize,
+        right: usize,
+        bottom: usize,
+        color: [u8; 4],
+    ) {
+        let (right, bottom) = (right.min(self.width), bottom.min(self.height));
+        for y in top..bottom {
+            for x in left..right {
+                let i = y * self.width + x;
+                self.color[i] = color;
+                self.depth[i] = 1.0;
+            }
+        }
+    }
+
+    /// Returns the color at a pixel, or `None` outside the buffer.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<[u8; 4]> {
+        self.index(x, y).map(|i| self.color[i])
+    }
+
+    /// Overwrites the color at a pixel. Does nothing outside the buffer.
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: [u8; 4]) {
+        if let Some(i) = self.index(x, y) {
+            self.color[i] = color;
+        }
+    }
+
+    /// Returns the depth at a pixel, or `None` outside the buffer.
+    pub fn depth(&self, x: usize, y: usize) -> Option<f32> {
+        self.index(x, y).map(|i| self.depth[i])
+    }
+
+    /// Returns all pixels, row by row.
+    pub fn pixels(&self) -> &[[u8; 4]] {
+        &self.color
+    }
+
+    /// Returns the color buffer as tightly packed RGBA bytes.
+    pub fn to_rgba_bytes(&self) -> Vec<u8> {
+        self.color.iter().flatten().copied().collect()
+    }
+
+    /// Depth-tests and blends one fragment.
+    ///
+    /// With [`BlendMode::Alpha`], opaque fragments replace the color and write
+    /// depth while translucent ones are blended over it and leave depth untouched.
+    /// See [`BlendMode`] for the other modes.
+    pub(crate) fn blend_fragment(
+        &mut self,
+        x: usize,
+        y: usize,
+        depth: f32,
+        color: [u8; 4],
+        blend: BlendMode,
+    ) {
+        let Some(i) = self.index(x, y) else {
+            return;
+        };
+        if depth > self.depth[i] || (blend != BlendMode::Opaque && color[3] == 0) {
+            return;
+        }
+
+        match blend {
+            BlendMode::Opaque => {
+                self.color[i] = [color[0], color[1], color[2], 255];
+                self.depth[i] = depth;
+            }
+            BlendMode::Alpha if color[3] == 255 => {
+                self.color[i] = color;
+                self.depth[i] = depth;
+            }
+            BlendMode::Alpha => self.color[i] = blend_over(color, self.color[i]),
+            BlendMode::Additive => self.color[i] = blend_add(color, self.color[i]),
+            BlendMode::Multiply => self.color[i] = blend_multiply(color, self.color[i]),
+        }
+    }
+
+    /// Blends one fragment over the pixel without depth testing or writing depth.
+    pub(crate) fn blend_pixel(&mut self, x: usize, y: usize, color: [u8; 4]) {
+        if let Some(i) = self.index(x, y) {
+            self.color[i] = blend_over(color, self.color[i]);
+        }
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+}
+
+/// Adds `src`, weighted by its alpha, to `dst`, saturating each channel.
+fn blend_add(src: [u8; 4], dst: [u8; 4]) -> [u8; 4] {
+    let channel = |s: u8, d: u8| (d as u32 + (s as u32 * src[3] as u32 + 127) / 255).min(255) as u8;
+    [
+        channel(src[0], dst[0]),
+        channel(src[1], dst[1]),
+        channel(src[2], dst[2]),
+        dst[3],
+    ]
+}
+
+/// Multiplies `dst` by `src`, fading towards no change as `src` alpha drops.
+fn blend_multiply(src: [u8; 4], dst: [u8; 4]) -> [u8; 4] {
+    let alpha = src[3] as u32;
+    let channel = |s: u8, d: u8| {
+        // Lerp the multiplier from white to `s` by alpha
+        let factor = (255 * (255 - alpha) + s as u32 * alpha + 127) / 255;
+        ((d as u32 * factor + 127) / 255) as u8
+    };
+    [
+        channel(src[0], dst[0]),
+        channel(src[1], dst[1]),
+        channel(src[2], dst[2]),
+        dst[3],
+    ]
+}
+
+/// Composites `src` over `dst` with straight (non-premultiplied) alpha.
+pub(crate) fn blend_over(src: [u8; 4], dst: [u8; 4]) -> [u8; 4] {
+    let sa = src[3] as f32 / 255.0;
+    let da = dst[3] as f32 / 255.0;
+    let out_a = sa + da * (1.0 - sa);
+    if out_a <= 0.0 {
+        return [0; 4];
+    }
+
+    let channel = |s: u8, d: u8| {
+        let c = (s as f32 * sa + d as f32 * da * (1.0 - sa)) / out_a;
+        c.round().clamp(0.0, 255.0) as u8
+    };
+    [
+        channel(src[0], dst[0]),
+        channel(src[1], dst[1]),
+        channel(src[2], dst[2]),
+        (out_a * 255.0).round() as u8,
+    ]
+}
@@ -0,0 +1,569 @@
+//! Image encoding.
+//!
+//! PPM and PNG encoders and decoders.
+//! Dependency-free, for exporting and comparing frames.
+
+use std::path::Path;
+
+use super::{ImageError, Texture};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Largest payload of a single stored deflate block.
+const STORED_BLOCK_LEN: usize = 65_535;
+
+/// Encodes RGBA pixels as a binary PPM (`P6`). Alpha is dropped.
+///
+/// # Panics
+///
+/// Panics if `pixels.len() != width * height`.
+pub fn encode_ppm(width: usize, height: usize, pixels: &[[u8; 4]]) -> Vec<u8> {
+    assert_eq!(pixels.len(), width * height, "pixel count must match size");
+    let mut out = format!("P6\n{width} {height}\n255\n").into_bytes();
+    for pixel in pixels {
+        out.extend_from_slice(&pixel[..3]);
+    }
+    out
+}
+
+/// Decodes a binary PPM (`P6`) with a maximum value of 255. Pixels come out opaque.
+pub fn decode_ppm(bytes: &[u8]) -> Result<Texture, ImageError> {
+    let mut pos = 0;
+    let mut fields = [0usize; 4];
+    for (i, field) in fields.iter_mut().enumerate() {
+        // Skip whitespace and comments between header fields
+        loop {
+            match bytes.get(pos) {
+                Some(b) if b.is_ascii_whitespace() => pos += 1,
+                Some(b'#') => {
+                    while bytes.get(pos).is_some_and(|&b| b != b'\n') {
+                        pos += 1;
+                    }
+                }
+                Some(_) => break,
+                None => return Err(ImageError::Truncated),
+            }
+        }
+        let start = pos;
+        while bytes.get(pos).is_some_and(|b| !b.is_ascii_whitespace()) {
+            pos += 1;
+        }
+        let token =
+            std::str::from_utf8(&bytes[start..pos]).map_err(|_| ImageError::InvalidFormat)?;
+        if i == 0 {
+            if token != "P6" {
+                return Err(ImageError::InvalidFormat);
+            }
+        } else {
+            *field = token.parse().map_err(|_| ImageError::InvalidFormat)?;
+        }
+    }
+    let [_, width, height, max_value] = fields;
+    if max_value != 255 {
+        return Err(ImageError::Unsupported);
+    }
+
+    // Exactly one whitespace byte separates the header from the raster
+    let data = bytes.get(pos + 1..).ok_or(ImageError::Truncated)?;
+    let len = width
+        .checked_mul(height)
+        .and_then(|pixels| pixels.checked_mul(3))
+        .ok_or(ImageError::TooLarge)?;
+    if data.len() < len {
+        return Err(ImageError::Truncated);
+    }
+    let pixels = data[..len]
+        .chunks_exact(3)
+        .map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+        .collect();
+    Texture::new(width, height, pixels).ok_or(ImageError::InvalidFormat)
+}
+
+/// Encodes RGBA pixels as an 8-bit RGBA PNG.
+///
+/// The image data is stored uncompressed inside the zlib stream, which keeps
+/// the encoder tiny; any PNG reader can open the result.
+///
+/// # Panics
+///
+/// Panics if `pixels.len() != width * height`.
+pub fn encode_png(width: usize, height: usize, pixels: &[[u8; 4]]) -> Vec<u8> {
+    assert_eq!(pixels.len(), width * height, "pixel count must match size");
+
+    let mut raw = Vec::with_capacity(height * (width * 4 + 1));
+    for row in pixels.chunks(width.max(1)).take(height) {
+        raw.push(0); // filter type: none
+        for pixel in row {
+            raw.extend_from_slice(pixel);
+        }
+    }
+
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(STORED_BLOCK_LEN).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        zlib.push(last as u8);
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    let mut out = PNG_SIGNATURE.to_vec();
+    write_chunk(&mut out, b"IHDR", &header);
+    write_chunk(&mut out, b"IDAT", &zlib);
+    write_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+/// Decodes an 8-bit, non-interlaced PNG (grayscale, RGB, with or without alpha).
+pub fn decode_png(bytes: &[u8]) -> Result<Texture, ImageError> {
+    if bytes.len() < 8 || bytes[..8] != PNG_SIGNATURE {
+        return Err(ImageError::InvalidFormat);
+    }
+
+    let mut pos = 8;
+    let mut header = None;
+    let mut compressed = Vec::new();
+    loop {
+        let len = read_u32(bytes, pos)? as usize;
+        let kind = bytes.get(pos + 4..pos + 8).ok_or(ImageError::Truncated)?;
+        let data = bytes
+            .get(pos + 8..pos + 8 + len)
+            .ok_or(ImageError::Truncated)?;
+        let crc = read_u32(bytes, pos + 8 + len)?;
+        if crc32(&[kind, data]) != crc {
+            return Err(ImageError::Corrupt);
+        }
+        pos += 12 + len;
+
+        match kind {
+            b"IHDR" => {
+                if data.len() != 13 {
+                    return Err(ImageError::InvalidFormat);
+                }
+                let width = read_u32(data, 0)? as usize;
+                let height = read_u32(data, 4)? as usize;
+                let (depth, color_type, interlace) = (data[8], data[9], data[12]);
+                if depth != 8 || interlace != 0 {
+                    return Err(ImageError::Unsupported);
+                }
+                let channels = match color_type {
+                    0 => 1,
+                    2 => 3,
+                    4 => 2,
+                    6 => 4,
+                    _ => return Err(ImageError::Unsupported),
+                };
+                header = Some((width, height, channels));
+            }
+            b"IDAT" => compressed.extend_from_slice(data),
+            b"IEND" => break,
+            // Ancillary chunks (lowercase first letter) can be skipped; critical ones cannot
+            _ if kind[0].is_ascii_lowercase() => {}
+            _ => return Err(ImageError::Unsupported),
+        }
+    }
+
+    let (width, height, channels) = header.ok_or(ImageError::InvalidFormat)?;
+    let raw = zlib_decompress(&compressed)?;
+    // Header sizes are untrusted, so every product is checked
+    let stride = width.checked_mul(channels).ok_or(ImageError::TooLarge)?;
+    let len = stride
+        .checked_add(1)
+        .and_then(|row| row.checked_mul(height))
+        .ok_or(ImageError::TooLarge)?;
+    if raw.len() < len {
+        return Err(ImageError::Truncated);
+    }
+
+    let mut previous = vec![0u8; stride];
+    let mut pixels = Vec::with_capacity(width * height);
+    for row in raw.chunks_exact(stride + 1).take(height) {
+        let mut current = row[1..].to_vec();
+        unfilter(row[0], &mut current, &previous, channels)?;
+        for px in current.chunks_exact(channels) {
+            pixels.push(match channels {
+                1 => [px[0], px[0], px[0], 255],
+                2 => [px[0], px[0], px[0], px[1]],
+                3 => [px[0], px[1], px[2], 255],
+                _ => [px[0], px[1], px[2], px[3]],
+            });
+        }
+        previous = current;
+    }
+    Texture::new(width, height, pixels).ok_or(ImageError::InvalidFormat)
+}
+
+/// Writes RGBA pixels to a file, as PPM if the extension is `ppm` and PNG otherwise.
+pub fn save_image(
+    path: impl AsRef<Path>,
+    width: usize,
+    height: usize,
+    pixels: &[[u8; 4]],
+) -> Result<(), ImageError> {
+    let path = path.as_ref();
+    let bytes = if path.extension().is_some_and(|ext| ext == "ppm") {
+        encode_ppm(width, height, pixels)
+    } else {
+        encode_png(width, height, pixels)
+    };
+    std::fs::write(path, bytes).map_err(|e| ImageError::Io(e.kind()))
+}
+
+/// Reads a PPM or PNG file, detected from its contents.
+pub fn load_image(path: impl AsRef<Path>) -> Result<Texture, ImageError> {
+    let bytes = std::fs::read(path).map_err(|e| ImageError::Io(e.kind()))?;
+    if bytes.starts_with(&PNG_SIGNATURE) {
+        decode_png(&bytes)
+    } else {
+        decode_ppm(&bytes)
+    }
+}
+
+fn read_u32(bytes: &[u8], pos: usize) -> Result<u32, ImageError> {
+    let slice = bytes.get(pos..pos + 4).ok_or(ImageError::Truncated)?;
+    Ok(u32::from_be_bytes([slice[0], slice[1], slice[2], slice[3]]))
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    out.extend_from_slice(&crc32(&[kind, data]).to_be_bytes());
+}
+
+/// CRC-32 (ISO 3309) over the concatenation of `parts`, as used by PNG chunks.
+fn crc32(parts: &[&[u8]]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut n = 0;
+        while n < 256 {
+            let mut c = n as u32;
+            let mut k = 0;
+            while k < 8 {
+                c = if c & 1 != 0 {
+                    0xedb8_8320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                };
+                k += 1;
+            }
+            table[n] = c;
+            n += 1;
+        }
+        table
+    };
+
+    let mut crc = 0xffff_ffffu32;
+    for &byte in parts.iter().flat_map(|part| part.iter()) {
+        crc = TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65_521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % MOD;
+        b = (b + a) % MOD;
+    }
+    (b << 16) | a
+}
+
+/// Reverses one PNG scanline filter in place.
+fn unfilter(
+    filter: u8,
+    row: &mut [u8],
+    previous: &[u8],
+    channels: usize,
+) -> Result<(), ImageError> {
+    for i in 0..row.len() {
+        let left = if i >= channels { row[i - channels] } else { 0 };
+        let up = previous[i];
+        let up_left = if i >= channels {
+            previous[i - channels]
+        } else {
+            0
+        };
+        let predictor = match filter {
+            0 => 0,
+            1 => left,
+            2 => up,
+            3 => ((left as u16 + up as u16) / 2) as u8,
+            4 => paeth(left, up, up_left),
+            _ => return Err(ImageError::Corrupt),
+        };
+        row[i] = row[i].wrapping_add(predictor);
+    }
+    Ok(())
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = (
+        (p - a as i16).abs(),
+        (p - b as i16).abs(),
+        (p - c as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Reads a zlib stream, checking its header and Adler-32 checksum.
+fn zlib_decompress(data: &[u8]) -> Result<Vec<u8>, ImageError> {
+    if data.len() < 6 {
+        return Err(ImageError::Truncated);
+    }
+    let (cmf, flg) = (data[0], data[1]);
+    if cmf & 0x0f != 8 || !(cmf as u16 * 256 + flg as u16).is_multiple_of(31) || flg & 0x20 != 0 {
+        return Err(ImageError::Corrupt);
+    }
+
+    let mut reader = BitReader {
+        data: &data[2..],
+        pos: 0,
+        bit: 0,
+    };
+    let out = inflate(&mut reader)?;
+
+    let checksum_at = 2 + reader.byte_end();
+    let expected = read_u32(data, checksum_at)?;
+    if adler32(&out) != expected {
+        return Err(ImageError::Corrupt);
+    }
+    Ok(out)
+}
+
+/// Reads deflate data least-significant bit first.
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    bit: u8,
+}
+
+impl BitReader<'_> {
+    fn bits(&mut self, count: u8) -> Result<u32, ImageError> {
+        let mut value = 0;
+        for i in 0..count {
+            let byte = *self.data.get(self.pos).ok_or(ImageError::Truncated)?;
+            value |= (((byte >> self.bit) & 1) as u32) << i;
+            self.bit += 1;
+            if self.bit == 8 {
+                self.bit = 0;
+                self.pos += 1;
+            }
+        }
+        Ok(value)
+    }
+
+    fn align_to_byte(&mut self) {
+        if self.bit != 0 {
+            self.bit = 0;
+            self.pos += 1;
+        }
+    }
+
+    /// Offset of the first byte after everything read so far.
+    fn byte_end(&self) -> usize {
+        self.pos + (self.bit != 0) as usize
+    }
+}
+
+/// A canonical Huffman code as bit-length counts and symbols sorted by code.
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Self {
+        let mut counts = [0u16; 16];
+        for &len in lengths {
+            counts[len as usize] += 1;
+        }
+        counts[0] = 0;
+
+        let mut offsets = [0u16; 16];
+        for len in 1..16 {
+            offsets[len] = offsets[len - 1] + counts[len - 1];
+        }
+        let mut symbols = vec![0; lengths.iter().filter(|&&l| l != 0).count()];
+        for (symbol, &len) in lengths.iter().enumerate() {
+            if len != 0 {
+                symbols[offsets[len as usize] as usize] = symbol as u16;
+                offsets[len as usize] += 1;
+            }
+        }
+        Self { counts, symbols }
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16, ImageError> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for len in 1..16 {
+            code |= reader.bits(1)? as i32;
+            let count = self.counts[len] as i32;
+            if code - first < count {
+                return self
+                    .symbols
+                    .get((index + code - first) as usize)
+                    .copied()
+                    .ok_or(ImageError::Corrupt);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(ImageError::Corrupt)
+    }
+}
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+/// Order in which code length code lengths are stored in a dynamic block header.
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+fn inflate(reader: &mut BitReader) -> Result<Vec<u8>, ImageError> {
+    let mut out = Vec::new();
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => {
+                reader.align_to_byte();
+                let header = reader
+                    .data
+                    .get(reader.pos..reader.pos + 4)
+                    .ok_or(ImageError::Truncated)?;
+                let len = u16::from_le_bytes([header[0], header[1]]);
+                let nlen = u16::from_le_bytes([header[2], header[3]]);
+                if len != !nlen {
+                    return Err(ImageError::Corrupt);
+                }
+                let start = reader.pos + 4;
+                let block = reader
+                    .data
+                    .get(start..start + len as usize)
+                    .ok_or(ImageError::Truncated)?;
+                out.extend_from_slice(block);
+                reader.pos = start + len as usize;
+            }
+            1 => {
+                let mut lengths = [0u8; 288];
+                lengths[..144].fill(8);
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                lengths[280..].fill(8);
+                let literals = Huffman::new(&lengths);
+                let distances = Huffman::new(&[5; 30]);
+                inflate_block(reader, &mut out, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = read_dynamic_tables(reader)?;
+                inflate_block(reader, &mut out, &literals, &distances)?;
+            }
+            _ => return Err(ImageError::Corrupt),
+        }
+        if last {
+            return Ok(out);
+        }
+    }
+}
+
+fn read_dynamic_tables(reader: &mut BitReader) -> Result<(Huffman, Huffman), ImageError> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+
+    let mut code_lengths = [0u8; 19];
+    for &index in &CODE_LENGTH_ORDER[..code_length_count] {
+        code_lengths[index] = reader.bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_lengths);
+
+    let mut lengths = Vec::with_capacity(literal_count + distance_count);
+    while lengths.len() < literal_count + distance_count {
+        let symbol = code_length_code.decode(reader)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *lengths.last().ok_or(ImageError::Corrupt)?;
+                (previous, 3 + reader.bits(2)?)
+            }
+            17 => (0, 3 + reader.bits(3)?),
+            18 => (0, 11 + reader.bits(7)?),
+            _ => return Err(ImageError::Corrupt),
+        };
+        lengths.extend(std::iter::repeat_n(value, repeat as usize));
+    }
+    if lengths.len() != literal_count + distance_count {
+        return Err(ImageError::Corrupt);
+    }
+
+    Ok((
+        Huffman::new(&lengths[..literal_count]),
+        Huffman::new(&lengths[literal_count..]),
+    ))
+}
+
+fn inflate_block(
+    reader: &mut BitReader,
+    out: &mut Vec<u8>,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<(), ImageError> {
+    loop {
+        let symbol = literals.decode(reader)? as usize;
+        match symbol {
+            0..=255 => out.push(symbol as u8),
+            256 => return Ok(()),
+            257..=285 => {
+                let i = symbol - 257;
+                let len = LENGTH_BASE[i] as usize + reader.bits(LENGTH_EXTRA[i])? as usize;
+                let d = distances.decode(reader)? as usize;
+                if d >= 30 {
+                    return Err(ImageError::Corrupt);
+                }
+                let distance = DISTANCE_BASE[d] as usize + reader.bits(DISTANCE_EXTRA[d])? as usize;
+                if distance > out.len() {
+                    return Err(ImageError::Corrupt);
+                }
+                // Copy byte by byte: the source may overlap what is being written
+                let start = out.len() - distance;
+                for i in 0..len {
+                    out.push(out[start + i]);
+                }
+            }
+            _ => return Err(ImageError::Corrupt),
+        }
+    }
+}
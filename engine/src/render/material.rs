@@ -0,0 +1,217 @@
+//! Materials.
+//!
+//! How the surface of a mesh is drawn.
+//! Base color, texture, blend mode and shader parameters.
+//! Registry of materials referenced by render commands.
+
+use std::collections::BTreeMap;
+
+use super::{RenderCommand, RenderPass, TextureId};
+use crate::math::{Vec2, Vec3, Vec4};
+
+/// Number of materials every registry starts with.
+const BUILT_IN_MATERIALS: usize = 1;
+
+/// How a fragment is combined with the color already in the framebuffer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BlendMode {
+    /// Replaces the color and writes depth; alpha is ignored.
+    Opaque,
+    /// Composites over the color by alpha; fully opaque fragments write depth.
+    #[default]
+    Alpha,
+    /// Adds the color weighted by alpha, brightening what is behind.
+    Additive,
+    /// Multiplies the color, darkening what is behind.
+    Multiply,
+}
+
+/// A named shader parameter of a [`Material`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Uniform {
+    Int(i32),
+    Float(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Vec4(Vec4),
+    Color([u8; 4]),
+    Texture(TextureId),
+}
+
+/// Describes how a mesh is drawn: its color, texture, blending and any
+/// parameters a backend's shaders read.
+///
+/// A command's color tints the base color, and a command's texture takes the
+/// place of the material's.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Material {
+    /// Color as sRGB red, green, blue and alpha bytes.
+    pub base_color: [u8; 4],
+    pub texture: Option<TextureId>,
+    pub blend: BlendMode,
+    uniforms: BTreeMap<String, Uniform>,
+}
+
+impl Material {
+    /// Float uniform below which the software renderer discards a fragment's
+    /// alpha, from 0.0 to 1.0.
+    pub const ALPHA_CUTOFF: &'static str = "alpha_cutoff";
+
+    /// Creates a white, untextured, alpha-blended material without uniforms.
+    pub fn new() -> Self {
+        Self {
+            base_color: [255; 4],
+            texture: None,
+            blend: BlendMode::Alpha,
+            uniforms: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the material with another base color.
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.base_color = color;
+        self
+    }
+
+    /// Returns the material sampling a texture.
+    pub fn with_texture(mut self, texture: TextureId) -> Self {
+        self.texture = Some(texture);
+        self
+    }
+
+    /// Returns the material with another blend mode.
+    pub fn with_blend(mut self, blend: BlendMode) -> Self {
+        self.blend = blend;
+        self
+    }
+
+    /// Returns the material with a uniform set.
+    pub fn with_uniform(mut self, name: impl Into<String>, value: Uniform) -> Self {
+        self.set_uniform(name, value);
+        self
+    }
+
+    /// Sets a uniform and returns its previous value.
+    pub fn set_uniform(&mut self, name: impl Into<String>, value: Uniform) -> Option<Uniform> {
+        self.uniforms.insert(name.into(), value)
+    }
+
+    /// Returns the uniform called `name`.
+    pub fn uniform(&self, name: &str) -> Option<&Uniform> {
+        self.uniforms.get(name)
+    }
+
+    /// Returns every uniform by name, in name order.
+    pub fn uniforms(&self) -> impl Iterator<Item = (&str, &Uniform)> {
+        self.uniforms
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Returns the base color tinted by a command's `color`.
+    pub fn color(&self, tint: [u8; 4]) -> [u8; 4] {
+        modulate(self.base_color, tint)
+    }
+
+    /// Returns the pass a command tinted by `tint` and sampling `texture` (its
+    /// own, or `None` for the material's) is drawn in with this material.
+    ///
+    /// Alpha blending counts as opaque only for an untextured, fully opaque color,
+    /// since a texture may have transparent texels; use [`BlendMode::Opaque`] to
+    /// draw textured meshes in the opaque pass.
+    pub fn pass(&self, tint: [u8; 4], texture: Option<TextureId>) -> RenderPass {
+        let textured = texture.or(self.texture).is_some();
+        match self.blend {
+            BlendMode::Opaque => RenderPass::Opaque,
+            BlendMode::Alpha if !textured && self.color(tint)[3] == 255 => RenderPass::Opaque,
+            _ => RenderPass::Transparent,
+        }
+    }
+}
+
+impl Default for Material {
+    /// Returns [`Material::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An identifier for a material in the [`Materials`] registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MaterialId(pub usize);
+
+/// Resource owning every material render commands can reference.
+///
+/// A new registry holds the built-in [`Materials::DEFAULT`]; [`Self::add`] hands
+/// out the following [`MaterialId`]s in insertion order. Materials are never
+/// removed, so an id stays valid for the lifetime of the registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Materials {
+    materials: Vec<Material>,
+}
+
+impl Materials {
+    /// The built-in [`Material::new`], used by commands that name no other material.
+    pub const DEFAULT: MaterialId = MaterialId(0);
+
+    /// Creates a registry holding only the built-in materials.
+    pub fn new() -> Self {
+        Self {
+            materials: vec![Material::new()],
+        }
+    }
+
+    /// Stores a material and returns its id.
+    pub fn add(&mut self, material: Material) -> MaterialId {
+        self.materials.push(material);
+        MaterialId(self.materials.len() - 1)
+    }
+
+    /// Returns the material with the given id.
+    pub fn get(&self, id: MaterialId) -> Option<&Material> {
+        self.materials.get(id.0)
+    }
+
+    /// Returns the material with the given id for editing.
+    pub fn get_mut(&mut self, id: MaterialId) -> Option<&mut Material> {
+        self.materials.get_mut(id.0)
+    }
+
+    /// Returns the material with the given id, or the default one for an unknown id.
+    pub fn resolve(&self, id: MaterialId) -> &Material {
+        self.get(id).unwrap_or(&self.materials[Self::DEFAULT.0])
+    }
+
+    /// Returns `true` if `id` names a registered material.
+    pub fn contains(&self, id: MaterialId) -> bool {
+        id.0 < self.materials.len()
+    }
+
+    /// Returns the number of materials added with [`Self::add`], not counting the built-in ones.
+    pub fn len(&self) -> usize {
+        self.materials.len() - BUILT_IN_MATERIALS
+    }
+
+    /// Returns `true` if no material was added besides the built-in ones.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the texture a command samples: its own, or else its material's.
+    pub fn texture_of(&self, command: &RenderCommand) -> Option<TextureId> {
+        command.texture.or(self.resolve(command.material).texture)
+    }
+}
+
+impl Default for Materials {
+    /// Returns [`Materials::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Multiplies two colors channel by channel.
+pub(crate) fn modulate(a: [u8; 4], b: [u8; 4]) -> [u8; 4] {
+    let channel = |i: usize| ((a[i] as u32 * b[i] as u32 + 127) / 255) as u8;
+    [channel(0), channel(1), channel(2), channel(3)]
+}
@@ -0,0 +1,134 @@
+//! Mesh data.
+//!
+//! Vertex and index storage for triangle meshes.
+//! Mesh registry handing out mesh ids.
+
+use super::{MeshError, MeshId, RenderCommand};
+use crate::math::{Vec2, Vec3};
+
+/// A mesh vertex: local-space position and texture coordinate.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Vertex {
+    pub position: Vec3,
+    /// Texture coordinate, with (0, 0) at the top-left of the texture.
+    pub uv: Vec2,
+}
+
+impl Vertex {
+    /// Creates a vertex.
+    pub const fn new(position: Vec3, uv: Vec2) -> Self {
+        Self { position, uv }
+    }
+}
+
+/// An indexed triangle list.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Mesh {
+    pub vertices: Vec<Vertex>,
+    /// Three indices per triangle into `vertices`.
+    pub indices: Vec<u32>,
+}
+
+impl Mesh {
+    /// Creates a mesh from vertices and triangle indices.
+    pub fn new(vertices: Vec<Vertex>, indices: Vec<u32>) -> Self {
+        Self { vertices, indices }
+    }
+
+    /// Returns the number of complete triangles.
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// Returns the vertices of each triangle, skipping any with out-of-range indices.
+    pub fn triangles(&self) -> impl Iterator<Item = [Vertex; 3]> + '_ {
+        self.indices.chunks_exact(3).filter_map(|tri| {
+            Some([
+                *self.vertices.get(tri[0] as usize)?,
+                *self.vertices.get(tri[1] as usize)?,
+                *self.vertices.get(tri[2] as usize)?,
+            ])
+        })
+    }
+}
+
+/// Number of meshes every registry starts with.
+const BUILT_IN_MESHES: usize = 1;
+
+/// Resource owning every mesh the renderers can draw.
+///
+/// A new registry holds the built-in [`Meshes::QUAD`]; [`Self::add`] hands out
+/// the following [`MeshId`]s in insertion order. Meshes are never removed, so
+/// an id stays valid for the lifetime of the registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Meshes {
+    meshes: Vec<Mesh>,
+}
+
+impl Meshes {
+    /// The built-in unit quad centered on the origin, used to draw sprites.
+    pub const QUAD: MeshId = MeshId(0);
+
+    /// Creates a registry holding only the built-in meshes.
+    pub fn new() -> Self {
+        Self {
+            meshes: vec![Mesh::quad(Vec2::ONE)],
+        }
+    }
+
+    /// Stores a mesh and returns its id.
+    pub fn add(&mut self, mesh: Mesh) -> MeshId {
+        self.meshes.push(mesh);
+        MeshId(self.meshes.len() - 1)
+    }
+
+    /// Returns the mesh with the given id.
+    pub fn get(&self, id: MeshId) -> Option<&Mesh> {
+        self.meshes.get(id.0)
+    }
+
+    /// Returns the mesh with the given id for editing.
+    pub fn get_mut(&mut self, id: MeshId) -> Option<&mut Mesh> {
+        self.meshes.get_mut(id.0)
+    }
+
+    /// Returns `true` if `id` names a registered mesh.
+    pub fn contains(&self, id: MeshId) -> bool {
+        id.0 < self.meshes.len()
+    }
+
+    /// Returns the number of meshes added with [`Self::add`], not counting the built-in ones.
+    pub fn len(&self) -> usize {
+        self.meshes.len() - BUILT_IN_MESHES
+    }
+
+    /// Returns `true` if no mesh was added besides the built-in ones.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns every mesh with its id, in id order, starting with the built-in ones.
+    pub fn iter(&self) -> impl Iterator<Item = (MeshId, &Mesh)> {
+        self.meshes
+            .iter()
+            .enumerate()
+            .map(|(index, mesh)| (MeshId(index), mesh))
+    }
+
+    /// Checks that every command names a registered mesh.
+    ///
+    /// Renderers skip unknown meshes silently; call this to catch them instead.
+    pub fn validate(&self, commands: &[RenderCommand]) -> Result<(), MeshError> {
+        match commands.iter().find(|command| !self.contains(command.mesh)) {
+            Some(command) => Err(MeshError::UnknownMesh(command.mesh)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for Meshes {
+    /// Returns [`Meshes::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -4,21 +4,134 @@
 //! Render pipelines and frame submission.
 //! Decoupled from ECS and game logic.
 
+mod atlas;
+mod batch;
+mod camera;
+mod color;
+mod debug;
+mod errors;
+mod font;
+mod font_data;
+mod framebuffer;
+pub mod image;
+mod material;
+mod mesh;
+mod primitives;
+mod renderer;
+mod terminal;
+mod texture;
+mod tiled;
+mod tilemap;
+
+pub use atlas::{AtlasBuilder, AtlasRegion, TextureAtlas, UvRect};
+pub use batch::{DrawBatch, FrameStats, RenderPass, build_batches, sort_for_view};
+pub use camera::{Camera, LayerMask, Projection, RenderTarget, RenderView, Viewport, view_matrix};
+pub use color::{Hsl, Hsv, LinearColor, linear_to_srgb, srgb_to_linear};
+pub use debug::{DebugDraw, DebugLabel, DebugLine, DebugOverlay, DebugShape};
+pub use errors::{AtlasError, FontError, ImageError, MeshError, TilemapError};
+pub use font::{BitmapFont, FontId, Fonts, Glyph, GlyphQuad, TextAlign, TextLayout};
+pub use framebuffer::Framebuffer;
+pub use material::{BlendMode, Material, MaterialId, Materials, Uniform};
+pub use mesh::{Mesh, Meshes, Vertex};
+pub use renderer::SoftwareRenderer;
+pub use terminal::{TerminalCell, TerminalRenderer};
+pub use texture::{Texture, TextureId, Textures};
+pub use tilemap::{Tile, TileFlags, TileLayer, Tilemap, Tileset};
+
+use crate::math::{Mat4, Quat, Vec3};
+
 /// A 3D transformation for rendering.
 /// Can be used for 2D by setting z=0 and ignoring depth.
-#[derive(Debug, Clone, Copy)]
+///
+/// Applied as scale, then rotation, then translation.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Transform {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
 }
 
-/// An identifier for a mesh resource.
-#[derive(Debug, Clone, Copy)]
+impl Transform {
+    /// The transform that leaves everything in place.
+    pub const IDENTITY: Transform = Transform {
+        translation: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        scale: Vec3::ONE,
+    };
+
+    /// Creates a transform that only translates.
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns the transform as a model matrix.
+    pub fn to_matrix(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Transforms a point from local space into the parent space.
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.translation + self.rotation * (point * self.scale)
+    }
+}
+
+impl Default for Transform {
+    /// Returns [`Transform::IDENTITY`].
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// An identifier for a mesh in the [`Meshes`] registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct MeshId(pub usize);
 
 /// A command to render a mesh at a specific transform.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RenderCommand {
     pub transform: Transform,
     pub mesh: MeshId,
+    /// Tint as sRGB red, green, blue and alpha bytes.
+    pub color: [u8; 4],
+    /// How the mesh is drawn; the color tints the material's base color.
+    pub material: MaterialId,
+    /// Texture sampled across the mesh in place of the material's, or `None` to use the material's.
+    pub texture: Option<TextureId>,
+    /// Part of the texture the mesh's texture coordinates span.
+    pub uv: UvRect,
+    /// Draw layer; lower layers are drawn first.
+    pub layer: i32,
+    /// Key that renderers sort commands by, see [`RenderCommand::sort_key_for`].
+    pub sort_key: u64,
+}
+
+impl RenderCommand {
+    /// Creates an untinted command with the default material on layer 0 with its sort key filled in.
+    pub fn new(transform: Transform, mesh: MeshId) -> Self {
+        Self {
+            transform,
+            mesh,
+            color: [255; 4],
+            material: Materials::DEFAULT,
+            texture: None,
+            uv: UvRect::FULL,
+            layer: 0,
+            sort_key: Self::sort_key_for(0, Materials::DEFAULT, mesh),
+        }
+    }
+
+    /// Builds a sort key ordering by layer first, then grouping equal materials
+    /// together and, within a material, equal meshes.
+    ///
+    /// The layer fills the high 32 bits with its sign bit flipped, so negative
+    /// layers sort before positive ones. The material and mesh take the low 16 bits
+    /// of their ids each, so ids 65536 apart share a key; [`sort_for_view`] breaks
+    /// such ties on the full ids.
+    pub fn sort_key_for(layer: i32, material: MaterialId, mesh: MeshId) -> u64 {
+        let layer_bits = (layer as u32 ^ 0x8000_0000) as u64;
+        (layer_bits << 32) | ((material.0 as u64 & 0xffff) << 16) | (mesh.0 as u64 & 0xffff)
+    }
 }
@@ -0,0 +1,152 @@
+//! Primitive meshes.
+//!
+//! Generators for quads, circles, cubes, spheres and grids.
+//! Counter-clockwise triangles facing +z or outwards.
+
+use std::f32::consts::{PI, TAU};
+
+use super::{Mesh, Vertex};
+use crate::math::{Vec2, Vec3};
+
+impl Mesh {
+    /// Creates a rectangle of the given size centered on the origin in the xy plane.
+    pub fn quad(size: Vec2) -> Self {
+        let (hx, hy) = (size.x * 0.5, size.y * 0.5);
+        let v = |x: f32, y: f32, u: f32, v: f32| Vertex::new(Vec3::new(x, y, 0.0), Vec2::new(u, v));
+        Mesh::new(
+            vec![
+                v(-hx, -hy, 0.0, 1.0),
+                v(hx, -hy, 1.0, 1.0),
+                v(hx, hy, 1.0, 0.0),
+                v(-hx, hy, 0.0, 0.0),
+            ],
+            vec![0, 1, 2, 0, 2, 3],
+        )
+    }
+
+    /// Creates a disc in the xy plane as a fan of `segments` triangles (at least 3).
+    ///
+    /// Texture coordinates map the disc onto the unit square.
+    pub fn circle(radius: f32, segments: u32) -> Self {
+        let segments = segments.max(3);
+        let mut vertices = Vec::with_capacity(segments as usize + 1);
+        vertices.push(Vertex::new(Vec3::ZERO, Vec2::new(0.5, 0.5)));
+        for i in 0..segments {
+            let angle = i as f32 / segments as f32 * TAU;
+            let (sin, cos) = angle.sin_cos();
+            vertices.push(Vertex::new(
+                Vec3::new(cos * radius, sin * radius, 0.0),
+                Vec2::new(0.5 + cos * 0.5, 0.5 - sin * 0.5),
+            ));
+        }
+
+        let mut indices = Vec::with_capacity(segments as usize * 3);
+        for i in 0..segments {
+            indices.extend([0, i + 1, (i + 1) % segments + 1]);
+        }
+        Mesh::new(vertices, indices)
+    }
+
+    /// Creates a cube with edges of length `size` centered on the origin.
+    ///
+    /// Each face has its own four vertices so it maps the whole texture.
+    pub fn cube(size: f32) -> Self {
+        let h = size * 0.5;
+        // Normal, then the face's right and up directions as seen from outside
+        let faces = [
+            (Vec3::X, Vec3::new(0.0, 0.0, -1.0), Vec3::Y),
+            (Vec3::new(-1.0, 0.0, 0.0), Vec3::Z, Vec3::Y),
+            (Vec3::Y, Vec3::X, Vec3::new(0.0, 0.0, -1.0)),
+            (Vec3::new(0.0, -1.0, 0.0), Vec3::X, Vec3::Z),
+            (Vec3::Z, Vec3::X, Vec3::Y),
+            (
+                Vec3::new(0.0, 0.0, -1.0),
+                Vec3::new(-1.0, 0.0, 0.0),
+                Vec3::Y,
+            ),
+        ];
+
+        let mut vertices = Vec::with_capacity(24);
+        let mut indices = Vec::with_capacity(36);
+        for (normal, right, up) in faces {
+            let base = vertices.len() as u32;
+            let corner = |x: f32, y: f32| (normal + right * x + up * y) * h;
+            vertices.extend([
+                Vertex::new(corner(-1.0, -1.0), Vec2::new(0.0, 1.0)),
+                Vertex::new(corner(1.0, -1.0), Vec2::new(1.0, 1.0)),
+                Vertex::new(corner(1.0, 1.0), Vec2::new(1.0, 0.0)),
+                Vertex::new(corner(-1.0, 1.0), Vec2::new(0.0, 0.0)),
+            ]);
+            indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+        Mesh::new(vertices, indices)
+    }
+
+    /// Creates a UV sphere centered on the origin with its poles on the y axis.
+    ///
+    /// `segments` (at least 3) divide it around the axis and `rings` (at least 2)
+    /// from pole to pole. The seam repeats its vertices so texture coordinates wrap.
+    pub fn sphere(radius: f32, segments: u32, rings: u32) -> Self {
+        let (segments, rings) = (segments.max(3), rings.max(2));
+        let columns = segments + 1;
+
+        let mut vertices = Vec::with_capacity((columns * (rings + 1)) as usize);
+        for ring in 0..=rings {
+            let v = ring as f32 / rings as f32;
+            let (sin_polar, cos_polar) = (v * PI).sin_cos();
+            for segment in 0..=segments {
+                let u = segment as f32 / segments as f32;
+                let (sin_azimuth, cos_azimuth) = (u * TAU).sin_cos();
+                let direction =
+                    Vec3::new(sin_polar * sin_azimuth, cos_polar, sin_polar * cos_azimuth);
+                vertices.push(Vertex::new(direction * radius, Vec2::new(u, v)));
+            }
+        }
+
+        let mut indices = Vec::with_capacity((segments * rings * 6) as usize);
+        for ring in 0..rings {
+            for segment in 0..segments {
+                let top = ring * columns + segment;
+                let bottom = top + columns;
+                // The triangles touching a pole collapse to nothing, so skip them
+                if ring != 0 {
+                    indices.extend([top, bottom, top + 1]);
+                }
+                if ring != rings - 1 {
+                    indices.extend([top + 1, bottom, bottom + 1]);
+                }
+            }
+        }
+        Mesh::new(vertices, indices)
+    }
+
+    /// Creates a rectangle of the given size in the xy plane, centered on the
+    /// origin and split into `columns` by `rows` cells (each at least 1).
+    ///
+    /// Texture coordinates span the whole grid.
+    pub fn grid(size: Vec2, columns: u32, rows: u32) -> Self {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+        let stride = columns + 1;
+
+        let mut vertices = Vec::with_capacity((stride * (rows + 1)) as usize);
+        for row in 0..=rows {
+            let v = row as f32 / rows as f32;
+            for column in 0..=columns {
+                let u = column as f32 / columns as f32;
+                let position = Vec3::new((u - 0.5) * size.x, (0.5 - v) * size.y, 0.0);
+                vertices.push(Vertex::new(position, Vec2::new(u, v)));
+            }
+        }
+
+        let mut indices = Vec::with_capacity((columns * rows * 6) as usize);
+        for row in 0..rows {
+            for column in 0..columns {
+                let top_left = row * stride + column;
+                let bottom_left = top_left + stride;
+                indices.extend([bottom_left, bottom_left + 1, top_left + 1]);
+                indices.extend([bottom_left, top_left + 1, top_left]);
+            }
+        }
+        Mesh::new(vertices, indices)
+    }
+}
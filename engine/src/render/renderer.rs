@@ -1,6 +1,538 @@
 //! Renderer implementation.
 //!
 //! Low-level rendering backend.
-//! GPU resource management.
+//! Software rasterization into a framebuffer.
 //! Frame rendering orchestration.
 //! No gameplay logic.
+
+use super::font_data::{FIRST_CHAR, GLYPH_HEIGHT, GLYPH_WIDTH, GLYPHS};
+use super::material::modulate;
+use super::{
+    BlendMode, DebugOverlay, Framebuffer, Material, Materials, Mesh, Meshes, RenderCommand,
+    RenderView, Texture, Textures, Uniform, UvRect, Vertex, Viewport,
+};
+use crate::math::{EPSILON, Mat4, Vec2, Vec3};
+
+/// A vertex after projection: pixel position, depth and what is needed to
+/// interpolate texture coordinates with perspective correction.
+#[derive(Debug, Clone, Copy)]
+struct ScreenVertex {
+    x: f32,
+    y: f32,
+    /// Depth in `0.0..=1.0`, near to far.
+    depth: f32,
+    /// `1 / w` of the clip-space position.
+    inv_w: f32,
+    uv: Vec2,
+}
+
+/// Everything the rasterizer needs to shade a triangle's fragments.
+#[derive(Debug, Clone, Copy)]
+struct Surface<'a> {
+    color: [u8; 4],
+    texture: Option<&'a Texture>,
+    blend: BlendMode,
+    /// Fragments with a lower alpha are discarded.
+    alpha_cutoff: u8,
+}
+
+impl<'a> Surface<'a> {
+    /// An alpha-blended surface, as drawn without a material.
+    fn tinted(color: [u8; 4], texture: Option<&'a Texture>) -> Self {
+        Self {
+            color,
+            texture,
+            blend: BlendMode::Alpha,
+            alpha_cutoff: 0,
+        }
+    }
+
+    /// The surface a material gives a command tinted by `color`.
+    ///
+    /// Reads the [`Material::ALPHA_CUTOFF`] uniform; other uniforms have no
+    /// meaning to this renderer.
+    fn for_material(material: &Material, color: [u8; 4], texture: Option<&'a Texture>) -> Self {
+        let alpha_cutoff = match material.uniform(Material::ALPHA_CUTOFF) {
+            Some(Uniform::Float(cutoff)) => (cutoff.clamp(0.0, 1.0) * 255.0).round() as u8,
+            _ => 0,
+        };
+        Self {
+            color: material.color(color),
+            texture,
+            blend: material.blend,
+            alpha_cutoff,
+        }
+    }
+}
+
+/// A headless renderer that rasterizes on the CPU.
+///
+/// Draws render commands, rectangles and sprites into its own [`Framebuffer`]
+/// with depth testing and blending. Commands are shaded by their [`Material`].
+/// World positions are projected with the view-projection matrix; rectangles
+/// and sprites are given in screen pixels.
+///
+/// Drawing is limited to the current viewport, which also defines where clip
+/// space lands on the framebuffer.
+///
+/// Triangles with a vertex behind the eye (clip `w <= 0`) are skipped rather
+/// than clipped, and fragments outside the depth range are discarded.
+#[derive(Debug, Clone)]
+pub struct SoftwareRenderer {
+    framebuffer: Framebuffer,
+    view_projection: Mat4,
+    clear_color: [u8; 4],
+    /// Current viewport in pixels: left, top, right, bottom.
+    scissor: Scissor,
+}
+
+/// A pixel rectangle: left and top inclusive, right and bottom exclusive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Scissor {
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+}
+
+impl SoftwareRenderer {
+    /// Creates a renderer with an identity view-projection and a black clear color.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            framebuffer: Framebuffer::new(width, height),
+            view_projection: Mat4::IDENTITY,
+            clear_color: [0, 0, 0, 255],
+            scissor: Scissor {
+                left: 0.0,
+                top: 0.0,
+                right: width as f32,
+                bottom: height as f32,
+            },
+        }
+    }
+
+    /// Restricts drawing to a viewport and maps clip space onto it.
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        let (x, y, width, height) =
+            viewport.to_pixels(self.framebuffer.width(), self.framebuffer.height());
+        self.scissor = Scissor {
+            left: x as f32,
+            top: y as f32,
+            right: (x + width) as f32,
+            bottom: (y + height) as f32,
+        };
+    }
+
+    /// Returns the framebuffer holding the last rendered image.
+    pub fn framebuffer(&self) -> &Framebuffer {
+        &self.framebuffer
+    }
+
+    /// Returns the matrix taking world space to clip space.
+    pub fn view_projection(&self) -> Mat4 {
+        self.view_projection
+    }
+
+    /// Sets the matrix taking world space to clip space.
+    pub fn set_view_projection(&mut self, view_projection: Mat4) {
+        self.view_projection = view_projection;
+    }
+
+    /// Returns the color [`Self::render`] clears to.
+    pub fn clear_color(&self) -> [u8; 4] {
+        self.clear_color
+    }
+
+    /// Sets the color [`Self::render`] clears to.
+    pub fn set_clear_color(&mut self, color: [u8; 4]) {
+        self.clear_color = color;
+    }
+
+    /// Clears the framebuffer to the clear color.
+    pub fn clear(&mut self) {
+        self.framebuffer.clear(self.clear_color);
+    }
+
+    /// Clears only the current viewport to `color`, resetting its depth.
+    pub fn clear_viewport(&mut self, color: [u8; 4]) {
+        let Scissor {
+            left,
+            top,
+            right,
+            bottom,
+        } = self.scissor;
+        self.framebuffer.clear_rect(
+            left as usize,
+            top as usize,
+            right as usize,
+            bottom as usize,
+            color,
+        );
+    }
+
+    /// Draws one camera's view into its viewport batch by batch, clearing it first if the view asks to.
+    ///
+    /// Leaves the view's viewport and view-projection set afterwards.
+    pub fn render_view(
+        &mut self,
+        view: &RenderView,
+        meshes: &Meshes,
+        textures: &Textures,
+        materials: &Materials,
+    ) {
+        self.set_viewport(view.viewport);
+        let (width, height) = (self.framebuffer.width(), self.framebuffer.height());
+        self.set_view_projection(view.view_projection(width, height));
+        if let Some(color) = view.clear_color {
+            self.clear_viewport(color);
+        }
+        for batch in &view.batches {
+            let Some(mesh) = meshes.get(batch.mesh) else {
+                continue;
+            };
+            let material = materials.resolve(batch.material);
+            let texture = batch.texture.and_then(|id| textures.get(id));
+            for command in &view.render_commands[batch.instances.clone()] {
+                let model = command.transform.to_matrix();
+                let surface = Surface::for_material(material, command.color, texture);
+                self.draw_mesh_surface(mesh, model, surface, command.uv);
+            }
+        }
+    }
+
+    /// Clears the framebuffer and draws the views in order.
+    ///
+    /// Views are drawn regardless of their target; pass only those meant for this framebuffer.
+    pub fn render_views<'a>(
+        &mut self,
+        views: impl IntoIterator<Item = &'a RenderView>,
+        meshes: &Meshes,
+        textures: &Textures,
+        materials: &Materials,
+    ) -> &Framebuffer {
+        self.set_viewport(Viewport::FULL);
+        self.clear();
+        for view in views {
+            self.render_view(view, meshes, textures, materials);
+        }
+        &self.framebuffer
+    }
+
+    /// Clears the framebuffer and draws the commands in order.
+    ///
+    /// Commands naming a mesh missing from `meshes` are skipped; see [`Self::draw_command`].
+    pub fn render(
+        &mut self,
+        commands: &[RenderCommand],
+        meshes: &Meshes,
+        textures: &Textures,
+        materials: &Materials,
+    ) -> &Framebuffer {
+        self.clear();
+        for command in commands {
+            self.draw_command(command, meshes, textures, materials);
+        }
+        &self.framebuffer
+    }
+
+    /// Draws a single command without clearing.
+    ///
+    /// A texture missing from `textures` draws the color alone, and an unknown
+    /// material draws as the default one.
+    pub fn draw_command(
+        &mut self,
+        command: &RenderCommand,
+        meshes: &Meshes,
+        textures: &Textures,
+        materials: &Materials,
+    ) {
+        if let Some(mesh) = meshes.get(command.mesh) {
+            let material = materials.resolve(command.material);
+            let texture = materials
+                .texture_of(command)
+                .and_then(|id| textures.get(id));
+            let model = command.transform.to_matrix();
+            let surface = Surface::for_material(material, command.color, texture);
+            self.draw_mesh_surface(mesh, model, surface, command.uv);
+        }
+    }
+
+    /// Draws a mesh with the given model matrix, tinting the texture (or white) by `color`.
+    pub fn draw_mesh(
+        &mut self,
+        mesh: &Mesh,
+        model: Mat4,
+        color: [u8; 4],
+        texture: Option<&Texture>,
+    ) {
+        self.draw_mesh_region(mesh, model, color, texture, UvRect::FULL);
+    }
+
+    /// Like [`Self::draw_mesh`], with the mesh's texture coordinates mapped into `region`.
+    pub fn draw_mesh_region(
+        &mut self,
+        mesh: &Mesh,
+        model: Mat4,
+        color: [u8; 4],
+        texture: Option<&Texture>,
+        region: UvRect,
+    ) {
+        self.draw_mesh_surface(mesh, model, Surface::tinted(color, texture), region);
+    }
+
+    /// Projects one triangle through `matrix` and rasterizes it.
+    pub fn draw_triangle(
+        &mut self,
+        matrix: Mat4,
+        vertices: [Vertex; 3],
+        color: [u8; 4],
+        texture: Option<&Texture>,
+    ) {
+        self.draw_triangle_surface(matrix, vertices, Surface::tinted(color, texture));
+    }
+
+    /// Draws the debug overlay on top of the current viewport, ignoring depth.
+    ///
+    /// Lines are one pixel wide; labels use the built-in 5x7 font at one texel
+    /// per pixel. Both are projected with the current view-projection.
+    pub fn draw_debug(&mut self, overlay: &DebugOverlay) {
+        let matrix = self.view_projection;
+        let to_screen = |renderer: &Self, point: Vec3| {
+            renderer
+                .project(matrix, Vertex::new(point, Vec2::ZERO))
+                .map(|vertex| Vec2::new(vertex.x, vertex.y))
+        };
+        for line in &overlay.lines {
+            if let (Some(start), Some(end)) =
+                (to_screen(self, line.start), to_screen(self, line.end))
+            {
+                self.draw_line(start, end, line.color);
+            }
+        }
+        for label in &overlay.labels {
+            if let Some(origin) = to_screen(self, label.position) {
+                self.draw_label(origin, &label.text, label.color);
+            }
+        }
+    }
+
+    /// Fills an axis-aligned rectangle given in screen pixels (y down) at `depth`.
+    pub fn draw_rect(&mut self, min: Vec2, max: Vec2, depth: f32, color: [u8; 4]) {
+        self.draw_screen_quad(min, max, depth, color, None);
+    }
+
+    /// Draws a whole texture stretched over a rectangle in screen pixels, tinted by `tint`.
+    pub fn draw_sprite(
+        &mut self,
+        texture: &Texture,
+        min: Vec2,
+        max: Vec2,
+        depth: f32,
+        tint: [u8; 4],
+    ) {
+        self.draw_screen_quad(min, max, depth, tint, Some(texture));
+    }
+
+    fn draw_screen_quad(
+        &mut self,
+        min: Vec2,
+        max: Vec2,
+        depth: f32,
+        color: [u8; 4],
+        texture: Option<&Texture>,
+    ) {
+        let corner = |x: f32, y: f32, u: f32, v: f32| ScreenVertex {
+            x,
+            y,
+            depth,
+            inv_w: 1.0,
+            uv: Vec2::new(u, v),
+        };
+        let top_left = corner(min.x, min.y, 0.0, 0.0);
+        let top_right = corner(max.x, min.y, 1.0, 0.0);
+        let bottom_right = corner(max.x, max.y, 1.0, 1.0);
+        let bottom_left = corner(min.x, max.y, 0.0, 1.0);
+        let scissor = self.scissor;
+        let surface = Surface::tinted(color, texture);
+        rasterize(
+            &mut self.framebuffer,
+            scissor,
+            [top_left, top_right, bottom_right],
+            surface,
+        );
+        rasterize(
+            &mut self.framebuffer,
+            scissor,
+            [top_left, bottom_right, bottom_left],
+            surface,
+        );
+    }
+
+    fn draw_mesh_surface(&mut self, mesh: &Mesh, model: Mat4, surface: Surface, region: UvRect) {
+        let model_view_projection = self.view_projection * model;
+        for mut triangle in mesh.triangles() {
+            for vertex in &mut triangle {
+                vertex.uv = region.map(vertex.uv);
+            }
+            self.draw_triangle_surface(model_view_projection, triangle, surface);
+        }
+    }
+
+    fn draw_triangle_surface(&mut self, matrix: Mat4, vertices: [Vertex; 3], surface: Surface) {
+        let project = |vertex: Vertex| self.project(matrix, vertex);
+        if let (Some(a), Some(b), Some(c)) = (
+            project(vertices[0]),
+            project(vertices[1]),
+            project(vertices[2]),
+        ) {
+            let scissor = self.scissor;
+            rasterize(&mut self.framebuffer, scissor, [a, b, c], surface);
+        }
+    }
+
+    /// Steps along a line in screen pixels, blending one pixel per step.
+    fn draw_line(&mut self, start: Vec2, end: Vec2, color: [u8; 4]) {
+        let delta = end - start;
+        let steps = delta.x.abs().max(delta.y.abs()).ceil().max(1.0) as usize;
+        for step in 0..=steps {
+            let point = start + delta * (step as f32 / steps as f32);
+            self.plot(point.x, point.y, color);
+        }
+    }
+
+    /// Draws text with the built-in font, with the top-left corner at `origin`.
+    fn draw_label(&mut self, origin: Vec2, text: &str, color: [u8; 4]) {
+        let (left, top) = (origin.x.floor(), origin.y.floor());
+        for (column, ch) in text.chars().enumerate() {
+            let index = (ch as usize)
+                .checked_sub(FIRST_CHAR as usize)
+                .filter(|&index| index < GLYPHS.len())
+                .unwrap_or(b'?' as usize - FIRST_CHAR as usize);
+            let x0 = left + (column * (GLYPH_WIDTH + 1)) as f32;
+            for (y, bits) in GLYPHS[index].iter().enumerate().take(GLYPH_HEIGHT) {
+                for x in 0..GLYPH_WIDTH {
+                    if bits & (1 << (GLYPH_WIDTH - 1 - x)) != 0 {
+                        self.plot(x0 + x as f32, top + y as f32, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Blends a pixel inside the viewport, ignoring depth.
+    fn plot(&mut self, x: f32, y: f32, color: [u8; 4]) {
+        let (x, y) = (x.floor(), y.floor());
+        let scissor = self.scissor;
+        if x >= scissor.left && x < scissor.right && y >= scissor.top && y < scissor.bottom {
+            self.framebuffer.blend_pixel(x as usize, y as usize, color);
+        }
+    }
+
+    /// Takes a vertex to screen space, or `None` if it is behind the eye.
+    fn project(&self, matrix: Mat4, vertex: Vertex) -> Option<ScreenVertex> {
+        let clip = matrix.mul_vec4(vertex.position.extend(1.0));
+        if clip.w <= EPSILON {
+            return None;
+        }
+        let inv_w = 1.0 / clip.w;
+        let ndc = Vec3::new(clip.x, clip.y, clip.z) * inv_w;
+        let Scissor {
+            left,
+            top,
+            right,
+            bottom,
+        } = self.scissor;
+        Some(ScreenVertex {
+            x: left + (ndc.x + 1.0) * 0.5 * (right - left),
+            y: top + (1.0 - ndc.y) * 0.5 * (bottom - top),
+            depth: ndc.z * 0.5 + 0.5,
+            inv_w,
+            uv: vertex.uv,
+        })
+    }
+}
+
+/// Twice the signed area of the triangle `a, b, p`; positive when `p` is on the inner side of `a -> b`.
+fn edge(a: &ScreenVertex, b: &ScreenVertex, x: f32, y: f32) -> f32 {
+    (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x)
+}
+
+/// Top-left fill rule: pixels exactly on a top or left edge belong to the triangle,
+/// so triangles sharing an edge never both cover a pixel.
+fn is_top_left(a: &ScreenVertex, b: &ScreenVertex) -> bool {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    (dy == 0.0 && dx > 0.0) || dy < 0.0
+}
+
+fn rasterize(
+    framebuffer: &mut Framebuffer,
+    scissor: Scissor,
+    vertices: [ScreenVertex; 3],
+    surface: Surface,
+) {
+    let [a, mut b, mut c] = vertices;
+    let mut area = edge(&a, &b, c.x, c.y);
+    if area.abs() <= EPSILON {
+        return;
+    }
+    // Either winding is drawn; normalize so inside means positive
+    if area < 0.0 {
+        std::mem::swap(&mut b, &mut c);
+        area = -area;
+    }
+
+    let min_x = a.x.min(b.x).min(c.x).floor().max(scissor.left);
+    let min_y = a.y.min(b.y).min(c.y).floor().max(scissor.top);
+    let max_x = a.x.max(b.x).max(c.x).ceil().min(scissor.right);
+    let max_y = a.y.max(b.y).max(c.y).ceil().min(scissor.bottom);
+    if min_x >= max_x || min_y >= max_y {
+        return;
+    }
+
+    let edges = [
+        (is_top_left(&b, &c), &b, &c),
+        (is_top_left(&c, &a), &c, &a),
+        (is_top_left(&a, &b), &a, &b),
+    ];
+
+    for py in min_y as usize..max_y as usize {
+        for px in min_x as usize..max_x as usize {
+            let (x, y) = (px as f32 + 0.5, py as f32 + 0.5);
+
+            let mut weights = [0.0; 3];
+            let mut inside = true;
+            for (weight, &(top_left, from, to)) in weights.iter_mut().zip(&edges) {
+                let w = edge(from, to, x, y);
+                if w < 0.0 || (w == 0.0 && !top_left) {
+                    inside = false;
+                    break;
+                }
+                *weight = w / area;
+            }
+            if !inside {
+                continue;
+            }
+
+            let [wa, wb, wc] = weights;
+            let depth = wa * a.depth + wb * b.depth + wc * c.depth;
+            if !(0.0..=1.0).contains(&depth) {
+                continue;
+            }
+
+            let fragment = match surface.texture {
+                Some(texture) => {
+                    let inv_w = wa * a.inv_w + wb * b.inv_w + wc * c.inv_w;
+                    let uv =
+                        (a.uv * (wa * a.inv_w) + b.uv * (wb * b.inv_w) + c.uv * (wc * c.inv_w))
+                            / inv_w;
+                    modulate(texture.sample(uv), surface.color)
+                }
+                None => surface.color,
+            };
+            if fragment[3] < surface.alpha_cutoff {
+                continue;
+            }
+            framebuffer.blend_fragment(px, py, depth, fragment, surface.blend);
+        }
+    }
+}
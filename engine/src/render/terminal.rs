@@ -0,0 +1,215 @@
+//! Terminal renderer.
+//!
+//! Render commands to a character grid.
+//! 24-bit ANSI colors or plain ASCII shading.
+//! Redraws only the cells that changed.
+
+use std::fmt::Write;
+
+use super::{Materials, Meshes, RenderCommand, SoftwareRenderer, Textures};
+use crate::math::{Mat4, Vec2};
+
+/// Characters from dark to bright, used when colors are disabled.
+const ASCII_RAMP: &[u8] = b" .:-=+*#%@";
+
+/// One character cell of the terminal grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalCell {
+    pub ch: char,
+    /// Background color when ANSI colors are enabled; black otherwise, so only
+    /// the character decides whether a plain cell is redrawn.
+    pub color: [u8; 3],
+}
+
+impl Default for TerminalCell {
+    fn default() -> Self {
+        Self {
+            ch: ' ',
+            color: [0; 3],
+        }
+    }
+}
+
+/// A renderer drawing into a terminal with escape sequences.
+///
+/// Each cell shows the color at its center. With colors enabled a cell is a
+/// space with a 24-bit background; without, it is a character picked by brightness.
+/// [`Self::render`] returns only what changed since the previous frame.
+#[derive(Debug, Clone)]
+pub struct TerminalRenderer {
+    raster: SoftwareRenderer,
+    columns: usize,
+    rows: usize,
+    cell_aspect: f32,
+    ansi_colors: bool,
+    cells: Vec<TerminalCell>,
+    /// What the terminal currently shows, or `None` when it must be fully redrawn.
+    shown: Option<Vec<TerminalCell>>,
+}
+
+impl TerminalRenderer {
+    /// Creates a renderer for a grid of `columns` by `rows` with ANSI colors and a cell aspect of 2.0.
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            raster: SoftwareRenderer::new(columns, rows),
+            columns,
+            rows,
+            cell_aspect: 2.0,
+            ansi_colors: true,
+            cells: vec![TerminalCell::default(); columns * rows],
+            shown: None,
+        }
+    }
+
+    /// Returns the number of columns.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the height of a cell divided by its width.
+    pub fn cell_aspect(&self) -> f32 {
+        self.cell_aspect
+    }
+
+    /// Sets the height of a cell divided by its width (most terminal fonts are about 2.0).
+    pub fn set_cell_aspect(&mut self, cell_aspect: f32) {
+        self.cell_aspect = cell_aspect;
+    }
+
+    /// Returns the width of the grid divided by its height, as it appears on screen.
+    pub fn aspect_ratio(&self) -> f32 {
+        self.columns as f32 / (self.rows as f32 * self.cell_aspect)
+    }
+
+    /// Enables 24-bit ANSI colors, or plain ASCII shading when disabled.
+    pub fn set_ansi_colors(&mut self, enabled: bool) {
+        if self.ansi_colors != enabled {
+            self.ansi_colors = enabled;
+            self.invalidate();
+        }
+    }
+
+    /// Sets the matrix taking world space to clip space.
+    pub fn set_view_projection(&mut self, view_projection: Mat4) {
+        self.raster.set_view_projection(view_projection);
+    }
+
+    /// Returns an orthographic view of `half_height` world units above and below
+    /// `center`, widened so world units appear square on screen.
+    pub fn view_2d(&self, center: Vec2, half_height: f32) -> Mat4 {
+        let half_width = half_height * self.aspect_ratio();
+        Mat4::orthographic_rh(
+            center.x - half_width,
+            center.x + half_width,
+            center.y - half_height,
+            center.y + half_height,
+            -1000.0,
+            1000.0,
+        )
+    }
+
+    /// Sets the color the grid is cleared to.
+    pub fn set_clear_color(&mut self, color: [u8; 4]) {
+        self.raster.set_clear_color(color);
+    }
+
+    /// Forgets what the terminal shows, so the next frame is drawn in full.
+    pub fn invalidate(&mut self) {
+        self.shown = None;
+    }
+
+    /// Returns the cell at `(column, row)` from the last render.
+    pub fn cell(&self, column: usize, row: usize) -> Option<TerminalCell> {
+        (column < self.columns && row < self.rows).then(|| self.cells[row * self.columns + column])
+    }
+
+    /// Returns the characters of the last render, one line per row, without colors.
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::with_capacity((self.columns + 1) * self.rows);
+        for row in self.cells.chunks(self.columns.max(1)) {
+            text.extend(row.iter().map(|cell| cell.ch));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Renders the commands and returns the escape sequences that update the terminal.
+    ///
+    /// The first frame (and any after [`Self::invalidate`]) clears the screen and
+    /// draws every cell; later frames only touch cells that changed. Write the
+    /// result to the terminal as-is.
+    pub fn render(
+        &mut self,
+        commands: &[RenderCommand],
+        meshes: &Meshes,
+        textures: &Textures,
+        materials: &Materials,
+    ) -> String {
+        let framebuffer = self.raster.render(commands, meshes, textures, materials);
+        let ansi_colors = self.ansi_colors;
+        for (cell, pixel) in self.cells.iter_mut().zip(framebuffer.pixels()) {
+            *cell = to_cell(*pixel, ansi_colors);
+        }
+
+        let mut out = String::new();
+        let full = self.shown.is_none();
+        if full {
+            out.push_str("\x1b[0m\x1b[2J");
+        }
+
+        let mut cursor = None;
+        let mut current_color = None;
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let index = row * self.columns + column;
+                let cell = self.cells[index];
+                if self
+                    .shown
+                    .as_ref()
+                    .is_some_and(|shown| shown[index] == cell)
+                {
+                    continue;
+                }
+
+                if cursor != Some((column, row)) {
+                    // Positions are 1-based
+                    let _ = write!(out, "\x1b[{};{}H", row + 1, column + 1);
+                }
+                if self.ansi_colors && current_color != Some(cell.color) {
+                    let [r, g, b] = cell.color;
+                    let _ = write!(out, "\x1b[48;2;{r};{g};{b}m");
+                    current_color = Some(cell.color);
+                }
+                out.push(cell.ch);
+                cursor = Some((column + 1, row));
+            }
+        }
+        if current_color.is_some() {
+            out.push_str("\x1b[0m");
+        }
+
+        self.shown = Some(self.cells.clone());
+        out
+    }
+}
+
+/// Converts a pixel to a cell: a colored space, or an uncolored ASCII shade by luminance.
+fn to_cell(pixel: [u8; 4], ansi_colors: bool) -> TerminalCell {
+    if ansi_colors {
+        return TerminalCell {
+            ch: ' ',
+            color: [pixel[0], pixel[1], pixel[2]],
+        };
+    }
+    let luma = (pixel[0] as u32 * 299 + pixel[1] as u32 * 587 + pixel[2] as u32 * 114) / 1000;
+    let index = luma as usize * (ASCII_RAMP.len() - 1) / 255;
+    TerminalCell {
+        ch: ASCII_RAMP[index] as char,
+        color: [0; 3],
+    }
+}
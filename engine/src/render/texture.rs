@@ -0,0 +1,121 @@
+//! Textures.
+//!
+//! CPU-side RGBA images.
+//! Nearest-neighbour sampling.
+
+use crate::math::Vec2;
+
+/// An RGBA image, stored row by row from the top-left corner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Texture {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 4]>,
+}
+
+impl Texture {
+    /// Creates a texture from its pixels, or `None` if the count does not match the size.
+    pub fn new(width: usize, height: usize, pixels: Vec<[u8; 4]>) -> Option<Self> {
+        (pixels.len() == width * height).then_some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Creates a texture filled with a single color.
+    pub fn solid(width: usize, height: usize, color: [u8; 4]) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![color; width * height],
+        }
+    }
+
+    /// Returns the width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the pixel at `(x, y)`, or `None` outside the texture.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<[u8; 4]> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Returns all pixels, row by row.
+    pub fn pixels(&self) -> &[[u8; 4]] {
+        &self.pixels
+    }
+
+    /// Samples the nearest texel, clamping coordinates to the edges.
+    ///
+    /// An empty texture samples as opaque white.
+    pub fn sample(&self, uv: Vec2) -> [u8; 4] {
+        if self.pixels.is_empty() {
+            return [255; 4];
+        }
+        let x = (uv.x * self.width as f32)
+            .floor()
+            .clamp(0.0, (self.width - 1) as f32);
+        let y = (uv.y * self.height as f32)
+            .floor()
+            .clamp(0.0, (self.height - 1) as f32);
+        self.pixels[y as usize * self.width + x as usize]
+    }
+}
+
+/// An identifier for a texture in the [`Textures`] registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TextureId(pub usize);
+
+/// Resource owning every texture the renderers can sample.
+///
+/// [`Self::add`] hands out [`TextureId`]s in insertion order. Textures are never
+/// removed, so an id stays valid for the lifetime of the registry.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Textures {
+    textures: Vec<Texture>,
+}
+
+impl Textures {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores a texture and returns its id.
+    pub fn add(&mut self, texture: Texture) -> TextureId {
+        self.textures.push(texture);
+        TextureId(self.textures.len() - 1)
+    }
+
+    /// Returns the texture with the given id.
+    pub fn get(&self, id: TextureId) -> Option<&Texture> {
+        self.textures.get(id.0)
+    }
+
+    /// Returns the texture with the given id for editing.
+    pub fn get_mut(&mut self, id: TextureId) -> Option<&mut Texture> {
+        self.textures.get_mut(id.0)
+    }
+
+    /// Returns `true` if `id` names a registered texture.
+    pub fn contains(&self, id: TextureId) -> bool {
+        id.0 < self.textures.len()
+    }
+
+    /// Returns the number of registered textures.
+    pub fn len(&self) -> usize {
+        self.textures.len()
+    }
+
+    /// Returns `true` if no texture is registered.
+    pub fn is_empty(&self) -> bool {
+        self.textures.is_empty()
+    }
+}
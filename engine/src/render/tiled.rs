@@ -0,0 +1,695 @@
+//! Tiled map loading.
+//!
+//! Reads orthogonal maps saved by the Tiled editor as TMX or JSON.
+//! Tile layers with CSV or plain array data.
+//! One embedded tileset per map.
+
+use std::collections::BTreeMap;
+
+use super::{TextureId, Tile, TileFlags, Tilemap, TilemapError, Tileset};
+use crate::math::Vec2;
+
+const FLIPPED_HORIZONTALLY: u32 = 0x8000_0000;
+const FLIPPED_VERTICALLY: u32 = 0x4000_0000;
+const FLIPPED_DIAGONALLY: u32 = 0x2000_0000;
+/// Hexagonal maps use this bit for a 120 degree rotation; it is ignored.
+const ROTATED_HEXAGONAL_120: u32 = 0x1000_0000;
+
+/// Deepest nesting of elements, arrays or objects accepted in a document.
+///
+/// Tiled nests only a few levels; the limit keeps parsing and dropping a
+/// hostile document from overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+impl Tilemap {
+    /// Loads a map from Tiled's XML format (`.tmx`), drawing its tileset from `texture`.
+    ///
+    /// Tile layers become [`super::TileLayer`]s on draw layers 0, 1, 2 and so on; other
+    /// layers are ignored. Tiles are one world unit wide and keep their aspect ratio.
+    pub fn parse_tmx(source: &str, texture: TextureId) -> Result<Self, TilemapError> {
+        let map = XmlElement::parse(source)?;
+        if map.name != "map" {
+            return Err(TilemapError::Missing("map".to_string()));
+        }
+        if map.attribute("infinite").is_some_and(|value| value == "1") {
+            return Err(TilemapError::Unsupported("infinite map".to_string()));
+        }
+
+        let mut tilesets = map.children_named("tileset");
+        let tileset = tilesets
+            .next()
+            .ok_or_else(|| TilemapError::Missing("tileset".to_string()))?;
+        if tilesets.next().is_some() {
+            return Err(TilemapError::Unsupported("multiple tilesets".to_string()));
+        }
+        if tileset.attribute("source").is_some() {
+            return Err(TilemapError::Unsupported("external tileset".to_string()));
+        }
+        let image = tileset
+            .children_named("image")
+            .next()
+            .ok_or_else(|| TilemapError::Missing("tileset.image".to_string()))?;
+        let header = TiledHeader {
+            width: map.number("map", "width")?,
+            height: map.number("map", "height")?,
+            tile_width: map.number("map", "tilewidth")?,
+            tile_height: map.number("map", "tileheight")?,
+            first_gid: tileset.number("tileset", "firstgid")?,
+            tileset: Tileset {
+                texture,
+                image_width: image.number("image", "width")?,
+                image_height: image.number("image", "height")?,
+                tile_width: tileset.number("tileset", "tilewidth")?,
+                tile_height: tileset.number("tileset", "tileheight")?,
+                margin: tileset.optional_number("tileset", "margin")?.unwrap_or(0),
+                spacing: tileset.optional_number("tileset", "spacing")?.unwrap_or(0),
+                columns: tileset.number("tileset", "columns")?,
+                tile_count: tileset.number("tileset", "tilecount")?,
+            },
+        };
+        header.validate()?;
+        let mut tilemap = header.tilemap();
+
+        for layer in map.children_named("layer") {
+            let name = layer.attribute("name").unwrap_or_default().to_string();
+            let data = layer
+                .children_named("data")
+                .next()
+                .ok_or_else(|| TilemapError::Missing("layer.data".to_string()))?;
+            if data.attribute("compression").is_some() {
+                return Err(TilemapError::Unsupported(
+                    "compressed layer data".to_string(),
+                ));
+            }
+            let gids = match data.attribute("encoding") {
+                Some("csv") => data
+                    .text
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(|value| {
+                        value
+                            .parse()
+                            .map_err(|_| TilemapError::InvalidValue("layer.data".to_string()))
+                    })
+                    .collect::<Result<Vec<u32>, _>>()?,
+                None => data
+                    .children_named("tile")
+                    .map(|tile| {
+                        tile.optional_number("tile", "gid")
+                            .map(Option::unwrap_or_default)
+                    })
+                    .collect::<Result<Vec<u32>, _>>()?,
+                Some(encoding) => {
+                    return Err(TilemapError::Unsupported(format!("{encoding} encoding")));
+                }
+            };
+            let visible = layer.attribute("visible") != Some("0");
+            let opacity = match layer.attribute("opacity") {
+                Some(value) => value
+                    .parse()
+                    .map_err(|_| TilemapError::InvalidValue("layer.opacity".to_string()))?,
+                None => 1.0,
+            };
+            header.add_layer(&mut tilemap, name, &gids, visible, opacity)?;
+        }
+        Ok(tilemap)
+    }
+
+    /// Loads a map from Tiled's JSON format (`.tmj` or `.json`), drawing its tileset from `texture`.
+    ///
+    /// Reads the same subset as [`Self::parse_tmx`]; layer data must be a plain array.
+    pub fn parse_tiled_json(source: &str, texture: TextureId) -> Result<Self, TilemapError> {
+        let map = JsonParser::parse(source)?;
+        if map.field("infinite").and_then(Json::as_bool) == Some(true) {
+            return Err(TilemapError::Unsupported("infinite map".to_string()));
+        }
+
+        let tilesets = map.array("map", "tilesets")?;
+        let tileset = match tilesets {
+            [tileset] => tileset,
+            [] => return Err(TilemapError::Missing("tileset".to_string())),
+            _ => return Err(TilemapError::Unsupported("multiple tilesets".to_string())),
+        };
+        if tileset.field("source").is_some() {
+            return Err(TilemapError::Unsupported("external tileset".to_string()));
+        }
+        let header = TiledHeader {
+            width: map.number("map", "width")?,
+            height: map.number("map", "height")?,
+            tile_width: map.number("map", "tilewidth")?,
+            tile_height: map.number("map", "tileheight")?,
+            first_gid: tileset.number("tileset", "firstgid")?,
+            tileset: Tileset {
+                texture,
+                image_width: tileset.number("tileset", "imagewidth")?,
+                image_height: tileset.number("tileset", "imageheight")?,
+                tile_width: tileset.number("tileset", "tilewidth")?,
+                tile_height: tileset.number("tileset", "tileheight")?,
+                margin: tileset.optional_number("tileset", "margin")?.unwrap_or(0),
+                spacing: tileset.optional_number("tileset", "spacing")?.unwrap_or(0),
+                columns: tileset.number("tileset", "columns")?,
+                tile_count: tileset.number("tileset", "tilecount")?,
+            },
+        };
+        header.validate()?;
+        let mut tilemap = header.tilemap();
+
+        for layer in map.array("map", "layers")? {
+            if layer.field("type").and_then(Json::as_str) != Some("tilelayer") {
+                continue;
+            }
+            if layer.field("encoding").and_then(Json::as_str) == Some("base64") {
+                return Err(TilemapError::Unsupported("base64 encoding".to_string()));
+            }
+            let name = layer
+                .field("name")
+                .and_then(Json::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let gids = layer
+                .array("layer", "data")?
+                .iter()
+                .map(|value| {
+                    value
+                        .as_u32()
+                        .ok_or_else(|| TilemapError::InvalidValue("layer.data".to_string()))
+                })
+                .collect::<Result<Vec<u32>, _>>()?;
+            let visible = layer.field("visible").and_then(Json::as_bool) != Some(false);
+            let opacity = layer.field("opacity").and_then(Json::as_f64).unwrap_or(1.0) as f32;
+            header.add_layer(&mut tilemap, name, &gids, visible, opacity)?;
+        }
+        Ok(tilemap)
+    }
+}
+
+/// Map-wide values shared by both formats.
+struct TiledHeader {
+    width: u32,
+    height: u32,
+    tile_width: u32,
+    tile_height: u32,
+    first_gid: u32,
+    tileset: Tileset,
+}
+
+impl TiledHeader {
+    /// Checks that the tileset's grid of tiles lies inside its image.
+    ///
+    /// The values come straight from the file, so the extent is computed with
+    /// checked math and anything that overflows is rejected too.
+    fn validate(&self) -> Result<(), TilemapError> {
+        let tileset = &self.tileset;
+        if tileset.tile_count == 0 {
+            return Ok(());
+        }
+        if tileset.columns == 0 {
+            return Err(TilemapError::InvalidValue("tileset.columns".to_string()));
+        }
+        let rows = tileset.tile_count.div_ceil(tileset.columns);
+        // Margin before the first tile, then tiles with spacing between them
+        let extent = |count: u32, size: u32| {
+            let gaps = (count - 1).checked_mul(tileset.spacing)?;
+            tileset
+                .margin
+                .checked_add(count.checked_mul(size)?)?
+                .checked_add(gaps)
+        };
+        if extent(tileset.columns, tileset.tile_width).is_none_or(|x| x > tileset.image_width) {
+            return Err(TilemapError::InvalidValue("tileset.tilewidth".to_string()));
+        }
+        if extent(rows, tileset.tile_height).is_none_or(|y| y > tileset.image_height) {
+            return Err(TilemapError::InvalidValue("tileset.tileheight".to_string()));
+        }
+        Ok(())
+    }
+
+    fn tilemap(&self) -> Tilemap {
+        let aspect = self.tile_height as f32 / self.tile_width.max(1) as f32;
+        Tilemap::new(
+            self.width,
+            self.height,
+            Vec2::new(1.0, aspect),
+            self.tileset,
+        )
+    }
+
+    /// Adds a layer from row-major global tile ids, where 0 is an empty cell.
+    fn add_layer(
+        &self,
+        tilemap: &mut Tilemap,
+        name: String,
+        gids: &[u32],
+        visible: bool,
+        opacity: f32,
+    ) -> Result<(), TilemapError> {
+        let expected = (self.width as usize)
+            .checked_mul(self.height as usize)
+            .ok_or(TilemapError::TooLarge {
+                width: self.width,
+                height: self.height,
+            })?;
+        if gids.len() != expected {
+            return Err(TilemapError::LayerSizeMismatch {
+                layer: name,
+                expected,
+                found: gids.len(),
+            });
+        }
+
+        let index = tilemap.add_layer(name)?;
+        let width = self.width as usize;
+        for (cell, &gid) in gids.iter().enumerate() {
+            let Some(tile) = self.tile(gid)? else {
+                continue;
+            };
+            let (x, y) = ((cell % width) as u32, (cell / width) as u32);
+            tilemap.set(index, x, y, Some(tile))?;
+        }
+        let layer = tilemap.layer_mut(index).expect("layer was just added");
+        layer.visible = visible;
+        layer.layer = index as i32;
+        layer.tint[3] = (opacity.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Ok(())
+    }
+
+    /// Splits a global tile id into the tileset index and flip flags.
+    fn tile(&self, gid: u32) -> Result<Option<Tile>, TilemapError> {
+        let id = gid
+            & !(FLIPPED_HORIZONTALLY
+                | FLIPPED_VERTICALLY
+                | FLIPPED_DIAGONALLY
+                | ROTATED_HEXAGONAL_120);
+        if id == 0 {
+            return Ok(None);
+        }
+        if id < self.first_gid || id - self.first_gid >= self.tileset.tile_count {
+            return Err(TilemapError::InvalidTile { gid });
+        }
+
+        let mut flags = TileFlags::NONE;
+        for (bit, flag) in [
+            (FLIPPED_HORIZONTALLY, TileFlags::FLIP_X),
+            (FLIPPED_VERTICALLY, TileFlags::FLIP_Y),
+            (FLIPPED_DIAGONALLY, TileFlags::FLIP_DIAGONAL),
+        ] {
+            if gid & bit != 0 {
+                flags = flags.with(flag);
+            }
+        }
+        Ok(Some(Tile::new(id - self.first_gid).with_flags(flags)))
+    }
+}
+
+/// An element of a TMX document with its attributes, child elements and text.
+#[derive(Debug, Default)]
+struct XmlElement {
+    name: String,
+    attributes: BTreeMap<String, String>,
+    children: Vec<XmlElement>,
+    text: String,
+}
+
+impl XmlElement {
+    /// Parses a document and returns its root element.
+    ///
+    /// Handles the subset Tiled writes: declarations, comments, attributes in
+    /// single or double quotes and the five predefined entities.
+    fn parse(source: &str) -> Result<Self, TilemapError> {
+        // The bottom of the stack collects the root element
+        let mut stack = vec![XmlElement::default()];
+        let mut rest = source;
+        while !rest.is_empty() {
+            let offset = source.len() - rest.len();
+            let syntax = || TilemapError::Syntax { offset };
+            let Some(open) = rest.find('<') else {
+                stack.last_mut().ok_or_else(syntax)?.text.push_str(rest);
+                break;
+            };
+            stack
+                .last_mut()
+                .ok_or_else(syntax)?
+                .text
+                .push_str(&unescape(&rest[..open]));
+            rest = &rest[open..];
+
+            let skipped = [("<?", "?>"), ("<!--", "-->"), ("<!", ">")]
+                .into_iter()
+                .find(|(start, _)| rest.starts_with(start));
+            if let Some((_, end)) = skipped {
+                let close = rest.find(end).ok_or_else(syntax)?;
+                rest = &rest[close + end.len()..];
+                continue;
+            }
+
+            let close = rest.find('>').ok_or_else(syntax)?;
+            let tag = &rest[1..close];
+            rest = &rest[close + 1..];
+            if let Some(name) = tag.strip_prefix('/') {
+                // Never pop the document itself
+                let element = stack
+                    .pop()
+                    .filter(|element| !stack.is_empty() && element.name == name.trim())
+                    .ok_or_else(syntax)?;
+                stack.last_mut().ok_or_else(syntax)?.children.push(element);
+                continue;
+            }
+
+            let (tag, self_closing) = match tag.strip_suffix('/') {
+                Some(tag) => (tag, true),
+                None => (tag, false),
+            };
+            let element = Self::open_tag(tag).ok_or_else(syntax)?;
+            if self_closing {
+                stack.last_mut().ok_or_else(syntax)?.children.push(element);
+            } else if stack.len() > MAX_DEPTH {
+                return Err(syntax());
+            } else {
+                stack.push(element);
+            }
+        }
+
+        // Exactly one element must remain open: the document, holding one root
+        let syntax = TilemapError::Syntax {
+            offset: source.len(),
+        };
+        let document = match stack.pop() {
+            Some(document) if stack.is_empty() => document,
+            _ => return Err(syntax),
+        };
+        let [root]: [XmlElement; 1] = document.children.try_into().map_err(|_| syntax)?;
+        Ok(root)
+    }
+
+    /// Reads the name and attributes of a start tag, without its angle brackets.
+    fn open_tag(tag: &str) -> Option<Self> {
+        let tag = tag.trim();
+        let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let mut element = XmlElement {
+            name: tag[..name_end].to_string(),
+            ..XmlElement::default()
+        };
+        if element.name.is_empty() {
+            return None;
+        }
+
+        let mut rest = tag[name_end..].trim_start();
+        while !rest.is_empty() {
+            let (key, after) = rest.split_once('=')?;
+            let after = after.trim_start();
+            let quote = after.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+            let end = after[1..].find(quote)? + 1;
+            element
+                .attributes
+                .insert(key.trim().to_string(), unescape(&after[1..end]));
+            rest = after[end + 1..].trim_start();
+        }
+        Some(element)
+    }
+
+    fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    fn number(&self, element: &str, key: &str) -> Result<u32, TilemapError> {
+        self.optional_number(element, key)?
+            .ok_or_else(|| TilemapError::Missing(format!("{element}.{key}")))
+    }
+
+    fn optional_number(&self, element: &str, key: &str) -> Result<Option<u32>, TilemapError> {
+        self.attribute(key)
+            .map(|value| {
+                value
+                    .trim()
+                    .parse()
+                    .map_err(|_| TilemapError::InvalidValue(format!("{element}.{key}")))
+            })
+            .transpose()
+    }
+}
+
+/// Replaces the predefined XML entities.
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// A parsed JSON value.
+#[derive(Debug, Clone, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(BTreeMap<String, Json>),
+}
+
+impl Json {
+    fn field(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.get(key),
+            _ => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            Json::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_u32(&self) -> Option<u32> {
+        self.as_f64()
+            .filter(|value| value.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(value))
+            .map(|value| value as u32)
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn array(&self, object: &str, key: &str) -> Result<&[Json], TilemapError> {
+        match self.field(key) {
+            Some(Json::Array(items)) => Ok(items),
+            Some(_) => Err(TilemapError::InvalidValue(format!("{object}.{key}"))),
+            None => Err(TilemapError::Missing(format!("{object}.{key}"))),
+        }
+    }
+
+    fn number(&self, object: &str, key: &str) -> Result<u32, TilemapError> {
+        self.optional_number(object, key)?
+            .ok_or_else(|| TilemapError::Missing(format!("{object}.{key}")))
+    }
+
+    fn optional_number(&self, object: &str, key: &str) -> Result<Option<u32>, TilemapError> {
+        self.field(key)
+            .map(|value| {
+                value
+                    .as_u32()
+                    .ok_or_else(|| TilemapError::InvalidValue(format!("{object}.{key}")))
+            })
+            .transpose()
+    }
+}
+
+/// A recursive-descent JSON reader over the bytes of a document.
+struct JsonParser<'a> {
+    source: &'a str,
+    position: usize,
+    /// Arrays and objects currently open, see [`MAX_DEPTH`].
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn parse(source: &'a str) -> Result<Json, TilemapError> {
+        let mut parser = JsonParser {
+            source,
+            position: 0,
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position != source.len() {
+            return Err(parser.error());
+        }
+        Ok(value)
+    }
+
+    fn error(&self) -> TilemapError {
+        TilemapError::Syntax {
+            offset: self.position,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), TilemapError> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(self.error());
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Json, TilemapError> {
+        self.skip_whitespace();
+        match self.peek().ok_or_else(|| self.error())? {
+            b'{' | b'[' if self.depth >= MAX_DEPTH => Err(self.error()),
+            b'{' => self.nested(Self::object),
+            b'[' => self.nested(Self::array),
+            b'"' => self.string().map(Json::String),
+            b't' => self.literal("true", Json::Bool(true)),
+            b'f' => self.literal("false", Json::Bool(false)),
+            b'n' => self.literal("null", Json::Null),
+            _ => self.number(),
+        }
+    }
+
+    /// Parses an array or object one level deeper.
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Json, TilemapError>,
+    ) -> Result<Json, TilemapError> {
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, TilemapError> {
+        if !self.source[self.position..].starts_with(word) {
+            return Err(self.error());
+        }
+        self.position += word.len();
+        Ok(value)
+    }
+
+    fn number(&mut self) -> Result<Json, TilemapError> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|byte| byte.is_ascii_digit() || b"+-.eE".contains(&byte))
+        {
+            self.position += 1;
+        }
+        self.source[start..self.position]
+            .parse()
+            .map(Json::Number)
+            .map_err(|_| TilemapError::Syntax { offset: start })
+    }
+
+    fn string(&mut self) -> Result<String, TilemapError> {
+        self.expect(b'"')?;
+        let mut value = String::new();
+        loop {
+            let rest = &self.source[self.position..];
+            let end = rest.find(['"', '\\']).ok_or_else(|| self.error())?;
+            value.push_str(&rest[..end]);
+            self.position += end + 1;
+            if rest.as_bytes()[end] == b'"' {
+                return Ok(value);
+            }
+
+            let escape = self.peek().ok_or_else(|| self.error())?;
+            self.position += 1;
+            let ch = match escape {
+                b'"' => '"',
+                b'\\' => '\\',
+                b'/' => '/',
+                b'b' => '\u{8}',
+                b'f' => '\u{c}',
+                b'n' => '\n',
+                b'r' => '\r',
+                b't' => '\t',
+                b'u' => {
+                    let hex = self
+                        .source
+                        .get(self.position..self.position + 4)
+                        .ok_or_else(|| self.error())?;
+                    let code = u32::from_str_radix(hex, 16).map_err(|_| self.error())?;
+                    self.position += 4;
+                    // Surrogate pairs are not combined; Tiled does not write them
+                    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                }
+                _ => return Err(self.error()),
+            };
+            value.push(ch);
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, TilemapError> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Json::Array(items));
+                }
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, TilemapError> {
+        self.expect(b'{')?;
+        let mut fields = BTreeMap::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(b':')?;
+            fields.insert(key, self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(Json::Object(fields));
+                }
+                _ => return Err(self.error()),
+            }
+        }
+    }
+}
@@ -0,0 +1,447 @@
+//! Tilemaps.
+//!
+//! Layered grids of tiles drawn from one tileset.
+//! Per-tile flips and gameplay flags.
+//! Chunked extraction into batched quads.
+//! Conversion between world and tile coordinates.
+
+use std::f32::consts::FRAC_PI_2;
+
+use super::{Materials, Meshes, RenderCommand, TextureId, TilemapError, Transform, UvRect};
+use crate::math::{Quat, Vec2, Vec3};
+
+/// Side length, in tiles, of the square chunks a map is extracted in.
+const DEFAULT_CHUNK_SIZE: u32 = 16;
+
+/// Per-tile bits: how the tile image is oriented and what it means to gameplay.
+///
+/// The flips match Tiled's: the diagonal flip mirrors the image across its
+/// top-left to bottom-right diagonal and is applied before the other two.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TileFlags(pub u8);
+
+impl TileFlags {
+    /// No flips and no flags.
+    pub const NONE: TileFlags = TileFlags(0);
+    /// The image is mirrored horizontally.
+    pub const FLIP_X: TileFlags = TileFlags(1);
+    /// The image is mirrored vertically.
+    pub const FLIP_Y: TileFlags = TileFlags(1 << 1);
+    /// The image is mirrored across its diagonal.
+    pub const FLIP_DIAGONAL: TileFlags = TileFlags(1 << 2);
+    /// The tile blocks movement.
+    pub const SOLID: TileFlags = TileFlags(1 << 3);
+
+    /// Returns the flags with every bit of `other` added.
+    pub const fn with(self, other: TileFlags) -> Self {
+        TileFlags(self.0 | other.0)
+    }
+
+    /// Returns `true` if every bit of `other` is set.
+    pub const fn contains(self, other: TileFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// One cell of a [`TileLayer`]: an index into the [`Tileset`] and its flags.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub id: u32,
+    pub flags: TileFlags,
+}
+
+impl Tile {
+    /// Creates an unflipped tile without flags.
+    pub const fn new(id: u32) -> Self {
+        Self {
+            id,
+            flags: TileFlags::NONE,
+        }
+    }
+
+    /// Returns the tile with `flags` added.
+    pub const fn with_flags(mut self, flags: TileFlags) -> Self {
+        self.flags = self.flags.with(flags);
+        self
+    }
+}
+
+/// A texture cut into equal tiles, numbered row by row from 0.
+///
+/// Sizes are in pixels; `margin` surrounds the whole grid and `spacing`
+/// separates neighbouring tiles, as in Tiled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tileset {
+    pub texture: TextureId,
+    pub image_width: u32,
+    pub image_height: u32,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub margin: u32,
+    pub spacing: u32,
+    pub columns: u32,
+    pub tile_count: u32,
+}
+
+impl Tileset {
+    /// Creates a tileset filling an image with `columns` by `rows` tiles and no gaps.
+    pub fn grid(
+        texture: TextureId,
+        image_width: u32,
+        image_height: u32,
+        columns: u32,
+        rows: u32,
+    ) -> Self {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+        Self {
+            texture,
+            image_width,
+            image_height,
+            tile_width: image_width / columns,
+            tile_height: image_height / rows,
+            margin: 0,
+            spacing: 0,
+            columns,
+            tile_count: columns * rows,
+        }
+    }
+
+    /// Returns the texture coordinates of tile `id`, or `None` past the last tile
+    /// or if its pixel position does not fit in a `u32`.
+    pub fn uv(&self, id: u32) -> Option<UvRect> {
+        if id >= self.tile_count || self.columns == 0 {
+            return None;
+        }
+        let (column, row) = (id % self.columns, id / self.columns);
+        let offset = |index: u32, size: u32| {
+            let step = size.checked_add(self.spacing)?;
+            self.margin.checked_add(index.checked_mul(step)?)
+        };
+        let (x, y) = (
+            offset(column, self.tile_width)?,
+            offset(row, self.tile_height)?,
+        );
+        let (right, bottom) = (
+            x.checked_add(self.tile_width)?,
+            y.checked_add(self.tile_height)?,
+        );
+        let (width, height) = (
+            self.image_width.max(1) as f32,
+            self.image_height.max(1) as f32,
+        );
+        Some(UvRect::new(
+            Vec2::new(x as f32 / width, y as f32 / height),
+            Vec2::new(right as f32 / width, bottom as f32 / height),
+        ))
+    }
+}
+
+/// One grid of tiles in a [`Tilemap`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileLayer {
+    pub name: String,
+    pub visible: bool,
+    /// Draw layer of the layer's tiles; lower layers are drawn first.
+    pub layer: i32,
+    /// Color multiplied with every tile; its alpha fades the layer.
+    pub tint: [u8; 4],
+    tiles: Vec<Option<Tile>>,
+    /// Number of tiles in each chunk, so empty chunks are skipped cheaply.
+    chunk_counts: Vec<u32>,
+}
+
+/// Tilemap component: Layers of tiles drawn from one [`Tileset`].
+///
+/// Tile (0, 0) is the top-left one: columns grow along +x and rows along -y
+/// from the entity's position, so the map hangs below and to the right of it,
+/// as in Tiled. Extraction draws each visible layer as unit quads scaled to
+/// `tile_size`, chunk by chunk, so the tiles of a layer share one batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tilemap {
+    width: u32,
+    height: u32,
+    tile_size: Vec2,
+    chunk_size: u32,
+    tileset: Tileset,
+    layers: Vec<TileLayer>,
+}
+
+impl Tilemap {
+    /// Creates a map of `width` by `height` tiles, each `tile_size` world units, with no layers.
+    pub fn new(width: u32, height: u32, tile_size: Vec2, tileset: Tileset) -> Self {
+        Self {
+            width,
+            height,
+            tile_size,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            tileset,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Returns the map with chunks of another side length, in tiles (at least 1).
+    ///
+    /// Keeps every tile; only the chunk bookkeeping of the layers is rebuilt.
+    pub fn with_chunk_size(mut self, chunk_size: u32) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        let (columns, rows) = self.chunk_grid();
+        for index in 0..self.layers.len() {
+            // Never more chunks than cells, which fit since the layer exists
+            let mut counts = vec![0; columns as usize * rows as usize];
+            for (cell, tile) in self.layers[index].tiles.iter().enumerate() {
+                if tile.is_some() {
+                    let (x, y) = self.cell_position(cell);
+                    counts[self.chunk_index(x, y)] += 1;
+                }
+            }
+            self.layers[index].chunk_counts = counts;
+        }
+        self
+    }
+
+    /// Returns the width of the map, in tiles.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the map, in tiles.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the size of one tile, in world units.
+    pub fn tile_size(&self) -> Vec2 {
+        self.tile_size
+    }
+
+    /// Sets the size of one tile, in world units.
+    pub fn set_tile_size(&mut self, tile_size: Vec2) {
+        self.tile_size = tile_size;
+    }
+
+    /// Returns the side length of a chunk, in tiles.
+    pub fn chunk_size(&self) -> u32 {
+        self.chunk_size
+    }
+
+    /// Returns the tileset every layer draws from.
+    pub fn tileset(&self) -> &Tileset {
+        &self.tileset
+    }
+
+    /// Appends an empty, visible layer drawn on layer 0 and returns its index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TilemapError::TooLarge`] if the map has more cells than can be allocated.
+    pub fn add_layer(&mut self, name: impl Into<String>) -> Result<usize, TilemapError> {
+        let (width, height) = (self.width, self.height);
+        let too_large = || TilemapError::TooLarge { width, height };
+        let cells = (width as usize)
+            .checked_mul(height as usize)
+            .ok_or_else(too_large)?;
+        let mut tiles = Vec::new();
+        tiles.try_reserve_exact(cells).map_err(|_| too_large())?;
+        tiles.resize(cells, None);
+
+        let (columns, rows) = self.chunk_grid();
+        self.layers.push(TileLayer {
+            name: name.into(),
+            visible: true,
+            layer: 0,
+            tint: [255; 4],
+            tiles,
+            chunk_counts: vec![0; columns as usize * rows as usize],
+        });
+        Ok(self.layers.len() - 1)
+    }
+
+    /// Returns the layers, from the first drawn to the last.
+    pub fn layers(&self) -> &[TileLayer] {
+        &self.layers
+    }
+
+    /// Returns the layer at `index`.
+    pub fn layer(&self, index: usize) -> Option<&TileLayer> {
+        self.layers.get(index)
+    }
+
+    /// Returns the layer at `index` for changing its name, visibility, draw layer or tint.
+    pub fn layer_mut(&mut self, index: usize) -> Option<&mut TileLayer> {
+        self.layers.get_mut(index)
+    }
+
+    /// Returns the index of the first layer called `name`.
+    pub fn layer_index(&self, name: &str) -> Option<usize> {
+        self.layers.iter().position(|layer| layer.name == name)
+    }
+
+    /// Returns the tile at column `x`, row `y` of a layer, or `None` if the cell is empty or outside the map.
+    pub fn get(&self, layer: usize, x: u32, y: u32) -> Option<Tile> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        *self.layers.get(layer)?.tiles.get(self.cell(x, y))?
+    }
+
+    /// Places a tile (or clears the cell with `None`) and returns what was there.
+    pub fn set(
+        &mut self,
+        layer: usize,
+        x: u32,
+        y: u32,
+        tile: Option<Tile>,
+    ) -> Result<Option<Tile>, TilemapError> {
+        if x >= self.width || y >= self.height {
+            return Err(TilemapError::OutOfBounds { x, y });
+        }
+        let chunk = self.chunk_index(x, y);
+        let cell = self.cell(x, y);
+        let layer = self
+            .layers
+            .get_mut(layer)
+            .ok_or(TilemapError::UnknownLayer(layer))?;
+        let previous = std::mem::replace(&mut layer.tiles[cell], tile);
+        match (previous.is_some(), tile.is_some()) {
+            (false, true) => layer.chunk_counts[chunk] += 1,
+            (true, false) => layer.chunk_counts[chunk] -= 1,
+            _ => {}
+        }
+        Ok(previous)
+    }
+
+    /// Returns `true` if any layer has a tile with all of `flags` at the cell.
+    pub fn has_flags(&self, x: u32, y: u32, flags: TileFlags) -> bool {
+        (0..self.layers.len()).any(|layer| {
+            self.get(layer, x, y)
+                .is_some_and(|tile| tile.flags.contains(flags))
+        })
+    }
+
+    /// Returns the world position of the center of tile (`x`, `y`) for a map placed at `transform`.
+    pub fn tile_to_world(&self, transform: &Transform, x: u32, y: u32) -> Vec3 {
+        transform.transform_point(self.tile_center(x, y).extend(0.0))
+    }
+
+    /// Returns the tile under a world position for a map placed at `transform`,
+    /// or `None` outside the map.
+    ///
+    /// The point is projected onto the map's plane along its local z axis.
+    pub fn world_to_tile(&self, transform: &Transform, point: Vec3) -> Option<(u32, u32)> {
+        let local = transform.to_matrix().inverse()?.transform_point3(point);
+        let column = (local.x / self.tile_size.x).floor();
+        let row = (-local.y / self.tile_size.y).floor();
+        let inside =
+            (0.0..self.width as f32).contains(&column) && (0.0..self.height as f32).contains(&row);
+        inside.then_some((column as u32, row as u32))
+    }
+
+    /// Builds one textured quad per tile of every visible layer, for a map placed at `transform`.
+    ///
+    /// Layers come in order and each is walked chunk by chunk, row by row;
+    /// chunks without tiles are skipped. Tiles past the end of the tileset are not drawn.
+    pub fn render_commands(&self, transform: &Transform) -> Vec<RenderCommand> {
+        let mut commands = Vec::new();
+        let (columns, rows) = self.chunk_grid();
+        for layer in self.layers.iter().filter(|layer| layer.visible) {
+            for chunk_y in 0..rows {
+                for chunk_x in 0..columns {
+                    if layer.chunk_counts[chunk_y as usize * columns as usize + chunk_x as usize]
+                        == 0
+                    {
+                        continue;
+                    }
+                    let (left, top) = (chunk_x * self.chunk_size, chunk_y * self.chunk_size);
+                    let right = left.saturating_add(self.chunk_size).min(self.width);
+                    let bottom = top.saturating_add(self.chunk_size).min(self.height);
+                    for y in top..bottom {
+                        for x in left..right {
+                            let Some(tile) = layer.tiles[self.cell(x, y)] else {
+                                continue;
+                            };
+                            if let Some(command) = self.tile_command(transform, layer, tile, x, y) {
+                                commands.push(command);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        commands
+    }
+
+    fn tile_command(
+        &self,
+        transform: &Transform,
+        layer: &TileLayer,
+        tile: Tile,
+        x: u32,
+        y: u32,
+    ) -> Option<RenderCommand> {
+        let uv = self.tileset.uv(tile.id)?;
+        let flags = tile.flags;
+        // A diagonal flip is a vertical mirror followed by a quarter turn clockwise
+        let (rotation, uv) = if flags.contains(TileFlags::FLIP_DIAGONAL) {
+            (
+                Quat::from_rotation_z(-FRAC_PI_2),
+                uv.flipped(
+                    flags.contains(TileFlags::FLIP_Y),
+                    !flags.contains(TileFlags::FLIP_X),
+                ),
+            )
+        } else {
+            (
+                Quat::IDENTITY,
+                uv.flipped(
+                    flags.contains(TileFlags::FLIP_X),
+                    flags.contains(TileFlags::FLIP_Y),
+                ),
+            )
+        };
+        Some(RenderCommand {
+            transform: Transform {
+                translation: self.tile_to_world(transform, x, y),
+                rotation: transform.rotation * rotation,
+                scale: transform.scale * self.tile_size.extend(1.0),
+            },
+            mesh: Meshes::QUAD,
+            color: layer.tint,
+            material: Materials::DEFAULT,
+            texture: Some(self.tileset.texture),
+            uv,
+            layer: layer.layer,
+            sort_key: RenderCommand::sort_key_for(layer.layer, Materials::DEFAULT, Meshes::QUAD),
+        })
+    }
+
+    fn tile_center(&self, x: u32, y: u32) -> Vec2 {
+        Vec2::new(
+            (x as f32 + 0.5) * self.tile_size.x,
+            -(y as f32 + 0.5) * self.tile_size.y,
+        )
+    }
+
+    /// Returns the number of chunk columns and rows.
+    fn chunk_grid(&self) -> (u32, u32) {
+        (
+            self.width.div_ceil(self.chunk_size),
+            self.height.div_ceil(self.chunk_size),
+        )
+    }
+
+    fn chunk_index(&self, x: u32, y: u32) -> usize {
+        let columns = self.width.div_ceil(self.chunk_size) as usize;
+        (y / self.chunk_size) as usize * columns + (x / self.chunk_size) as usize
+    }
+
+    /// Returns the row-major index of a cell; computed in `usize` so large maps do not overflow.
+    fn cell(&self, x: u32, y: u32) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+
+    /// Returns the column and row of a row-major cell index.
+    fn cell_position(&self, cell: usize) -> (u32, u32) {
+        let width = self.width as usize;
+        ((cell % width) as u32, (cell / width) as u32)
+    }
+}
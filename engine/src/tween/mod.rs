@@ -0,0 +1,301 @@
+//! Tweening.
+//!
+//! Time-based interpolation of entity properties.
+//! Delays, looping and ping-pong playback.
+//! Completion events for gameplay code.
+
+use crate::Entity;
+use crate::ecs::components::{Color, Position};
+use crate::math::{Easing, Vec3};
+use crate::render::Transform;
+
+/// The property a [`Tween`] animates, with its start and end values.
+///
+/// The tween writes the matching component on its entity; entities without
+/// that component are left untouched.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TweenTarget {
+    Position { from: Vec3, to: Vec3 },
+    Color { from: Color, to: Color },
+    Transform { from: Transform, to: Transform },
+}
+
+/// How many times a [`Tween`] plays its `from` to `to` cycle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Repeat {
+    /// Play a single cycle.
+    #[default]
+    Once,
+    /// Play the given number of cycles. `Times(0)` finishes immediately after the delay.
+    Times(u32),
+    /// Never finish.
+    Forever,
+}
+
+/// Something that happened to a tween during the last update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TweenEvent {
+    /// A cycle finished and another one started.
+    Looped(Entity),
+    /// The last cycle finished; the tween will not change its target again.
+    Completed(Entity),
+}
+
+/// Resource collecting the [`TweenEvent`]s of the current frame.
+///
+/// Cleared and refilled by [`crate::ecs::system::tween_system`] each frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TweenEvents {
+    events: Vec<TweenEvent>,
+}
+
+impl TweenEvents {
+    /// Appends an event.
+    pub fn push(&mut self, event: TweenEvent) {
+        self.events.push(event);
+    }
+
+    /// Removes all events.
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// Returns this frame's events in the order they happened.
+    pub fn events(&self) -> &[TweenEvent] {
+        &self.events
+    }
+
+    /// Returns `true` if the tween on `entity` completed this frame.
+    pub fn completed(&self, entity: Entity) -> bool {
+        self.events.contains(&TweenEvent::Completed(entity))
+    }
+}
+
+/// What a single [`Tween::advance`] call crossed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TweenStep {
+    /// Number of cycles that ended and were followed by another.
+    pub looped: u32,
+    /// Whether the tween finished during this step.
+    pub completed: bool,
+}
+
+/// Component animating one property of its entity over time.
+///
+/// Built with a constructor for the property, e.g. [`Tween::position`], then
+/// configured with the `with_*` methods.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween {
+    pub target: TweenTarget,
+    /// Length of one cycle, in seconds.
+    pub duration: f32,
+    pub easing: Easing,
+    /// Seconds to wait before the first cycle starts. The target is not written meanwhile.
+    pub delay: f32,
+    pub repeat: Repeat,
+    /// Play every other cycle backwards, from `to` to `from`.
+    pub ping_pong: bool,
+    elapsed: f32,
+    /// Set by the first [`Tween::advance`], so a tween that ends at once still gets a step.
+    stepped: bool,
+}
+
+impl Tween {
+    /// Creates a tween with linear easing, no delay and a single cycle.
+    pub fn new(target: TweenTarget, duration: f32) -> Self {
+        Self {
+            target,
+            duration,
+            easing: Easing::Linear,
+            delay: 0.0,
+            repeat: Repeat::Once,
+            ping_pong: false,
+            elapsed: 0.0,
+            stepped: false,
+        }
+    }
+
+    /// Creates a tween moving the [`Position`] from `from` to `to`.
+    pub fn position(from: Vec3, to: Vec3, duration: f32) -> Self {
+        Self::new(TweenTarget::Position { from, to }, duration)
+    }
+
+    /// Creates a tween fading the [`Color`] from `from` to `to`.
+    pub fn color(from: Color, to: Color, duration: f32) -> Self {
+        Self::new(TweenTarget::Color { from, to }, duration)
+    }
+
+    /// Creates a tween blending the [`Transform`] from `from` to `to`.
+    pub fn transform(from: Transform, to: Transform, duration: f32) -> Self {
+        Self::new(TweenTarget::Transform { from, to }, duration)
+    }
+
+    /// Sets the easing curve.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the start delay, in seconds.
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets how many cycles to play.
+    pub fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Enables or disables ping-pong playback.
+    pub fn with_ping_pong(mut self, ping_pong: bool) -> Self {
+        self.ping_pong = ping_pong;
+        self
+    }
+
+    /// Returns the time since the tween was created, including the delay.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Returns `true` while the start delay has not passed yet.
+    pub fn is_delayed(&self) -> bool {
+        self.elapsed < self.delay
+    }
+
+    /// Returns `true` once the last cycle has ended.
+    ///
+    /// A tween with no cycles to play (zero duration or [`Repeat::Times`]`(0)`)
+    /// finishes on its first [`Self::advance`], not before, so that step still
+    /// reports completion and the end value gets written.
+    pub fn is_finished(&self) -> bool {
+        self.stepped && self.reached_end()
+    }
+
+    /// Restarts the tween from the beginning, including the delay.
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+        self.stepped = false;
+    }
+
+    /// Advances the tween by `dt` seconds and reports the cycles crossed.
+    pub fn advance(&mut self, dt: f32) -> TweenStep {
+        if self.is_finished() {
+            return TweenStep::default();
+        }
+
+        let before = self.cycles_done(self.elapsed);
+        self.elapsed += dt.max(0.0);
+        self.stepped = true;
+        let after = self.cycles_done(self.elapsed);
+
+        let completed = self.is_finished();
+        let ended = after - before;
+        TweenStep {
+            looped: if completed {
+                ended.saturating_sub(1)
+            } else {
+                ended
+            },
+            completed,
+        }
+    }
+
+    /// Returns the eased progress of the current cycle, after ping-pong is applied.
+    ///
+    /// 0.0 is `from` and 1.0 is `to`. During the delay this is 0.0.
+    pub fn progress(&self) -> f32 {
+        if self.is_delayed() {
+            return 0.0;
+        }
+
+        let (cycle, t) = if self.reached_end() {
+            (self.total_cycles().saturating_sub(1), 1.0)
+        } else {
+            let active = self.elapsed - self.delay;
+            let cycle = self.cycles_done(self.elapsed);
+            (cycle, active / self.duration - cycle as f32)
+        };
+
+        let t = if self.ping_pong && cycle % 2 == 1 {
+            1.0 - t
+        } else {
+            t
+        };
+        self.easing.apply(t)
+    }
+
+    /// Number of whole cycles finished at the given elapsed time, capped at the total.
+    fn cycles_done(&self, elapsed: f32) -> u32 {
+        let active = elapsed - self.delay;
+        if active < 0.0 {
+            return 0;
+        }
+        let done = if self.duration <= 0.0 {
+            u32::MAX
+        } else {
+            (active / self.duration).floor().min(u32::MAX as f32) as u32
+        };
+        done.min(self.total_cycles())
+    }
+
+    /// Whether the last cycle has ended, whether or not the tween was stepped yet.
+    fn reached_end(&self) -> bool {
+        !self.is_delayed() && self.cycles_done(self.elapsed) >= self.total_cycles()
+    }
+
+    fn total_cycles(&self) -> u32 {
+        match self.repeat {
+            Repeat::Once => 1,
+            Repeat::Times(n) => n,
+            Repeat::Forever => u32::MAX,
+        }
+    }
+}
+
+/// Interpolates two colors channel by channel, rounding to the nearest value.
+fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let channel = |a: u8, b: u8| {
+        (a as f32 + (b as f32 - a as f32) * t)
+            .round()
+            .clamp(0.0, 255.0) as u8
+    };
+    Color {
+        r: channel(from.r, to.r),
+        g: channel(from.g, to.g),
+        b: channel(from.b, to.b),
+        a: channel(from.a, to.a),
+    }
+}
+
+/// Interpolates translation and scale linearly and rotation spherically.
+fn lerp_transform(from: Transform, to: Transform, t: f32) -> Transform {
+    Transform {
+        translation: from.translation.lerp(to.translation, t),
+        rotation: from.rotation.slerp(to.rotation, t),
+        scale: from.scale.lerp(to.scale, t),
+    }
+}
+
+/// Writes the tween's current value into the matching component of `entity`.
+pub(crate) fn apply(world: &mut crate::World, entity: Entity, tween: &Tween) {
+    let t = tween.progress();
+    match tween.target {
+        TweenTarget::Position { from, to } => {
+            if let Ok(position) = world.get_component_mut::<Position>(entity) {
+                position.0 = from.lerp(to, t);
+            }
+        }
+        TweenTarget::Color { from, to } => {
+            if let Ok(color) = world.get_component_mut::<Color>(entity) {
+                *color = lerp_color(from, to, t);
+            }
+        }
+        TweenTarget::Transform { from, to } => {
+            if let Ok(transform) = world.get_component_mut::<Transform>(entity) {
+                *transform = lerp_transform(from, to, t);
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Asset test module
+//
+// Groups handle, storage and unloading tests.
+
+mod storage;
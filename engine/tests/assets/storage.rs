@@ -0,0 +1,153 @@
+use pyreframe_engine::World;
+use pyreframe_engine::assets::{AssetId, Assets, Handle};
+use pyreframe_engine::ecs::system::asset_system;
+
+#[test]
+fn added_assets_are_reachable_through_their_handle() {
+    let mut assets = Assets::new();
+    assert!(assets.is_empty());
+    let a = assets.add("a".to_string());
+    let b = assets.add("b".to_string());
+    assert_ne!(a, b);
+    assert_eq!(assets.len(), 2);
+    assert_eq!(assets.get(&a).map(String::as_str), Some("a"));
+    assets.get_mut(&b).unwrap().push('!');
+    assert_eq!(assets.get(b.id()).map(String::as_str), Some("b!"));
+    assert_eq!(assets.path(&a), None);
+
+    let ids: Vec<AssetId<String>> = assets.iter().map(|(id, _)| id).collect();
+    assert_eq!(ids, vec![a.id(), b.id()]);
+}
+
+#[test]
+fn clones_count_as_references() {
+    let mut assets = Assets::new();
+    let handle = assets.add(1);
+    assert_eq!(assets.ref_count(&handle), 1);
+    let copy = handle.clone();
+    assert_eq!(copy, handle);
+    assert_eq!(assets.ref_count(&handle), 2);
+    drop(copy);
+    assert_eq!(assets.ref_count(&handle), 1);
+}
+
+#[test]
+fn unreferenced_assets_are_unloaded() {
+    let mut assets = Assets::new();
+    let kept = assets.add(1);
+    let dropped = assets.add(2);
+    let id = dropped.id();
+    let weak = dropped.downgrade();
+    assert!(weak.upgrade().is_some());
+
+    drop(dropped);
+    assert!(weak.upgrade().is_none());
+    // Still loaded until the next unload
+    assert_eq!(assets.get(id), Some(&2));
+    assert_eq!(assets.ref_count(id), 0);
+
+    assert_eq!(assets.unload_unused(), vec![id]);
+    assert_eq!(assets.get(id), None);
+    assert!(!assets.contains(&weak));
+    assert_eq!(assets.get(&kept), Some(&1));
+    assert!(assets.unload_unused().is_empty());
+}
+
+#[test]
+fn reused_slots_do_not_resolve_stale_ids() {
+    let mut assets = Assets::new();
+    let old = assets.add(1).id();
+    assets.unload_unused();
+    let new = assets.add(2);
+    assert_eq!(new.id().index, old.index);
+    assert_ne!(new.id(), old);
+    assert_eq!(assets.get(old), None);
+    assert_eq!(assets.get(&new), Some(&2));
+}
+
+#[test]
+fn loading_a_path_twice_returns_the_same_handle() {
+    let mut assets = Assets::new();
+    let mut loads = 0;
+    let mut loader = |path: &str| -> Result<String, ()> {
+        loads += 1;
+        Ok(path.to_uppercase())
+    };
+    let first = assets.load("hero.png", &mut loader).unwrap();
+    let second = assets.load("hero.png", &mut loader).unwrap();
+    let other = assets.load("enemy.png", &mut loader).unwrap();
+    assert_eq!(loads, 2);
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+    assert_eq!(assets.ref_count(&first), 2);
+    assert_eq!(assets.path(&first), Some("hero.png"));
+    assert_eq!(assets.get(&first).map(String::as_str), Some("HERO.PNG"));
+    assert_eq!(assets.find("enemy.png"), Some(other));
+    assert_eq!(assets.find("missing.png"), None);
+}
+
+#[test]
+fn failed_loads_store_nothing() {
+    let mut assets: Assets<u32> = Assets::new();
+    assert_eq!(assets.load("bad", |_| Err("corrupt")), Err("corrupt"));
+    assert!(assets.is_empty());
+    assert_eq!(assets.find("bad"), None);
+}
+
+#[test]
+fn cached_assets_can_be_revived_before_unloading() {
+    let mut assets = Assets::new();
+    let handle = assets.load("level", |_| Ok::<_, ()>(7)).unwrap();
+    let (id, weak) = (handle.id(), handle.downgrade());
+    drop(handle);
+    assert_eq!(assets.ref_count(id), 0);
+
+    let revived = assets.find("level").unwrap();
+    assert_eq!(revived.id(), id);
+    // Weak handles from before the revival observe the asset again
+    let observed = weak.upgrade().unwrap();
+    assert_eq!(observed, revived);
+    assert_eq!(assets.ref_count(id), 2);
+    assert!(assets.unload_unused().is_empty());
+
+    drop((revived, observed));
+    assert!(weak.upgrade().is_none());
+    assets.unload_unused();
+    assert!(weak.upgrade().is_none());
+    let mut reloaded = false;
+    let fresh = assets
+        .load("level", |_| {
+            reloaded = true;
+            Ok::<_, ()>(8)
+        })
+        .unwrap();
+    assert!(reloaded);
+    assert_ne!(fresh.id(), id);
+}
+
+#[test]
+fn removing_invalidates_remaining_handles() {
+    let mut assets = Assets::new();
+    let handle = assets.load("sound", |_| Ok::<_, ()>(3)).unwrap();
+    assert_eq!(assets.remove(&handle), Some(3));
+    assert_eq!(assets.get(&handle), None);
+    assert_eq!(assets.handle(handle.id()), None);
+    assert_eq!(assets.find("sound"), None);
+    assert_eq!(assets.remove(&handle), None);
+}
+
+#[test]
+fn system_unloads_assets_in_the_world() {
+    let mut world = World::new();
+    asset_system::<u8>(&mut world);
+
+    let mut assets = Assets::new();
+    let kept: Handle<u8> = assets.add(1);
+    let dropped = assets.add(2).id();
+    world.insert_resource(assets);
+
+    asset_system::<u8>(&mut world);
+    let assets = world.get_resource::<Assets<u8>>().unwrap();
+    assert!(assets.contains(&kept));
+    assert!(!assets.contains(dropped));
+}
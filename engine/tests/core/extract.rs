@@ -0,0 +1,345 @@
+use pyreframe_engine::Engine;
+use pyreframe_engine::core::extract::{extract_render_commands, extract_views};
+use pyreframe_engine::ecs::World;
+use pyreframe_engine::ecs::components::{Color, Position, Renderable, Sprite, Text};
+use pyreframe_engine::input::Input;
+use pyreframe_engine::math::{Quat, Vec2, Vec3};
+use pyreframe_engine::render::{
+    BitmapFont, Camera, Fonts, FrameStats, LayerMask, Materials, Mesh, MeshId, Meshes,
+    RenderCommand, TextAlign, TextureId, Transform, UvRect, view_matrix,
+};
+
+#[test]
+fn only_renderable_entities_are_extracted() {
+    let mut world = World::new();
+    let plain = world.spawn();
+    world
+        .insert_component(plain, Position::new(1.0, 2.0, 3.0))
+        .unwrap();
+    let drawn = world.spawn();
+    world
+        .insert_component(drawn, Renderable::new(MeshId(4)))
+        .unwrap();
+
+    let commands = extract_render_commands(&world);
+    assert_eq!(commands.len(), 1);
+    assert_eq!(
+        commands[0],
+        RenderCommand::new(Transform::IDENTITY, MeshId(4))
+    );
+}
+
+#[test]
+fn position_overrides_transform_translation() {
+    let mut world = World::new();
+    let entity = world.spawn();
+    let transform = Transform {
+        translation: Vec3::new(9.0, 9.0, 9.0),
+        rotation: Quat::from_rotation_z(0.5),
+        scale: Vec3::splat(2.0),
+    };
+    world.insert_component(entity, transform).unwrap();
+    world
+        .insert_component(entity, Position::new(1.0, 2.0, 0.0))
+        .unwrap();
+    world
+        .insert_component(
+            entity,
+            Color {
+                r: 10,
+                g: 20,
+                b: 30,
+                a: 40,
+            },
+        )
+        .unwrap();
+    world
+        .insert_component(entity, Renderable::new(MeshId(0)))
+        .unwrap();
+
+    let command = extract_render_commands(&world)[0];
+    assert_eq!(command.transform.translation, Vec3::new(1.0, 2.0, 0.0));
+    assert_eq!(command.transform.rotation, transform.rotation);
+    assert_eq!(command.transform.scale, transform.scale);
+    assert_eq!(command.color, [10, 20, 30, 40]);
+}
+
+#[test]
+fn commands_are_sorted_by_layer_then_mesh() {
+    let mut world = World::new();
+    let spawn = |world: &mut World, renderable: Renderable| {
+        let entity = world.spawn();
+        world.insert_component(entity, renderable).unwrap();
+    };
+    spawn(&mut world, Renderable::new(MeshId(2)).with_layer(1));
+    spawn(&mut world, Renderable::new(MeshId(1)).with_layer(1));
+    spawn(&mut world, Renderable::new(MeshId(7)).with_layer(-3));
+    spawn(&mut world, Renderable::new(MeshId(0)));
+
+    let order: Vec<(i32, usize)> = extract_render_commands(&world)
+        .iter()
+        .map(|command| (command.layer, command.mesh.0))
+        .collect();
+    assert_eq!(order, vec![(-3, 7), (0, 0), (1, 1), (1, 2)]);
+}
+
+#[test]
+fn equal_keys_keep_entity_order() {
+    let mut world = World::new();
+    for i in 0..5 {
+        let entity = world.spawn();
+        world
+            .insert_component(entity, Renderable::new(MeshId(0)))
+            .unwrap();
+        world
+            .insert_component(entity, Position::new(i as f32, 0.0, 0.0))
+            .unwrap();
+    }
+
+    let xs: Vec<f32> = extract_render_commands(&world)
+        .iter()
+        .map(|command| command.transform.translation.x)
+        .collect();
+    assert_eq!(xs, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn tick_returns_extracted_commands() {
+    let mut engine = Engine::new();
+    let entity = engine.world_mut().spawn();
+    engine
+        .world_mut()
+        .insert_component(entity, Renderable::new(MeshId(3)))
+        .unwrap();
+
+    let output = engine.tick(Input::default(), 0.016);
+    assert_eq!(output.render_commands.len(), 1);
+    assert_eq!(output.render_commands[0].mesh, MeshId(3));
+}
+
+#[test]
+fn views_follow_camera_order_and_layers() {
+    let mut world = World::new();
+    for layer in [0, 1, 2] {
+        let entity = world.spawn();
+        world
+            .insert_component(entity, Renderable::new(MeshId(0)).with_layer(layer))
+            .unwrap();
+    }
+    let hud = world.spawn();
+    world
+        .insert_component(
+            hud,
+            Camera::orthographic(10.0)
+                .with_layers(LayerMask::only(2))
+                .with_order(1),
+        )
+        .unwrap();
+    let main = world.spawn();
+    world
+        .insert_component(
+            main,
+            Camera::orthographic(10.0).with_layers(LayerMask::only(0).with(1)),
+        )
+        .unwrap();
+    world
+        .insert_component(main, Position::new(4.0, 5.0, 0.0))
+        .unwrap();
+    let off = world.spawn();
+    let mut inactive = Camera::orthographic(10.0);
+    inactive.active = false;
+    world.insert_component(off, inactive).unwrap();
+
+    let commands = extract_render_commands(&world);
+    let views = extract_views(&world, &commands);
+    assert_eq!(views.len(), 2);
+    assert_eq!(views[0].camera, main);
+    assert_eq!(views[1].camera, hud);
+
+    let layers = |i: usize| -> Vec<i32> {
+        views[i]
+            .view
+            .render_commands
+            .iter()
+            .map(|command| command.layer)
+            .collect()
+    };
+    assert_eq!(layers(0), vec![0, 1]);
+    assert_eq!(layers(1), vec![2]);
+    assert_eq!(
+        views[0].view.view,
+        view_matrix(&Transform::from_translation(Vec3::new(4.0, 5.0, 0.0)))
+    );
+}
+
+#[test]
+fn tick_returns_views_per_camera() {
+    let mut engine = Engine::new();
+    let entity = engine.world_mut().spawn();
+    engine
+        .world_mut()
+        .insert_component(entity, Renderable::new(MeshId(3)))
+        .unwrap();
+
+    let output = engine.tick(Input::default(), 0.016);
+    assert!(output.views.is_empty());
+
+    let camera = engine.world_mut().spawn();
+    engine
+        .world_mut()
+        .insert_component(camera, Camera::orthographic(2.0))
+        .unwrap();
+    let output = engine.tick(Input::default(), 0.016);
+    assert_eq!(output.views.len(), 1);
+    assert_eq!(output.views[0].view.render_commands, output.render_commands);
+}
+
+#[test]
+fn tick_reports_stats_over_views() {
+    let mut engine = Engine::new();
+    let mut meshes = Meshes::new();
+    let quad = meshes.add(Mesh::quad(Vec2::ONE));
+    let world = engine.world_mut();
+    world.insert_resource(meshes);
+    for x in 0..3 {
+        let entity = world.spawn();
+        world
+            .insert_component(entity, Renderable::new(quad))
+            .unwrap();
+        world
+            .insert_component(entity, Position::new(x as f32, 0.0, 0.0))
+            .unwrap();
+    }
+    let hud = world.spawn();
+    world
+        .insert_component(hud, Renderable::new(MeshId(7)).with_layer(1))
+        .unwrap();
+    for order in 0..2 {
+        let camera = world.spawn();
+        world
+            .insert_component(camera, Camera::orthographic(4.0).with_order(order))
+            .unwrap();
+    }
+
+    let output = engine.tick(Input::default(), 0.016);
+    assert_eq!(output.views[0].view.batches.len(), 2);
+    // The unknown mesh on layer 1 is batched but never drawn
+    assert_eq!(
+        output.stats,
+        FrameStats {
+            draw_calls: 2,
+            batches: 2,
+            instances: 6,
+            triangles: 12,
+        }
+    );
+}
+
+#[test]
+fn sprites_become_textured_quads() {
+    let mut world = World::new();
+    let region = UvRect::new(Vec2::new(0.0, 0.5), Vec2::new(0.5, 1.0));
+    let entity = world.spawn();
+    world
+        .insert_component(
+            entity,
+            Sprite::new(TextureId(3), Vec2::new(4.0, 2.0))
+                .with_region(region)
+                .with_anchor(Vec2::ZERO)
+                .with_flip(true, false)
+                .with_tint(Color {
+                    r: 255,
+                    g: 128,
+                    b: 0,
+                    a: 200,
+                })
+                .with_layer(2),
+        )
+        .unwrap();
+    world
+        .insert_component(entity, Position::new(10.0, 5.0, 1.0))
+        .unwrap();
+
+    let commands = extract_render_commands(&world);
+    assert_eq!(commands.len(), 1);
+    let command = commands[0];
+    assert_eq!(command.mesh, Meshes::QUAD);
+    assert_eq!(command.texture, Some(TextureId(3)));
+    assert_eq!(command.uv, region.flipped(true, false));
+    assert_eq!(command.color, [255, 128, 0, 200]);
+    assert_eq!(command.layer, 2);
+    assert_eq!(
+        command.sort_key,
+        RenderCommand::sort_key_for(2, Materials::DEFAULT, Meshes::QUAD)
+    );
+    // Bottom-left anchor: the quad's center sits half the size up and right
+    assert_eq!(command.transform.translation, Vec3::new(12.0, 6.0, 1.0));
+    assert_eq!(command.transform.scale, Vec3::new(4.0, 2.0, 1.0));
+}
+
+#[test]
+fn sprite_anchor_follows_entity_rotation_and_scale() {
+    let mut world = World::new();
+    let entity = world.spawn();
+    world
+        .insert_component(
+            entity,
+            Sprite::new(TextureId(0), Vec2::new(2.0, 2.0)).with_anchor(Vec2::new(0.0, 0.5)),
+        )
+        .unwrap();
+    world
+        .insert_component(
+            entity,
+            Transform {
+                translation: Vec3::ZERO,
+                rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+                scale: Vec3::splat(3.0),
+            },
+        )
+        .unwrap();
+
+    let command = extract_render_commands(&world)[0];
+    // The offset of +1 along x becomes +3 along y after scaling and a quarter turn
+    assert!((command.transform.translation - Vec3::new(0.0, 3.0, 0.0)).length() < 1e-5);
+    assert_eq!(command.transform.scale, Vec3::new(6.0, 6.0, 3.0));
+}
+
+#[test]
+fn text_becomes_one_quad_per_visible_glyph() {
+    let mut world = World::new();
+    let mut fonts = Fonts::new();
+    let font = fonts.add(BitmapFont::builtin(TextureId(2)));
+    let entity = world.spawn();
+    world
+        .insert_component(
+            entity,
+            Text::new("A b", font, 2.0)
+                .with_align(TextAlign::Center)
+                .with_layer(3),
+        )
+        .unwrap();
+    world
+        .insert_component(entity, Position::new(10.0, 0.0, 0.0))
+        .unwrap();
+
+    // Without fonts there is nothing to draw with
+    assert!(extract_render_commands(&world).is_empty());
+
+    world.insert_resource(fonts);
+    let commands = extract_render_commands(&world);
+    assert_eq!(commands.len(), 2);
+    let builtin = BitmapFont::builtin(TextureId(2));
+    let layout = builtin.layout("A b", TextAlign::Center, None);
+    // A line of 8 font pixels is 2 world units high, so a pixel is 0.25
+    let a = commands[0];
+    assert_eq!(a.texture, Some(TextureId(2)));
+    assert_eq!(a.mesh, Meshes::QUAD);
+    assert_eq!(a.uv, layout.glyphs[0].uv);
+    assert_eq!(a.layer, 3);
+    assert_eq!(
+        a.transform.translation,
+        Vec3::new(10.0 - 9.0 * 0.25 + 0.625, -0.875, 0.0)
+    );
+    assert_eq!(a.transform.scale, Vec3::new(1.25, 1.75, 1.0));
+    assert_eq!(commands[1].uv, layout.glyphs[1].uv);
+}
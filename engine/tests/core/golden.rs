@@ -0,0 +1,169 @@
+use std::path::PathBuf;
+
+use pyreframe_engine::Engine;
+use pyreframe_engine::core::golden::{
+    GoldenError, UPDATE_GOLDEN_ENV, check_golden, diff_images, render_ticks,
+};
+use pyreframe_engine::ecs::components::{Color, Position, Renderable, Velocity};
+use pyreframe_engine::ecs::system::movement_system;
+use pyreframe_engine::math::Mat4;
+use pyreframe_engine::render::image::load_image;
+use pyreframe_engine::render::{Framebuffer, Materials, Meshes, SoftwareRenderer, Textures};
+
+/// Two colored quads moving across a 32x32 view of world units -8..8.
+fn scene() -> (Engine, SoftwareRenderer) {
+    let mut engine = Engine::new();
+    engine.schedule_mut().add_system(movement_system);
+
+    let world = engine.world_mut();
+    let spawn = |world: &mut pyreframe_engine::World, x: f32, vx: f32, color: Color| {
+        let entity = world.spawn();
+        world
+            .insert_component(entity, Position::new(x, 0.0, 0.0))
+            .unwrap();
+        world
+            .insert_component(entity, Velocity::new(vx, 0.5, 0.0))
+            .unwrap();
+        world.insert_component(entity, color).unwrap();
+        world
+            .insert_component(entity, Renderable::new(Meshes::QUAD))
+            .unwrap();
+    };
+    spawn(
+        world,
+        -6.0,
+        1.0,
+        Color {
+            r: 230,
+            g: 60,
+            b: 40,
+            a: 255,
+        },
+    );
+    spawn(
+        world,
+        4.0,
+        -0.5,
+        Color {
+            r: 40,
+            g: 120,
+            b: 230,
+            a: 160,
+        },
+    );
+
+    let mut renderer = SoftwareRenderer::new(32, 32);
+    renderer.set_view_projection(Mat4::orthographic_rh(-8.0, 8.0, -8.0, 8.0, -1.0, 1.0));
+    renderer.set_clear_color([20, 20, 24, 255]);
+    (engine, renderer)
+}
+
+fn render_scene(ticks: usize) -> Framebuffer {
+    let (mut engine, mut renderer) = scene();
+    render_ticks(
+        &mut engine,
+        &mut renderer,
+        &Meshes::new(),
+        &Textures::new(),
+        &Materials::new(),
+        ticks,
+        1.0 / 60.0,
+    )
+    .clone()
+}
+
+fn temp_golden(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("pyreframe_golden_{name}.png"));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn scene_matches_stored_golden() {
+    let golden = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden/moving_quads.png");
+    // Run with the variable set to re-record the golden after an intended change
+    let update = std::env::var_os(UPDATE_GOLDEN_ENV).is_some();
+    assert_eq!(check_golden(&render_scene(3), golden, 0, update), Ok(()));
+}
+
+#[test]
+fn missing_golden_is_written_and_reported() {
+    let path = temp_golden("missing");
+    let frame = render_scene(1);
+    assert_eq!(
+        check_golden(&frame, &path, 0, false),
+        Err(GoldenError::Missing {
+            written: path.clone()
+        })
+    );
+    assert_eq!(check_golden(&frame, &path, 0, false), Ok(()));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mismatch_writes_diff_image() {
+    let path = temp_golden("mismatch");
+    let _ = check_golden(&render_scene(1), &path, 0, false);
+
+    // Four more ticks move both quads
+    let result = check_golden(&render_scene(5), &path, 0, false);
+    let Err(GoldenError::Mismatch {
+        mismatched, diff, ..
+    }) = result
+    else {
+        panic!("expected a mismatch, got {result:?}");
+    };
+    assert!(mismatched > 0);
+
+    let diff_image = load_image(&diff).unwrap();
+    let red = diff_image
+        .pixels()
+        .iter()
+        .filter(|&&p| p == [255, 0, 0, 255])
+        .count();
+    assert_eq!(red, mismatched);
+
+    let actual = path.with_file_name("pyreframe_golden_mismatch.actual.png");
+    assert!(actual.exists());
+    for file in [&path, &diff, &actual] {
+        std::fs::remove_file(file).unwrap();
+    }
+}
+
+#[test]
+fn update_overwrites_a_mismatched_golden() {
+    let path = temp_golden("update");
+    let _ = check_golden(&render_scene(1), &path, 0, false);
+
+    let frame = render_scene(5);
+    assert_eq!(check_golden(&frame, &path, 0, true), Ok(()));
+    assert_eq!(check_golden(&frame, &path, 0, false), Ok(()));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn tolerance_allows_small_differences() {
+    let expected = vec![[100, 100, 100, 255]; 4];
+    let mut actual = expected.clone();
+    actual[2] = [103, 98, 100, 255];
+
+    let strict = diff_images(&actual, &expected, 2);
+    assert_eq!(strict.mismatched, 1);
+    assert_eq!(strict.max_difference, 3);
+    assert_eq!(strict.pixels[2], [255, 0, 0, 255]);
+    assert_eq!(diff_images(&actual, &expected, 3).mismatched, 0);
+}
+
+#[test]
+fn size_mismatch_is_reported() {
+    let path = temp_golden("size");
+    let _ = check_golden(&Framebuffer::new(4, 4), &path, 0, false);
+    assert_eq!(
+        check_golden(&Framebuffer::new(4, 5), &path, 0, false),
+        Err(GoldenError::SizeMismatch {
+            expected: (4, 4),
+            actual: (4, 5)
+        })
+    );
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,7 @@
+//! Core test module
+//
+// Groups engine-core tests by subsystem.
+
+mod extract;
+mod golden;
+mod replay;
@@ -0,0 +1,246 @@
+use pyreframe_engine::Engine;
+use pyreframe_engine::World;
+use pyreframe_engine::core::replay::{
+    InputRecording, RecordedFrame, ReplayError, default_state_hash,
+};
+use pyreframe_engine::ecs::components::{Position, Velocity};
+use pyreframe_engine::ecs::system::{movement_system, time_system};
+use pyreframe_engine::input::{
+    GamepadAxis, GamepadButton, GamepadEvent, GamepadId, Input, Key, KeyboardState, TextEvent,
+};
+use pyreframe_engine::time::Time;
+
+/// Steers every moving entity from the keyboard, scaled by the frame delta.
+fn steering_system(world: &mut World) {
+    let input = world.get_resource::<Input>().unwrap().clone();
+    let dt = world.get_resource::<Time>().unwrap().delta;
+    let dx = input.keyboard.is_pressed(Key::D) as i32 - input.keyboard.is_pressed(Key::A) as i32;
+
+    for entity in world.entities_with::<(Velocity,)>() {
+        let vel = world.get_component_mut::<Velocity>(entity).unwrap();
+        vel.x = dx as f32 * dt * 10.0;
+        vel.y = input.mouse.wheel.y * dt;
+    }
+}
+
+/// Nudges positions on frame 3 only, to simulate a nondeterministic bug.
+fn glitch_system(world: &mut World) {
+    if world.get_resource::<Time>().unwrap().frame != 3 {
+        return;
+    }
+    for entity in world.entities_with::<(Position,)>() {
+        world.get_component_mut::<Position>(entity).unwrap().x += 0.001;
+    }
+}
+
+fn setup(engine: &mut Engine) {
+    let world = engine.world_mut();
+    world.insert_resource(Time::default());
+    let player = world.spawn();
+    world
+        .insert_component(player, Position::new(0.0, 0.0, 0.0))
+        .unwrap();
+    world
+        .insert_component(player, Velocity::new(0.0, 0.0, 0.0))
+        .unwrap();
+
+    let schedule = engine.schedule_mut();
+    schedule.add_system(time_system);
+    schedule.add_system(steering_system);
+    schedule.add_system(movement_system);
+}
+
+fn frame_input(frame: usize) -> Input {
+    let mut input = Input {
+        keyboard: KeyboardState::new(if frame.is_multiple_of(3) {
+            vec![Key::D]
+        } else {
+            vec![Key::A]
+        }),
+        ..Input::default()
+    };
+    input.mouse.move_to(frame as f32 * 3.0, 7.5);
+    input.mouse.scroll(0.0, frame as f32 * 0.25);
+    input.mouse.buttons.left = frame.is_multiple_of(2);
+    input
+        .text
+        .push(TextEvent::Char(char::from(b'a' + frame as u8)));
+    if frame == 2 {
+        input.text.push(TextEvent::CompositionUpdate {
+            text: "ね".to_string(),
+            cursor: 3,
+        });
+        input
+            .text
+            .push(TextEvent::CompositionCommit("猫".to_string()));
+    }
+    if frame == 1 {
+        input
+            .gamepads
+            .handle_event(GamepadEvent::Connected(GamepadId(4)));
+        input.gamepads.handle_event(GamepadEvent::ButtonPressed(
+            GamepadId(4),
+            GamepadButton::Start,
+        ));
+        input.gamepads.handle_event(GamepadEvent::AxisMoved(
+            GamepadId(4),
+            GamepadAxis::LeftTrigger,
+            0.5,
+        ));
+    }
+    input
+}
+
+fn record(frames: usize) -> (InputRecording, u64) {
+    let mut engine = Engine::new();
+    setup(&mut engine);
+    engine.start_recording(Some(default_state_hash));
+    for frame in 0..frames {
+        engine.tick(frame_input(frame), 0.016 + frame as f32 * 0.001);
+    }
+    let recording = engine.stop_recording().unwrap();
+    (recording, default_state_hash(engine.world()))
+}
+
+#[test]
+fn recording_captures_every_tick() {
+    let (recording, _) = record(5);
+    assert_eq!(recording.frames.len(), 5);
+    assert_eq!(recording.frames[2].input, frame_input(2));
+    assert_eq!(recording.frames[2].dt, 0.016 + 2.0 * 0.001);
+    assert!(recording.frames.iter().all(|f| f.hash.is_some()));
+}
+
+#[test]
+fn recording_round_trips_through_bytes_and_files() {
+    let (recording, _) = record(4);
+    let decoded = InputRecording::decode(&recording.encode()).unwrap();
+    assert_eq!(decoded, recording);
+
+    let path = std::env::temp_dir().join("pyreframe_replay_round_trip.pfri");
+    recording.save(&path).unwrap();
+    let loaded = InputRecording::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, recording);
+}
+
+#[test]
+fn busy_frames_round_trip_past_255_events() {
+    let mut input = Input::default();
+    for i in 0..300u32 {
+        input.text.push(TextEvent::Char(
+            char::from_u32('a' as u32 + i % 26).unwrap(),
+        ));
+        input.gamepads.handle_event(GamepadEvent::AxisMoved(
+            GamepadId(0),
+            GamepadAxis::LeftStickX,
+            i as f32 / 300.0,
+        ));
+    }
+    let recording = InputRecording {
+        frames: vec![RecordedFrame {
+            input,
+            dt: 0.016,
+            hash: None,
+        }],
+    };
+    let decoded = InputRecording::decode(&recording.encode()).unwrap();
+    assert_eq!(decoded.frames[0].input.text.events().len(), 300);
+    assert_eq!(decoded, recording);
+}
+
+#[test]
+fn gamepad_ids_past_u32_round_trip() {
+    let id = GamepadId(u32::MAX as usize + 7);
+    let mut input = Input::default();
+    input.gamepads.handle_event(GamepadEvent::Connected(id));
+    input
+        .gamepads
+        .handle_event(GamepadEvent::ButtonPressed(id, GamepadButton::South));
+    let recording = InputRecording {
+        frames: vec![RecordedFrame {
+            input,
+            dt: 0.016,
+            hash: None,
+        }],
+    };
+    let decoded = InputRecording::decode(&recording.encode()).unwrap();
+    assert_eq!(decoded.frames[0].input.gamepads.connected(), vec![id]);
+    assert_eq!(decoded, recording);
+}
+
+#[test]
+fn replay_reproduces_the_same_world_state() {
+    let (recording, final_hash) = record(8);
+
+    let mut engine = Engine::new();
+    setup(&mut engine);
+    engine.replay(&recording);
+    assert_eq!(default_state_hash(engine.world()), final_hash);
+
+    let mut verified = Engine::new();
+    setup(&mut verified);
+    assert_eq!(
+        verified.verify_replay(&recording, default_state_hash),
+        Ok(())
+    );
+}
+
+#[test]
+fn verification_reports_first_divergent_frame() {
+    let (recording, _) = record(8);
+
+    let mut engine = Engine::new();
+    setup(&mut engine);
+    engine.schedule_mut().add_system(glitch_system);
+
+    match engine.verify_replay(&recording, default_state_hash) {
+        Err(ReplayError::Diverged {
+            frame,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(frame, 2);
+            assert_ne!(expected, actual);
+        }
+        other => panic!("expected divergence, got {other:?}"),
+    }
+}
+
+#[test]
+fn verification_requires_recorded_hashes() {
+    let mut engine = Engine::new();
+    setup(&mut engine);
+    engine.start_recording(None);
+    engine.tick(frame_input(0), 0.016);
+    let recording = engine.stop_recording().unwrap();
+
+    let mut replayed = Engine::new();
+    setup(&mut replayed);
+    assert_eq!(
+        replayed.verify_replay(&recording, default_state_hash),
+        Err(ReplayError::MissingHash { frame: 0 })
+    );
+}
+
+#[test]
+fn decoding_rejects_malformed_data() {
+    let (recording, _) = record(2);
+    let bytes = recording.encode();
+
+    assert_eq!(
+        InputRecording::decode(b"nope"),
+        Err(ReplayError::InvalidFormat)
+    );
+    assert_eq!(
+        InputRecording::decode(&bytes[..bytes.len() - 3]),
+        Err(ReplayError::Truncated)
+    );
+
+    let mut wrong_version = bytes.clone();
+    wrong_version[4] = 99;
+    assert_eq!(
+        InputRecording::decode(&wrong_version),
+        Err(ReplayError::UnsupportedVersion(99))
+    );
+}
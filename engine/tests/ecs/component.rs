@@ -7,26 +7,12 @@ fn component_can_be_inserted_read_and_mutated() {
     let entity = world.spawn();
 
     world
-        .insert_component(
-            entity,
-            Position {
-                x: 10.0,
-                y: 20.0,
-                z: 0.0,
-            },
-        )
+        .insert_component(entity, Position::new(10.0, 20.0, 0.0))
         .unwrap();
 
     {
         let pos = world.get_component::<Position>(entity).unwrap();
-        assert_eq!(
-            pos,
-            &Position {
-                x: 10.0,
-                y: 20.0,
-                z: 0.0
-            }
-        );
+        assert_eq!(pos, &Position::new(10.0, 20.0, 0.0));
     }
 
     {
@@ -35,14 +21,7 @@ fn component_can_be_inserted_read_and_mutated() {
     }
 
     let pos = world.get_component::<Position>(entity).unwrap();
-    assert_eq!(
-        pos,
-        &Position {
-            x: 15.0,
-            y: 20.0,
-            z: 0.0
-        }
-    );
+    assert_eq!(pos, &Position::new(15.0, 20.0, 0.0));
 }
 
 #[test]
@@ -51,36 +30,15 @@ fn entities_with_filters_by_component_presence() {
 
     let e1 = world.spawn();
     world
-        .insert_component(
-            e1,
-            Position {
-                x: 0.0,
-                y: 0.0,
-                z: 0.0,
-            },
-        )
+        .insert_component(e1, Position::new(0.0, 0.0, 0.0))
         .unwrap();
 
     let e2 = world.spawn();
     world
-        .insert_component(
-            e2,
-            Position {
-                x: 0.0,
-                y: 0.0,
-                z: 0.0,
-            },
-        )
+        .insert_component(e2, Position::new(0.0, 0.0, 0.0))
         .unwrap();
     world
-        .insert_component(
-            e2,
-            Velocity {
-                dx: 1.0,
-                dy: 1.0,
-                dz: 0.0,
-            },
-        )
+        .insert_component(e2, Velocity::new(1.0, 1.0, 0.0))
         .unwrap();
 
     let result = world.entities_with::<(Position, Velocity)>();
@@ -93,14 +51,7 @@ fn component_can_be_removed() {
     let entity = world.spawn();
 
     world
-        .insert_component(
-            entity,
-            Position {
-                x: 5.0,
-                y: 10.0,
-                z: 15.0,
-            },
-        )
+        .insert_component(entity, Position::new(5.0, 10.0, 15.0))
         .unwrap();
     assert!(world.has_component::<Position>(entity).unwrap());
     assert!(world.get_component::<Position>(entity).is_ok());
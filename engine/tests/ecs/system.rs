@@ -7,27 +7,27 @@ use pyreframe_engine::time::{FrameDelta, Time};
 fn movement_system_moves_entities_with_position_and_velocity() {
     let mut world = World::new();
     let entity = world.spawn();
-    world.insert_component(entity, Position { x: 1.0, y: 1.0, z: 0.0 }).unwrap();
-    world.insert_component(entity, Velocity { dx: 1.0, dy: 0.5, dz: 0.0 }).unwrap();
+    world.insert_component(entity, Position::new(1.0, 1.0, 0.0)).unwrap();
+    world.insert_component(entity, Velocity::new(1.0, 0.5, 0.0)).unwrap();
     movement_system(&mut world);
     let pos = world.get_component::<Position>(entity).unwrap();
-    assert_eq!(pos, &Position { x: 2.0, y: 1.5, z: 0.0 });
+    assert_eq!(pos, &Position::new(2.0, 1.5, 0.0));
 }
 
 #[test]
 fn initialize_velocity_system() {
     let mut world = World::new();
     let entity = world.spawn();
-    world.insert_component(entity, Position { x: 1.0, y: 1.0, z: 0.0 }).unwrap();
+    world.insert_component(entity, Position::new(1.0, 1.0, 0.0)).unwrap();
     assert_eq!(world.has_component::<Velocity>(entity), Ok(false));
     for e in world.entities_with::<(Position,)>() {
         if world.has_component::<Velocity>(e) == Ok(false) {
-            world.insert_component(e, Velocity { dx: 2.0, dy: 1.0, dz: 0.0 }).unwrap();
+            world.insert_component(e, Velocity::new(2.0, 1.0, 0.0)).unwrap();
         }
     }
     assert!(world.has_component::<Velocity>(entity).unwrap());
     let vel = world.get_component::<Velocity>(entity).unwrap();
-    assert_eq!(vel, &Velocity { dx: 2.0, dy: 1.0, dz: 0.0 });
+    assert_eq!(vel, &Velocity::new(2.0, 1.0, 0.0));
 }
 
 #[test]
@@ -36,24 +36,10 @@ fn despawning_entity_removes_it_and_components() {
     let entity = world.spawn();
 
     world
-        .insert_component(
-            entity,
-            Position {
-                x: 10.0,
-                y: 20.0,
-                z: 0.0,
-            },
-        )
+        .insert_component(entity, Position::new(10.0, 20.0, 0.0))
         .unwrap();
     world
-        .insert_component(
-            entity,
-            Velocity {
-                dx: 1.0,
-                dy: 2.0,
-                dz: 0.0,
-            },
-        )
+        .insert_component(entity, Velocity::new(1.0, 2.0, 0.0))
         .unwrap();
 
     assert!(world.get_component::<Position>(entity).is_ok());
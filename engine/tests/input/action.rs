@@ -0,0 +1,191 @@
+use pyreframe_engine::ecs::World;
+use pyreframe_engine::ecs::system::action_system;
+use pyreframe_engine::input::{
+    ActionBindings, ActionMap, ActionMapError, AnalogSource, AxisBinding, ButtonBinding, Input,
+    Key, KeyboardState, Modifier, MouseButton,
+};
+
+fn keys(pressed: &[Key]) -> Input {
+    Input {
+        keyboard: KeyboardState::new(pressed.to_vec()),
+        ..Input::default()
+    }
+}
+
+#[test]
+fn button_action_tracks_press_and_release_edges() {
+    let mut actions = ActionMap::new();
+    actions
+        .bind_button("jump", ButtonBinding::Key(Key::Space))
+        .unwrap();
+    actions
+        .bind_button("jump", ButtonBinding::Mouse(MouseButton::Left))
+        .unwrap();
+
+    actions.update(&keys(&[Key::Space]));
+    assert!(actions.pressed("jump"));
+    assert!(actions.just_pressed("jump"));
+
+    let mut mouse_only = Input::default();
+    mouse_only.mouse.buttons.left = true;
+    actions.update(&mouse_only);
+    assert!(actions.pressed("jump"));
+    assert!(!actions.just_pressed("jump"));
+
+    actions.update(&Input::default());
+    assert!(!actions.pressed("jump"));
+    assert!(actions.just_released("jump"));
+}
+
+#[test]
+fn axis_action_combines_key_pairs_and_clamps() {
+    let mut actions = ActionMap::new();
+    let pair = |negative, positive| AxisBinding::Buttons {
+        negative: ButtonBinding::Key(negative),
+        positive: ButtonBinding::Key(positive),
+    };
+    actions.bind_axis("move_x", pair(Key::A, Key::D)).unwrap();
+    actions
+        .bind_axis("move_x", pair(Key::Left, Key::Right))
+        .unwrap();
+
+    actions.update(&keys(&[Key::A]));
+    assert_eq!(actions.value("move_x"), -1.0);
+
+    actions.update(&keys(&[Key::D, Key::Right]));
+    assert_eq!(actions.value("move_x"), 1.0);
+
+    actions.update(&keys(&[Key::A, Key::D]));
+    assert_eq!(actions.value("move_x"), 0.0);
+}
+
+#[test]
+fn analog_deadzone_zeroes_small_values_and_rescales_large_ones() {
+    let mut actions = ActionMap::new();
+    actions
+        .bind_axis("zoom", AxisBinding::Analog(AnalogSource::WheelY))
+        .unwrap();
+    actions.set_deadzone("zoom", 0.5).unwrap();
+
+    let mut input = Input::default();
+    input.mouse.wheel.y = 0.25;
+    actions.update(&input);
+    assert_eq!(actions.value("zoom"), 0.0);
+
+    input.mouse.wheel.y = -0.75;
+    actions.update(&input);
+    assert_eq!(actions.value("zoom"), -0.5);
+}
+
+#[test]
+fn active_chord_suppresses_plain_key_binding() {
+    let mut actions = ActionMap::new();
+    actions
+        .bind_button("move_back", ButtonBinding::Key(Key::S))
+        .unwrap();
+    actions
+        .bind_button(
+            "save",
+            ButtonBinding::Chord {
+                modifiers: vec![Modifier::Ctrl],
+                key: Key::S,
+            },
+        )
+        .unwrap();
+
+    actions.update(&keys(&[Key::RightControl, Key::S]));
+    assert!(actions.pressed("save"));
+    assert!(!actions.pressed("move_back"));
+
+    actions.update(&keys(&[Key::S]));
+    assert!(!actions.pressed("save"));
+    assert!(actions.pressed("move_back"));
+}
+
+#[test]
+fn binding_kind_mismatch_is_rejected() {
+    let mut actions = ActionMap::new();
+    actions
+        .bind_button("jump", ButtonBinding::Key(Key::Space))
+        .unwrap();
+    let result = actions.bind_axis("jump", AxisBinding::Analog(AnalogSource::WheelY));
+    assert_eq!(result, Err(ActionMapError::KindMismatch));
+    assert_eq!(
+        actions.set_deadzone("missing", 0.1),
+        Err(ActionMapError::UnknownAction)
+    );
+}
+
+#[test]
+fn config_round_trips_through_text() {
+    let text = "\
+# player bindings
+button jump = Space, MouseLeft, WheelUp
+button save = Ctrl+Shift+S
+axis move_x deadzone=0.2 = A/D, Left/Right, WheelY
+";
+    let actions = ActionMap::from_config(text).unwrap();
+    assert_eq!(actions.action_names(), vec!["jump", "move_x", "save"]);
+    assert_eq!(actions.deadzone("move_x"), Some(0.2));
+    assert_eq!(
+        actions.bindings("save"),
+        Some(&ActionBindings::Button(vec![ButtonBinding::Chord {
+            modifiers: vec![Modifier::Ctrl, Modifier::Shift],
+            key: Key::S,
+        }]))
+    );
+
+    let reparsed = ActionMap::from_config(&actions.to_config()).unwrap();
+    assert_eq!(reparsed, actions);
+}
+
+#[test]
+fn config_errors_report_line_numbers() {
+    assert_eq!(
+        ActionMap::from_config("button jump = Space\nbutton fly = Hyperdrive\n"),
+        Err(ActionMapError::UnknownInput {
+            line: 2,
+            token: "Hyperdrive".to_string(),
+        })
+    );
+    assert_eq!(
+        ActionMap::from_config("\ntrigger jump = Space"),
+        Err(ActionMapError::InvalidLine { line: 2 })
+    );
+}
+
+#[test]
+fn rebinding_survives_save_and_load() {
+    let mut actions = ActionMap::from_config("button jump = Space").unwrap();
+    actions.set_bindings(
+        "jump",
+        ActionBindings::Button(vec![ButtonBinding::Key(Key::J)]),
+    );
+
+    let path = std::env::temp_dir().join("pyreframe_action_map_rebind.cfg");
+    actions.save(&path).unwrap();
+    let mut loaded = ActionMap::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    loaded.update(&keys(&[Key::J]));
+    assert!(loaded.pressed("jump"));
+    assert_eq!(
+        ActionMap::load(&path),
+        Err(ActionMapError::Io(std::io::ErrorKind::NotFound))
+    );
+}
+
+#[test]
+fn action_system_updates_map_from_input_resource() {
+    let mut world = World::new();
+    let mut actions = ActionMap::new();
+    actions
+        .bind_button("jump", ButtonBinding::Key(Key::Space))
+        .unwrap();
+    world.insert_resource(actions);
+    world.insert_resource(keys(&[Key::Space]));
+
+    action_system(&mut world);
+
+    assert!(world.get_resource::<ActionMap>().unwrap().pressed("jump"));
+}
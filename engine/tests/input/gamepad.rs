@@ -0,0 +1,118 @@
+use pyreframe_engine::input::{
+    ActionMap, AnalogSource, AxisBinding, ButtonBinding, GamepadAxis, GamepadButton, GamepadEvent,
+    GamepadId, GamepadState, Input,
+};
+use pyreframe_engine::{Engine, ecs::World};
+
+#[test]
+fn events_update_buttons_and_axes_per_gamepad() {
+    let mut pads = GamepadState::new();
+    let first = GamepadId(0);
+    let second = GamepadId(3);
+
+    pads.handle_event(GamepadEvent::Connected(first));
+    pads.handle_event(GamepadEvent::Connected(second));
+    pads.handle_event(GamepadEvent::ButtonPressed(first, GamepadButton::South));
+    pads.handle_event(GamepadEvent::AxisMoved(
+        second,
+        GamepadAxis::LeftStickX,
+        -0.5,
+    ));
+
+    assert_eq!(pads.connected(), vec![first, second]);
+    assert!(pads.get(first).unwrap().is_pressed(GamepadButton::South));
+    assert!(!pads.get(second).unwrap().is_pressed(GamepadButton::South));
+    assert_eq!(
+        pads.get(second).unwrap().axis(GamepadAxis::LeftStickX),
+        -0.5
+    );
+
+    pads.handle_event(GamepadEvent::ButtonReleased(first, GamepadButton::South));
+    assert!(!pads.any_pressed(GamepadButton::South));
+}
+
+#[test]
+fn axis_values_are_clamped_to_their_range() {
+    let mut pads = GamepadState::new();
+    let id = GamepadId(0);
+    pads.handle_event(GamepadEvent::AxisMoved(id, GamepadAxis::RightStickY, 4.0));
+    pads.handle_event(GamepadEvent::AxisMoved(id, GamepadAxis::LeftTrigger, -1.0));
+
+    let pad = pads.get(id).unwrap();
+    assert_eq!(pad.axis(GamepadAxis::RightStickY), 1.0);
+    assert_eq!(pad.axis(GamepadAxis::LeftTrigger), 0.0);
+}
+
+#[test]
+fn connect_and_disconnect_are_reported_for_one_frame() {
+    let mut pads = GamepadState::new();
+    let id = GamepadId(1);
+
+    pads.handle_event(GamepadEvent::Connected(id));
+    assert!(pads.just_connected(id));
+
+    pads.clear_events();
+    assert!(!pads.just_connected(id));
+    assert!(pads.get(id).is_some());
+
+    pads.handle_event(GamepadEvent::Disconnected(id));
+    assert!(pads.just_disconnected(id));
+    assert!(pads.get(id).is_none());
+    assert!(pads.connected().is_empty());
+}
+
+#[test]
+fn action_map_reads_gamepad_bindings() {
+    let mut actions = ActionMap::from_config(
+        "button jump = Space, PadSouth\naxis move_x deadzone=0.2 = A/D, PadLeftStickX\n",
+    )
+    .unwrap();
+    assert!(actions.to_config().contains("PadLeftStickX"));
+
+    let mut input = Input::default();
+    input.gamepads.handle_event(GamepadEvent::ButtonPressed(
+        GamepadId(0),
+        GamepadButton::South,
+    ));
+    input.gamepads.handle_event(GamepadEvent::AxisMoved(
+        GamepadId(0),
+        GamepadAxis::LeftStickX,
+        0.1,
+    ));
+    actions.update(&input);
+    assert!(actions.pressed("jump"));
+    assert_eq!(actions.value("move_x"), 0.0);
+
+    actions
+        .bind_axis(
+            "throttle",
+            AxisBinding::Analog(AnalogSource::Gamepad(GamepadAxis::RightTrigger)),
+        )
+        .unwrap();
+    actions
+        .bind_button("pause", ButtonBinding::Gamepad(GamepadButton::Start))
+        .unwrap();
+    input.gamepads.handle_event(GamepadEvent::AxisMoved(
+        GamepadId(2),
+        GamepadAxis::RightTrigger,
+        0.75,
+    ));
+    actions.update(&input);
+    assert_eq!(actions.value("throttle"), 0.75);
+    assert!(!actions.pressed("pause"));
+}
+
+#[test]
+fn engine_tick_publishes_gamepad_state_with_input() {
+    let mut engine = Engine::new();
+    let mut input = Input::default();
+    input
+        .gamepads
+        .handle_event(GamepadEvent::Connected(GamepadId(0)));
+
+    engine.tick(input, 0.016);
+
+    let world: &World = engine.world();
+    let published = world.get_resource::<Input>().unwrap();
+    assert!(published.gamepads.just_connected(GamepadId(0)));
+}
@@ -0,0 +1,8 @@
+//! Input test module
+//
+// Groups input-related tests by subsystem.
+
+mod action;
+mod gamepad;
+mod mouse;
+mod text;
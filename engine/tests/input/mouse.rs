@@ -0,0 +1,77 @@
+use pyreframe_engine::input::{
+    ActionMap, Input, MouseButton, MouseButtons, MouseDelta, MousePosition, MouseState, MouseWheel,
+};
+use pyreframe_engine::math::{Vec2, Vec3};
+use pyreframe_engine::render::{Camera, Materials, RenderView, Transform, view_matrix};
+
+#[test]
+fn move_to_accumulates_delta_until_next_frame() {
+    let mut mouse = MouseState::default();
+    mouse.move_to(10.0, 5.0);
+    // The first position only places the cursor
+    assert_eq!(mouse.delta, MouseDelta::default());
+    assert!(mouse.has_position);
+    mouse.move_to(14.0, 2.0);
+    assert_eq!(mouse.position, MousePosition { x: 14.0, y: 2.0 });
+    assert_eq!(mouse.delta, MouseDelta { x: 4.0, y: -3.0 });
+
+    mouse.begin_frame();
+    assert_eq!(mouse.delta, MouseDelta::default());
+    mouse.move_to(13.0, 2.0);
+    mouse.add_motion(0.5, 0.5);
+    assert_eq!(mouse.delta, MouseDelta { x: -0.5, y: 0.5 });
+    assert_eq!(mouse.position, MousePosition { x: 13.0, y: 2.0 });
+}
+
+#[test]
+fn scroll_accumulates_both_axes_and_resets_per_frame() {
+    let mut mouse = MouseState::default();
+    mouse.scroll(0.0, 1.0);
+    mouse.scroll(-0.5, 1.0);
+    assert_eq!(mouse.wheel, MouseWheel { x: -0.5, y: 2.0 });
+
+    mouse.begin_frame();
+    assert_eq!(mouse.wheel, MouseWheel::default());
+}
+
+#[test]
+fn side_buttons_are_tracked_and_bindable() {
+    let mut buttons = MouseButtons::default();
+    buttons.set(MouseButton::Back, true);
+    assert!(buttons.back);
+    assert!(buttons.is_pressed(MouseButton::Back));
+    assert!(!buttons.is_pressed(MouseButton::Forward));
+
+    let mut actions =
+        ActionMap::from_config("button undo = MouseBack\naxis pan = WheelX\n").unwrap();
+    let mut input = Input::default();
+    input.mouse.buttons = buttons;
+    input.mouse.scroll(-1.0, 0.0);
+    actions.update(&input);
+    assert!(actions.pressed("undo"));
+    assert_eq!(actions.value("pan"), -1.0);
+}
+
+#[test]
+fn cursor_converts_to_world_through_a_pixel_camera() {
+    // One world unit per pixel on an 800x600 window, centered on (100, 50)
+    let camera = Camera::orthographic(600.0);
+    let view = RenderView::new(
+        &camera,
+        view_matrix(&Transform::from_translation(Vec3::new(100.0, 50.0, 0.0))),
+        &[],
+        &Materials::new(),
+    );
+    let mut mouse = MouseState {
+        inside_window: true,
+        ..MouseState::default()
+    };
+
+    mouse.move_to(400.0, 300.0);
+    let center = mouse.world_position(&view, 800, 600).unwrap();
+    assert!(center.abs_diff_eq(Vec2::new(100.0, 50.0), 1e-2));
+
+    mouse.move_to(0.0, 0.0);
+    let corner = mouse.world_position(&view, 800, 600).unwrap();
+    assert!(corner.abs_diff_eq(Vec2::new(-300.0, 350.0), 1e-2));
+}
@@ -0,0 +1,141 @@
+use pyreframe_engine::ecs::World;
+use pyreframe_engine::ecs::system::{action_system, text_input_system};
+use pyreframe_engine::input::{
+    ActionMap, ButtonBinding, Input, Key, KeyboardState, TextEvent, TextField, TextFocus, TextInput,
+};
+
+fn typed(events: Vec<TextEvent>) -> TextInput {
+    let mut text = TextInput::new();
+    for event in events {
+        text.push(event);
+    }
+    text
+}
+
+#[test]
+fn committed_text_joins_chars_and_compositions() {
+    let text = typed(vec![
+        TextEvent::Char('h'),
+        TextEvent::CompositionUpdate {
+            text: "に".to_string(),
+            cursor: 3,
+        },
+        TextEvent::CompositionCommit("日本".to_string()),
+        TextEvent::Char('!'),
+    ]);
+    assert_eq!(text.committed(), "h日本!");
+    assert_eq!(text.events().len(), 4);
+}
+
+#[test]
+fn text_field_applies_composition_backspace_and_enter() {
+    let mut field = TextField::new(None);
+    field.apply(&typed(vec![
+        TextEvent::Char('a'),
+        TextEvent::Char('b'),
+        TextEvent::Char('\u{8}'),
+        TextEvent::CompositionUpdate {
+            text: "ka".to_string(),
+            cursor: 2,
+        },
+    ]));
+    assert_eq!(field.value, "a");
+    assert_eq!(field.composition, "ka");
+    assert!(!field.submitted);
+
+    field.apply(&typed(vec![
+        TextEvent::CompositionCommit("か".to_string()),
+        TextEvent::Char('\t'),
+        TextEvent::Char('\r'),
+    ]));
+    assert_eq!(field.value, "aか");
+    assert!(field.composition.is_empty());
+    assert!(field.submitted);
+
+    field.apply(&typed(vec![TextEvent::CompositionCancel]));
+    assert!(!field.submitted);
+}
+
+#[test]
+fn text_field_respects_character_limit() {
+    let mut field = TextField::new(Some(3));
+    field.apply(&typed(vec![TextEvent::CompositionCommit(
+        "ñandú".to_string(),
+    )]));
+    assert_eq!(field.value, "ñan");
+}
+
+#[test]
+fn key_to_char_follows_shift() {
+    assert_eq!(Key::Q.to_char(false), Some('q'));
+    assert_eq!(Key::Q.to_char(true), Some('Q'));
+    assert_eq!(Key::Num2.to_char(true), Some('@'));
+    assert_eq!(Key::Num7.to_char(false), Some('7'));
+    assert_eq!(Key::LeftShift.to_char(true), None);
+}
+
+#[test]
+fn focused_field_receives_text_and_swallows_actions() {
+    let mut world = World::new();
+    let name_field = world.spawn();
+    world
+        .insert_component(name_field, TextField::new(None))
+        .unwrap();
+
+    let mut actions = ActionMap::new();
+    actions
+        .bind_button("jump", ButtonBinding::Key(Key::Space))
+        .unwrap();
+    world.insert_resource(actions);
+
+    let mut focus = TextFocus::default();
+    focus.focus(name_field);
+    world.insert_resource(focus);
+
+    let mut input = Input {
+        keyboard: KeyboardState::new(vec![Key::Space]),
+        ..Input::default()
+    };
+    input.text.push(TextEvent::Char(' '));
+    world.insert_resource(input.clone());
+
+    action_system(&mut world);
+    text_input_system(&mut world);
+    assert!(!world.get_resource::<ActionMap>().unwrap().pressed("jump"));
+    assert_eq!(
+        world.get_component::<TextField>(name_field).unwrap().value,
+        " "
+    );
+
+    world.get_resource_mut::<TextFocus>().unwrap().blur();
+    action_system(&mut world);
+    text_input_system(&mut world);
+    assert!(world.get_resource::<ActionMap>().unwrap().pressed("jump"));
+    assert_eq!(
+        world.get_component::<TextField>(name_field).unwrap().value,
+        " "
+    );
+}
+
+#[test]
+fn submit_flag_clears_after_the_field_loses_focus() {
+    let mut world = World::new();
+    let field = world.spawn();
+    world.insert_component(field, TextField::new(None)).unwrap();
+
+    let mut focus = TextFocus::default();
+    focus.focus(field);
+    world.insert_resource(focus);
+
+    let mut input = Input::default();
+    input.text.push(TextEvent::Char('\r'));
+    world.insert_resource(input);
+
+    text_input_system(&mut world);
+    assert!(world.get_component::<TextField>(field).unwrap().submitted);
+
+    world.get_resource_mut::<TextFocus>().unwrap().blur();
+    world.insert_resource(Input::default());
+    text_input_system(&mut world);
+    assert!(!world.get_component::<TextField>(field).unwrap().submitted);
+}
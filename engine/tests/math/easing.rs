@@ -0,0 +1,58 @@
+use pyreframe_engine::math::Easing;
+
+#[test]
+fn every_curve_starts_at_zero_and_ends_at_one() {
+    for easing in Easing::ALL {
+        assert_eq!(easing.apply(0.0), 0.0, "{easing:?}");
+        assert_eq!(easing.apply(1.0), 1.0, "{easing:?}");
+        assert_eq!(easing.apply(-3.0), 0.0, "{easing:?}");
+        assert_eq!(easing.apply(4.0), 1.0, "{easing:?}");
+    }
+}
+
+#[test]
+fn in_out_curves_pass_through_the_midpoint() {
+    for easing in [
+        Easing::Linear,
+        Easing::QuadInOut,
+        Easing::CubicInOut,
+        Easing::QuartInOut,
+        Easing::SineInOut,
+        Easing::ExpoInOut,
+        Easing::CircInOut,
+        Easing::BackInOut,
+        Easing::ElasticInOut,
+        Easing::BounceInOut,
+    ] {
+        assert!((easing.apply(0.5) - 0.5).abs() < 1e-5, "{easing:?}");
+    }
+}
+
+#[test]
+fn out_curves_mirror_in_curves() {
+    let pairs = [
+        (Easing::QuadIn, Easing::QuadOut),
+        (Easing::CubicIn, Easing::CubicOut),
+        (Easing::SineIn, Easing::SineOut),
+        (Easing::BackIn, Easing::BackOut),
+        (Easing::ElasticIn, Easing::ElasticOut),
+        (Easing::BounceIn, Easing::BounceOut),
+    ];
+    for (ease_in, ease_out) in pairs {
+        for i in 1..10 {
+            let t = i as f32 / 10.0;
+            let mirrored = 1.0 - ease_out.apply(1.0 - t);
+            assert!((ease_in.apply(t) - mirrored).abs() < 1e-5, "{ease_in:?}");
+        }
+    }
+}
+
+#[test]
+fn known_values() {
+    assert!((Easing::QuadIn.apply(0.5) - 0.25).abs() < 1e-6);
+    assert!((Easing::CubicOut.apply(0.5) - 0.875).abs() < 1e-6);
+    assert!((Easing::BounceOut.apply(0.5) - 0.765625).abs() < 1e-6);
+    // Back overshoots below zero early on
+    assert!(Easing::BackIn.apply(0.2) < 0.0);
+    assert!(Easing::ElasticOut.apply(0.1) > 1.0);
+}
@@ -0,0 +1,165 @@
+use std::f32::consts::FRAC_PI_4;
+
+use pyreframe_engine::math::{
+    Aabb, Circle, Frustum, Mat4, OrientedRect, Plane, Ray, Sphere, Vec2, Vec3, point_in_polygon,
+};
+
+fn unit_box() -> Aabb {
+    Aabb::new(Vec3::ZERO, Vec3::ONE)
+}
+
+#[test]
+fn aabb_new_orders_corners_and_from_points_bounds_them() {
+    let aabb = Aabb::new(Vec3::new(1.0, -1.0, 2.0), Vec3::new(-1.0, 1.0, 0.0));
+    assert_eq!(aabb.min, Vec3::new(-1.0, -1.0, 0.0));
+    assert_eq!(aabb.max, Vec3::new(1.0, 1.0, 2.0));
+    assert_eq!(aabb.center(), Vec3::new(0.0, 0.0, 1.0));
+
+    let bounds = Aabb::from_points(&[Vec3::X, -Vec3::Y, Vec3::Z * 3.0]).unwrap();
+    assert_eq!(
+        bounds,
+        Aabb::new(Vec3::new(0.0, -1.0, 0.0), Vec3::new(1.0, 0.0, 3.0))
+    );
+    assert_eq!(Aabb::from_points(&[]), None);
+}
+
+#[test]
+fn aabb_overlap_includes_touching_and_degenerate_boxes() {
+    let a = unit_box();
+    let touching = Aabb::new(Vec3::new(1.0, 0.0, 0.0), Vec3::new(2.0, 1.0, 1.0));
+    let apart = Aabb::new(Vec3::new(1.1, 0.0, 0.0), Vec3::new(2.0, 1.0, 1.0));
+    assert!(a.intersects_aabb(&touching));
+    assert!(!a.intersects_aabb(&apart));
+
+    let point_box = Aabb::new(Vec3::splat(0.5), Vec3::splat(0.5));
+    assert!(a.intersects_aabb(&point_box));
+    assert!(a.contains_aabb(&point_box));
+    assert!(point_box.contains_point(Vec3::splat(0.5)));
+    assert!(!point_box.contains_point(Vec3::splat(0.6)));
+
+    let flat = Aabb::new(Vec3::new(-1.0, 0.5, -1.0), Vec3::new(2.0, 0.5, 2.0));
+    assert!(a.intersects_aabb(&flat));
+    assert!(!a.contains_aabb(&flat));
+}
+
+#[test]
+fn aabb_sphere_and_transform() {
+    let a = unit_box();
+    assert!(a.intersects_sphere(&Sphere::new(Vec3::new(2.0, 0.5, 0.5), 1.0)));
+    assert!(!a.intersects_sphere(&Sphere::new(Vec3::new(2.0, 2.0, 2.0), 1.0)));
+
+    let moved = a.transformed(&Mat4::from_translation(Vec3::new(5.0, 0.0, 0.0)));
+    assert_eq!(moved.min, Vec3::new(5.0, 0.0, 0.0));
+    assert_eq!(a.union(&moved).max, Vec3::new(6.0, 1.0, 1.0));
+}
+
+#[test]
+fn ray_aabb_handles_inside_parallel_and_behind_cases() {
+    let a = unit_box();
+
+    let hit = Ray::new(Vec3::new(-2.0, 0.5, 0.5), Vec3::X).intersect_aabb(&a);
+    assert_eq!(hit, Some(2.0));
+
+    let inside = Ray::new(Vec3::splat(0.5), Vec3::Y).intersect_aabb(&a);
+    assert_eq!(inside, Some(0.0));
+
+    let behind = Ray::new(Vec3::new(2.0, 0.5, 0.5), Vec3::X).intersect_aabb(&a);
+    assert_eq!(behind, None);
+
+    let parallel_outside = Ray::new(Vec3::new(-1.0, 2.0, 0.5), Vec3::X).intersect_aabb(&a);
+    assert_eq!(parallel_outside, None);
+
+    let grazing = Ray::new(Vec3::new(-1.0, 1.0, 0.5), Vec3::X).intersect_aabb(&a);
+    assert_eq!(grazing, Some(1.0));
+
+    let point_box = Aabb::new(Vec3::splat(3.0), Vec3::splat(3.0));
+    let diagonal = Ray::new(Vec3::ZERO, Vec3::ONE).intersect_aabb(&point_box);
+    assert_eq!(diagonal, Some(3.0));
+}
+
+#[test]
+fn ray_sphere_and_plane() {
+    let sphere = Sphere::new(Vec3::new(0.0, 0.0, -5.0), 1.0);
+    let ray = Ray::new(Vec3::ZERO, -Vec3::Z);
+    assert_eq!(ray.intersect_sphere(&sphere), Some(4.0));
+    assert_eq!(ray.at(4.0), Vec3::new(0.0, 0.0, -4.0));
+    assert_eq!(
+        Ray::new(Vec3::ZERO, Vec3::Z).intersect_sphere(&sphere),
+        None
+    );
+
+    let ground = Plane::from_point_normal(Vec3::ZERO, Vec3::Y * 2.0);
+    let down = Ray::new(Vec3::new(1.0, 3.0, 1.0), -Vec3::Y);
+    assert_eq!(down.intersect_plane(&ground), Some(3.0));
+    assert_eq!(Ray::new(Vec3::Y, Vec3::X).intersect_plane(&ground), None);
+    assert_eq!(ground.signed_distance(Vec3::new(0.0, -2.0, 0.0)), -2.0);
+}
+
+#[test]
+fn frustum_culls_spheres_and_boxes() {
+    let view = Mat4::look_at_rh(Vec3::ZERO, -Vec3::Z, Vec3::Y);
+    let proj = Mat4::perspective_rh(std::f32::consts::FRAC_PI_2, 1.0, 0.1, 100.0);
+    let frustum = Frustum::from_view_projection(&(proj * view)).unwrap();
+
+    assert!(frustum.contains_point(Vec3::new(0.0, 0.0, -10.0)));
+    assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, 10.0)));
+
+    assert!(frustum.intersects_sphere(&Sphere::new(Vec3::new(0.0, 0.0, -50.0), 1.0)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(Vec3::new(0.0, 0.0, 5.0), 1.0)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(Vec3::new(0.0, 0.0, -200.0), 10.0)));
+    assert!(frustum.intersects_sphere(&Sphere::new(Vec3::new(11.0, 0.0, -10.0), 1.5)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(Vec3::new(20.0, 0.0, -10.0), 1.0)));
+
+    let straddling = Aabb::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+    assert!(frustum.intersects_aabb(&straddling));
+    let behind = Aabb::new(Vec3::new(-1.0, -1.0, 1.0), Vec3::new(1.0, 1.0, 2.0));
+    assert!(!frustum.intersects_aabb(&behind));
+
+    assert_eq!(Frustum::from_view_projection(&Mat4::ZERO), None);
+}
+
+#[test]
+fn circles_and_oriented_rects() {
+    let circle = Circle::new(Vec2::ZERO, 1.0);
+    assert!(circle.contains_point(Vec2::new(0.0, 1.0)));
+    assert!(circle.intersects_circle(&Circle::new(Vec2::new(2.0, 0.0), 1.0)));
+    assert!(!circle.intersects_circle(&Circle::new(Vec2::new(2.1, 0.0), 1.0)));
+
+    let diamond = OrientedRect::new(Vec2::ZERO, Vec2::ONE, FRAC_PI_4);
+    assert!(diamond.contains_point(Vec2::new(1.4, 0.0)));
+    assert!(!diamond.contains_point(Vec2::new(0.9, 0.9)));
+    assert!(diamond.intersects_circle(&Circle::new(Vec2::new(2.0, 0.0), 0.6)));
+    assert!(!diamond.intersects_circle(&Circle::new(Vec2::new(1.2, 1.2), 0.3)));
+
+    let square = OrientedRect::new(Vec2::new(2.0, 0.0), Vec2::ONE, 0.0);
+    assert!(!square.intersects_rect(&OrientedRect::new(Vec2::ZERO, Vec2::splat(0.7), 0.0)));
+    assert!(square.intersects_rect(&diamond));
+    let zero_size = OrientedRect::new(Vec2::new(1.0, 0.5), Vec2::ZERO, 0.3);
+    assert!(square.intersects_rect(&zero_size));
+    let near_miss = OrientedRect::new(Vec2::new(2.5, 0.0), Vec2::ONE, 0.0);
+    assert!(!near_miss.intersects_rect(&diamond));
+}
+
+#[test]
+fn point_in_polygon_handles_concave_shapes_and_edges() {
+    // A "C" shape open to the right
+    let c_shape = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(3.0, 0.0),
+        Vec2::new(3.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(3.0, 2.0),
+        Vec2::new(3.0, 3.0),
+        Vec2::new(0.0, 3.0),
+    ];
+    assert!(point_in_polygon(Vec2::new(0.5, 1.5), &c_shape));
+    assert!(point_in_polygon(Vec2::new(2.0, 0.5), &c_shape));
+    assert!(!point_in_polygon(Vec2::new(2.0, 1.5), &c_shape));
+    assert!(!point_in_polygon(Vec2::new(-1.0, 1.5), &c_shape));
+    assert!(point_in_polygon(Vec2::new(3.0, 0.5), &c_shape));
+    assert!(point_in_polygon(Vec2::new(0.0, 0.0), &c_shape));
+
+    let degenerate = [Vec2::ZERO, Vec2::ONE];
+    assert!(!point_in_polygon(Vec2::splat(0.5), &degenerate));
+}
@@ -0,0 +1,101 @@
+use std::f32::consts::FRAC_PI_2;
+
+use pyreframe_engine::math::{Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+
+#[test]
+fn mat3_composes_2d_transforms() {
+    let m = Mat3::from_scale_angle_translation(Vec2::splat(2.0), FRAC_PI_2, Vec2::new(10.0, 0.0));
+    assert!(
+        m.transform_point2(Vec2::X)
+            .abs_diff_eq(Vec2::new(10.0, 2.0), 1e-5)
+    );
+    assert!(
+        m.transform_vector2(Vec2::X)
+            .abs_diff_eq(Vec2::new(0.0, 2.0), 1e-5)
+    );
+    assert!((m.determinant() - 4.0).abs() < 1e-5);
+}
+
+#[test]
+fn mat3_inverse_round_trips() {
+    let m = Mat3::from_scale_angle_translation(Vec2::new(2.0, 3.0), 0.4, Vec2::new(-1.0, 5.0));
+    let inv = m.inverse().unwrap();
+    assert!((m * inv).abs_diff_eq(&Mat3::IDENTITY, 1e-5));
+    assert_eq!(Mat3::ZERO.inverse(), None);
+}
+
+#[test]
+fn mat4_inverse_round_trips_and_detects_singular() {
+    let m = Mat4::from_scale_rotation_translation(
+        Vec3::new(1.0, 2.0, 0.5),
+        Quat::from_axis_angle(Vec3::new(0.0, 0.6, 0.8), 1.1),
+        Vec3::new(3.0, -4.0, 5.0),
+    );
+    let inv = m.inverse().unwrap();
+    assert!((m * inv).abs_diff_eq(&Mat4::IDENTITY, 1e-5));
+    assert!((m.determinant() - 1.0).abs() < 1e-5);
+    assert_eq!(Mat4::from_scale(Vec3::new(1.0, 0.0, 1.0)).inverse(), None);
+}
+
+#[test]
+fn small_scales_and_pixel_projections_are_invertible() {
+    let small = Mat3::from_scale(Vec2::splat(0.001));
+    assert!((small * small.inverse().unwrap()).abs_diff_eq(&Mat3::IDENTITY, 1e-5));
+
+    let scale = Mat4::from_scale(Vec3::splat(0.01));
+    assert!((scale * scale.inverse().unwrap()).abs_diff_eq(&Mat4::IDENTITY, 1e-5));
+
+    // Determinant is about 1e-8 for an 800x600 pixel ortho
+    let ortho = Mat4::orthographic_rh(0.0, 800.0, 0.0, 600.0, -1000.0, 1000.0);
+    let inv = ortho.inverse().unwrap();
+    let corner = inv * Vec4::new(1.0, 1.0, 0.0, 1.0);
+    assert!(corner.abs_diff_eq(Vec4::new(800.0, 600.0, 0.0, 1.0), 1e-2));
+}
+
+#[test]
+fn mat4_transpose_and_array_round_trip() {
+    let m = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(Mat4::from_cols_array(&m.to_cols_array()), m);
+    assert_eq!(m.transpose().transpose(), m);
+    assert_eq!(m.transpose().x_axis, Vec4::new(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(m.transform_point3(Vec3::ZERO), Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(m.transform_vector3(Vec3::X), Vec3::X);
+}
+
+#[test]
+fn look_at_maps_target_onto_negative_z() {
+    let view = Mat4::look_at_rh(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+    assert!(
+        view.transform_point3(Vec3::ZERO)
+            .abs_diff_eq(Vec3::new(0.0, 0.0, -5.0), 1e-6)
+    );
+    assert!(
+        view.transform_point3(Vec3::new(1.0, 0.0, 0.0))
+            .abs_diff_eq(Vec3::new(1.0, 0.0, -5.0), 1e-6)
+    );
+}
+
+#[test]
+fn perspective_maps_near_and_far_to_clip_depth() {
+    let proj = Mat4::perspective_rh(FRAC_PI_2, 2.0, 1.0, 100.0);
+    let near = proj.project_point3(Vec3::new(0.0, 0.0, -1.0));
+    let far = proj.project_point3(Vec3::new(0.0, 0.0, -100.0));
+    assert!((near.z + 1.0).abs() < 1e-5);
+    assert!((far.z - 1.0).abs() < 1e-5);
+
+    let edge = proj.project_point3(Vec3::new(2.0, 1.0, -1.0));
+    assert!(edge.abs_diff_eq(Vec3::new(1.0, 1.0, -1.0), 1e-5));
+}
+
+#[test]
+fn orthographic_maps_box_to_unit_cube() {
+    let proj = Mat4::orthographic_rh(-10.0, 10.0, -5.0, 5.0, 0.0, 10.0);
+    assert!(
+        proj.project_point3(Vec3::new(10.0, -5.0, 0.0))
+            .abs_diff_eq(Vec3::new(1.0, -1.0, -1.0), 1e-6)
+    );
+    assert!(
+        proj.project_point3(Vec3::new(0.0, 0.0, -10.0))
+            .abs_diff_eq(Vec3::new(0.0, 0.0, 1.0), 1e-6)
+    );
+}
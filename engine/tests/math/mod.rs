@@ -0,0 +1,11 @@
+//! Math test module
+//
+// Groups math tests by type family.
+
+mod easing;
+mod geometry;
+mod matrix;
+mod noise;
+mod quat;
+mod random;
+mod vector;
@@ -0,0 +1,105 @@
+use pyreframe_engine::math::{Fbm, Noise, Vec2, Vec3};
+
+const P1: f32 = 0.37;
+const P2: Vec2 = Vec2::new(1.37, 2.71);
+const P3: Vec3 = Vec3::new(0.37, 1.91, 2.23);
+
+fn assert_golden(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn golden_values_for_seed_one() {
+    let noise = Noise::new(1);
+    let fbm = Fbm::default();
+
+    assert_golden(noise.value1(P1), 0.15228552);
+    assert_golden(noise.value2(P2), -0.46954173);
+    assert_golden(noise.value3(P3), -0.0050926767);
+    assert_golden(noise.perlin1(P1), 0.18180034);
+    assert_golden(noise.perlin2(P2), -0.26791176);
+    assert_golden(noise.perlin3(P3), -0.4355759);
+    assert_golden(noise.simplex2(P2), -0.5922448);
+    assert_golden(noise.simplex3(P3), 0.53695667);
+    assert_golden(noise.fbm2(P2, &fbm), -0.31309727);
+    assert_golden(noise.fbm3(P3, &fbm), 0.3194868);
+    assert_golden(noise.warp2(P2, 2.0, &fbm), -0.04550314);
+    assert_golden(noise.warp3(P3, 2.0, &fbm), 0.069407016);
+}
+
+#[test]
+fn same_seed_matches_and_other_seeds_differ() {
+    let a = Noise::new(7);
+    assert_eq!(a, Noise::new(7));
+    assert_eq!(a.seed(), 7);
+    assert_eq!(a.simplex2(P2), Noise::new(7).simplex2(P2));
+    assert_ne!(a.simplex2(P2), Noise::new(8).simplex2(P2));
+}
+
+#[test]
+fn outputs_stay_in_range() {
+    let noise = Noise::new(3);
+    for i in 0..5000 {
+        let x = i as f32 * 0.173 - 400.0;
+        let p2 = Vec2::new(x, x * 0.61 + 3.0);
+        let p3 = Vec3::new(x, -x * 0.37, x * 0.11 + 8.0);
+        for v in [
+            noise.value1(x),
+            noise.value2(p2),
+            noise.value3(p3),
+            noise.perlin1(x),
+            noise.perlin2(p2),
+            noise.perlin3(p3),
+            noise.simplex2(p2),
+            noise.simplex3(p3),
+        ] {
+            assert!((-1.0..=1.0).contains(&v), "{v} out of range");
+        }
+    }
+}
+
+#[test]
+fn perlin_is_zero_on_lattice_points() {
+    let noise = Noise::new(5);
+    for i in -3..3 {
+        let f = i as f32;
+        assert_eq!(noise.perlin1(f), 0.0);
+        assert_eq!(noise.perlin2(Vec2::new(f, 2.0 * f)), 0.0);
+        assert_eq!(noise.perlin3(Vec3::new(f, 1.0, -f)), 0.0);
+    }
+}
+
+#[test]
+fn noise_is_continuous() {
+    let noise = Noise::new(11);
+    let step = Vec2::new(1e-3, 0.0);
+    for i in 0..200 {
+        let p = Vec2::new(i as f32 * 0.05, 0.3);
+        assert!((noise.simplex2(p) - noise.simplex2(p + step)).abs() < 0.02);
+        assert!((noise.perlin2(p) - noise.perlin2(p + step)).abs() < 0.02);
+        assert!((noise.value2(p) - noise.value2(p + step)).abs() < 0.02);
+    }
+}
+
+#[test]
+fn fbm_edge_cases() {
+    let noise = Noise::new(2);
+    let none = Fbm {
+        octaves: 0,
+        ..Fbm::default()
+    };
+    assert_eq!(noise.fbm2(P2, &none), 0.0);
+
+    let single = Fbm {
+        octaves: 1,
+        ..Fbm::default()
+    };
+    assert_eq!(noise.fbm2(P2, &single), noise.simplex2(P2));
+
+    let fbm = Fbm::default();
+    assert_eq!(noise.warp2(P2, 0.0, &fbm), noise.fbm2(P2, &fbm));
+    assert_eq!(noise.warp3(P3, 0.0, &fbm), noise.fbm3(P3, &fbm));
+}
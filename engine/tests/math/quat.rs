@@ -0,0 +1,48 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use pyreframe_engine::math::{Quat, Vec3};
+
+#[test]
+fn rotation_about_z_turns_x_into_y() {
+    let q = Quat::from_rotation_z(FRAC_PI_2);
+    assert!((q * Vec3::X).abs_diff_eq(Vec3::Y, 1e-6));
+    assert!((q * Vec3::Z).abs_diff_eq(Vec3::Z, 1e-6));
+}
+
+#[test]
+fn composition_applies_right_hand_side_first() {
+    let yaw = Quat::from_rotation_y(FRAC_PI_2);
+    let pitch = Quat::from_rotation_x(FRAC_PI_2);
+    let v = (yaw * pitch) * Vec3::Y;
+    assert!(v.abs_diff_eq(yaw * (pitch * Vec3::Y), 1e-6));
+    assert!(v.abs_diff_eq(Vec3::X, 1e-6));
+}
+
+#[test]
+fn inverse_undoes_rotation() {
+    let q = Quat::from_axis_angle(Vec3::new(1.0, 1.0, 0.0).normalize_or_zero(), 0.7);
+    let v = Vec3::new(0.3, -2.0, 5.0);
+    let back = q.inverse().unwrap() * (q * v);
+    assert!(back.abs_diff_eq(v, 1e-5));
+    assert!(q.conjugate().abs_diff_eq(q.inverse().unwrap(), 1e-6));
+    assert_eq!(Quat::from_xyzw(0.0, 0.0, 0.0, 0.0).inverse(), None);
+}
+
+#[test]
+fn slerp_moves_at_constant_angular_speed() {
+    let a = Quat::IDENTITY;
+    let b = Quat::from_rotation_z(PI * 0.5);
+    let mid = a.slerp(b, 0.5);
+    assert!(mid.abs_diff_eq(Quat::from_rotation_z(PI * 0.25), 1e-6));
+    assert!(a.slerp(b, 0.0).abs_diff_eq(a, 1e-6));
+    assert!(a.slerp(b, 1.0).abs_diff_eq(b, 1e-6));
+    assert!((a.lerp(b, 0.5).length() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn slerp_takes_the_shortest_path() {
+    let a = Quat::from_rotation_z(0.1);
+    let b = -Quat::from_rotation_z(0.3);
+    let mid = a.slerp(b, 0.5);
+    assert!(mid.abs_diff_eq(Quat::from_rotation_z(0.2), 1e-5));
+}
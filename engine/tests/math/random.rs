@@ -0,0 +1,119 @@
+use pyreframe_engine::World;
+use pyreframe_engine::math::{Random, Rng};
+
+#[test]
+fn same_seed_gives_same_sequence() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let mut c = Rng::new(43);
+    let seq_a: Vec<u32> = (0..16).map(|_| a.next_u32()).collect();
+    let seq_b: Vec<u32> = (0..16).map(|_| b.next_u32()).collect();
+    let seq_c: Vec<u32> = (0..16).map(|_| c.next_u32()).collect();
+    assert_eq!(seq_a, seq_b);
+    assert_ne!(seq_a, seq_c);
+}
+
+#[test]
+fn ranges_stay_within_bounds() {
+    let mut rng = Rng::new(7);
+    for _ in 0..1000 {
+        let f = rng.range_f32(-2.0, 3.0);
+        assert!((-2.0..3.0).contains(&f));
+        let i = rng.range_i32(-5, 5);
+        assert!((-5..5).contains(&i));
+        assert!(rng.next_f32() < 1.0);
+    }
+    assert_eq!(rng.range_i32(4, 4), 4);
+    assert_eq!(rng.range_i32(i32::MIN, i32::MIN + 1), i32::MIN);
+}
+
+#[test]
+fn range_i32_covers_every_value() {
+    let mut rng = Rng::new(1);
+    let mut seen = [false; 6];
+    for _ in 0..200 {
+        seen[rng.range_i32(0, 6) as usize] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+}
+
+#[test]
+fn weighted_index_skips_zero_weights() {
+    let mut rng = Rng::new(3);
+    let weights = [0.0, 1.0, -4.0, 3.0, f32::NAN];
+    let mut counts = [0; 5];
+    for _ in 0..4000 {
+        counts[rng.weighted_index(&weights).unwrap()] += 1;
+    }
+    assert_eq!(counts[0], 0);
+    assert_eq!(counts[2], 0);
+    assert_eq!(counts[4], 0);
+    // Index 3 has three times the weight of index 1
+    assert!(counts[3] > counts[1] * 2);
+    assert_eq!(rng.weighted_index(&[0.0, 0.0]), None);
+    assert_eq!(rng.weighted_index(&[]), None);
+}
+
+#[test]
+fn shuffle_is_a_permutation() {
+    let mut rng = Rng::new(9);
+    let mut items: Vec<u32> = (0..50).collect();
+    rng.shuffle(&mut items);
+    assert_ne!(items, (0..50).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..50).collect::<Vec<_>>());
+    assert_eq!(rng.choose::<u32>(&[]), None);
+    assert_eq!(rng.choose(&[5]), Some(&5));
+}
+
+#[test]
+fn unit_vectors_have_unit_length() {
+    let mut rng = Rng::new(11);
+    for _ in 0..100 {
+        assert!((rng.unit_vec2().length() - 1.0).abs() < 1e-5);
+        assert!((rng.unit_vec3().length() - 1.0).abs() < 1e-5);
+        assert!(rng.in_unit_circle().length() <= 1.0 + 1e-5);
+    }
+}
+
+#[test]
+fn named_streams_ignore_other_streams() {
+    let mut a = Random::new(5);
+    let mut b = Random::new(5);
+
+    // `b` draws from another stream and the shared one first
+    b.stream("spawner").next_u32();
+    b.rng().next_u32();
+
+    let from_a: Vec<u32> = (0..8).map(|_| a.stream("ai").next_u32()).collect();
+    let from_b: Vec<u32> = (0..8).map(|_| b.stream("ai").next_u32()).collect();
+    assert_eq!(from_a, from_b);
+
+    let other: Vec<u32> = (0..8).map(|_| a.stream("spawner").next_u32()).collect();
+    assert_ne!(from_a, other);
+}
+
+#[test]
+fn entity_streams_do_not_depend_on_iteration_order() {
+    let mut world = World::new();
+    let entities: Vec<_> = (0..4).map(|_| world.spawn()).collect();
+    let random = Random::new(99);
+
+    let forward: Vec<f32> = entities
+        .iter()
+        .map(|&e| e.rng(&random, 1).next_f32())
+        .collect();
+    let mut backward: Vec<f32> = entities
+        .iter()
+        .rev()
+        .map(|&e| e.rng(&random, 1).next_f32())
+        .collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    // A different salt, such as the next frame, gives new values
+    assert_ne!(
+        entities[0].rng(&random, 1).next_u32(),
+        entities[0].rng(&random, 2).next_u32()
+    );
+}
@@ -0,0 +1,67 @@
+use pyreframe_engine::math::{Vec2, Vec3, Vec4, inverse_lerp, lerp};
+
+#[test]
+fn vector_operators_work_component_wise() {
+    let a = Vec3::new(1.0, 2.0, 3.0);
+    let b = Vec3::new(4.0, 5.0, 6.0);
+    assert_eq!(a + b, Vec3::new(5.0, 7.0, 9.0));
+    assert_eq!(b - a, Vec3::splat(3.0));
+    assert_eq!(a * 2.0, Vec3::new(2.0, 4.0, 6.0));
+    assert_eq!(2.0 * a, a * 2.0);
+    assert_eq!(a * b, Vec3::new(4.0, 10.0, 18.0));
+    assert_eq!(b / 2.0, Vec3::new(2.0, 2.5, 3.0));
+    assert_eq!(-a, Vec3::new(-1.0, -2.0, -3.0));
+
+    let mut c = a;
+    c += b;
+    c -= Vec3::ONE;
+    c *= 0.5;
+    assert_eq!(c, Vec3::new(2.0, 3.0, 4.0));
+}
+
+#[test]
+fn dot_and_cross_products() {
+    assert_eq!(Vec3::X.cross(Vec3::Y), Vec3::Z);
+    assert_eq!(Vec3::Y.cross(Vec3::X), -Vec3::Z);
+    assert_eq!(
+        Vec3::new(1.0, 2.0, 3.0).dot(Vec3::new(4.0, -5.0, 6.0)),
+        12.0
+    );
+    assert_eq!(Vec2::X.perp_dot(Vec2::Y), 1.0);
+    assert_eq!(Vec2::X.perp(), Vec2::Y);
+    assert_eq!(Vec4::ONE.dot(Vec4::splat(2.0)), 8.0);
+}
+
+#[test]
+fn normalization_handles_zero_vectors() {
+    let v = Vec2::new(3.0, 4.0);
+    assert_eq!(v.length(), 5.0);
+    assert_eq!(v.try_normalize(), Some(Vec2::new(0.6, 0.8)));
+    assert_eq!(Vec3::ZERO.try_normalize(), None);
+    assert_eq!(Vec3::ZERO.normalize_or_zero(), Vec3::ZERO);
+}
+
+#[test]
+fn lerp_and_helpers() {
+    let a = Vec2::new(0.0, 10.0);
+    let b = Vec2::new(10.0, 20.0);
+    assert_eq!(a.lerp(b, 0.25), Vec2::new(2.5, 12.5));
+    assert_eq!(lerp(2.0, 4.0, 0.5), 3.0);
+    assert_eq!(inverse_lerp(2.0, 4.0, 3.0), 0.5);
+    assert_eq!(inverse_lerp(1.0, 1.0, 3.0), 0.0);
+    assert_eq!(a.min(b), a);
+    assert_eq!(Vec3::new(-1.0, 2.0, -3.0).abs(), Vec3::new(1.0, 2.0, 3.0));
+    assert!(
+        Vec2::X
+            .rotate(std::f32::consts::FRAC_PI_2)
+            .abs_diff_eq(Vec2::Y, 1e-6)
+    );
+}
+
+#[test]
+fn vectors_extend_and_truncate() {
+    let v = Vec2::new(1.0, 2.0).extend(3.0);
+    assert_eq!(v, Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(v.extend(4.0).truncate(), v);
+    assert_eq!(v.truncate(), Vec2::new(1.0, 2.0));
+}
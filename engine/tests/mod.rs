@@ -1 +1,8 @@
+mod assets;
+mod core;
 mod ecs;
+mod input;
+mod math;
+mod particles;
+mod render;
+mod tween;
@@ -0,0 +1,155 @@
+use pyreframe_engine::ecs::components::Color;
+use pyreframe_engine::math::Vec3;
+use pyreframe_engine::particles::{Curve, Gradient, Particle, ParticleEmitter};
+use pyreframe_engine::render::{Materials, Meshes, RenderCommand, TextureId};
+
+fn snapshot(emitter: &ParticleEmitter) -> Vec<Particle> {
+    emitter.particles().collect()
+}
+
+#[test]
+fn curves_interpolate_between_keys_and_hold_the_ends() {
+    let curve = Curve::linear(1.0, 3.0).with_key(0.5, 0.0);
+    assert_eq!(curve.sample(-1.0), 1.0);
+    assert_eq!(curve.sample(0.25), 0.5);
+    assert_eq!(curve.sample(0.5), 0.0);
+    assert_eq!(curve.sample(0.75), 1.5);
+    assert_eq!(curve.sample(2.0), 3.0);
+    assert_eq!(Curve::constant(4.0).sample(0.7), 4.0);
+    // A key at an existing time replaces it
+    assert_eq!(Curve::linear(1.0, 3.0).with_key(1.0, 5.0).sample(1.0), 5.0);
+}
+
+#[test]
+fn gradients_blend_in_linear_light() {
+    let gradient = Gradient::linear(Color::BLACK, Color::WHITE.with_alpha(0));
+    assert_eq!(gradient.sample(0.0), Color::BLACK);
+    // Half of linear white is brighter than sRGB 128
+    assert_eq!(gradient.sample(0.5), Color::rgba(188, 188, 188, 128));
+    assert_eq!(gradient.sample(1.0), Color::WHITE.with_alpha(0));
+    assert_eq!(
+        Gradient::constant(Color::RED)
+            .with_key(1.0, Color::BLUE)
+            .sample(1.0),
+        Color::BLUE
+    );
+}
+
+#[test]
+fn rate_spawns_whole_particles_and_carries_the_rest() {
+    let mut emitter = ParticleEmitter::new()
+        .with_rate(3.0)
+        .with_lifetime(10.0..10.0);
+    emitter.update(0.5, Vec3::ZERO);
+    assert_eq!(emitter.len(), 1);
+    emitter.update(0.5, Vec3::ZERO);
+    assert_eq!(emitter.len(), 3);
+
+    emitter.emitting = false;
+    emitter.update(1.0, Vec3::ZERO);
+    assert_eq!(emitter.len(), 3);
+}
+
+#[test]
+fn bursts_spawn_once_up_to_the_limit() {
+    let mut emitter = ParticleEmitter::new().with_rate(0.0).with_max_particles(5);
+    emitter.burst(8);
+    assert!(emitter.is_empty());
+    emitter.update(0.1, Vec3::ZERO);
+    assert_eq!(emitter.len(), 5);
+    emitter.update(0.1, Vec3::ZERO);
+    assert_eq!(emitter.len(), 5);
+
+    emitter.clear();
+    assert!(emitter.is_empty());
+}
+
+#[test]
+fn particles_fall_under_gravity_and_expire() {
+    let mut emitter = ParticleEmitter::new()
+        .with_rate(0.0)
+        .with_lifetime(1.0..1.0)
+        .with_velocity(Vec3::new(2.0, 0.0, 0.0)..Vec3::new(2.0, 0.0, 0.0))
+        .with_gravity(Vec3::new(0.0, -10.0, 0.0));
+    emitter.burst(1);
+    emitter.update(0.5, Vec3::new(1.0, 1.0, 0.0));
+    let particle = snapshot(&emitter)[0];
+    assert_eq!(particle.position, Vec3::new(1.0, 1.0, 0.0));
+    assert_eq!(particle.age, 0.0);
+
+    emitter.update(0.5, Vec3::ZERO);
+    let particle = snapshot(&emitter)[0];
+    assert_eq!(particle.velocity, Vec3::new(2.0, -5.0, 0.0));
+    assert_eq!(particle.position, Vec3::new(2.0, -1.5, 0.0));
+    assert_eq!(particle.life_fraction(), 0.5);
+
+    emitter.update(0.5, Vec3::ZERO);
+    assert!(emitter.is_empty());
+}
+
+#[test]
+fn spawns_stay_within_ranges() {
+    let mut emitter = ParticleEmitter::new()
+        .with_rate(0.0)
+        .with_lifetime(0.5..2.0)
+        .with_velocity(Vec3::new(-1.0, 2.0, 0.0)..Vec3::new(1.0, 4.0, 0.0))
+        .with_spawn_radius(0.5)
+        .with_max_particles(1000);
+    emitter.burst(1000);
+    emitter.update(0.0, Vec3::new(5.0, 0.0, 0.0));
+    assert_eq!(emitter.len(), 1000);
+    for particle in emitter.particles() {
+        assert!((0.5..2.0).contains(&particle.lifetime));
+        assert!((-1.0..1.0).contains(&particle.velocity.x));
+        assert!((2.0..4.0).contains(&particle.velocity.y));
+        assert!(particle.position.distance(Vec3::new(5.0, 0.0, 0.0)) <= 0.5);
+    }
+}
+
+#[test]
+fn same_seed_gives_the_same_particles() {
+    let build = |seed| {
+        let mut emitter = ParticleEmitter::new()
+            .with_seed(seed)
+            .with_rate(100.0)
+            .with_lifetime(0.2..0.6)
+            .with_velocity(Vec3::splat(-1.0)..Vec3::splat(1.0))
+            .with_spawn_radius(1.0);
+        for _ in 0..30 {
+            emitter.update(1.0 / 60.0, Vec3::ZERO);
+        }
+        snapshot(&emitter)
+    };
+    assert_eq!(build(7), build(7));
+    assert_ne!(build(7), build(8));
+}
+
+#[test]
+fn render_commands_share_a_batch_and_follow_curves() {
+    let mut emitter = ParticleEmitter::new()
+        .with_rate(0.0)
+        .with_lifetime(2.0..2.0)
+        .with_size(Curve::linear(1.0, 0.0))
+        .with_color(Gradient::linear(Color::RED, Color::RED.with_alpha(0)))
+        .with_texture(TextureId(2))
+        .with_layer(3);
+    emitter.burst(2);
+    emitter.update(0.0, Vec3::ZERO);
+    emitter.update(0.5, Vec3::ZERO);
+
+    let mut commands = Vec::new();
+    emitter.render_commands(&mut commands);
+    assert_eq!(commands.len(), 2);
+    for command in &commands {
+        assert_eq!(command.mesh, Meshes::QUAD);
+        assert_eq!(command.texture, Some(TextureId(2)));
+        assert_eq!(command.material, Materials::DEFAULT);
+        assert_eq!(command.layer, 3);
+        assert_eq!(
+            command.sort_key,
+            RenderCommand::sort_key_for(3, Materials::DEFAULT, Meshes::QUAD)
+        );
+        assert_eq!(command.transform.scale, Vec3::new(0.75, 0.75, 0.75));
+        assert_eq!(command.color, [255, 0, 0, 191]);
+    }
+}
@@ -0,0 +1,6 @@
+//! Particle test module
+//
+// Groups emitter simulation and system tests.
+
+mod emitter;
+mod system;
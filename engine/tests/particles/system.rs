@@ -0,0 +1,66 @@
+use pyreframe_engine::World;
+use pyreframe_engine::core::extract::extract_render_commands;
+use pyreframe_engine::ecs::components::Position;
+use pyreframe_engine::ecs::system::{particle_system, time_system};
+use pyreframe_engine::math::Vec3;
+use pyreframe_engine::particles::ParticleEmitter;
+use pyreframe_engine::time::{FrameDelta, Time};
+
+fn world_with_time(dt: f32) -> World {
+    let mut world = World::new();
+    world.insert_resource(Time::default());
+    world.insert_resource(FrameDelta { dt });
+    world
+}
+
+fn step(world: &mut World) {
+    time_system(world);
+    particle_system(world);
+}
+
+#[test]
+fn emitters_spawn_at_their_entity_position() {
+    let mut world = world_with_time(0.5);
+    let entity = world.spawn();
+    world
+        .insert_component(entity, Position::new(3.0, -2.0, 0.0))
+        .unwrap();
+    world
+        .insert_component(entity, ParticleEmitter::new().with_rate(4.0))
+        .unwrap();
+
+    step(&mut world);
+    let emitter = world.get_component::<ParticleEmitter>(entity).unwrap();
+    assert_eq!(emitter.len(), 2);
+    assert!(
+        emitter
+            .particles()
+            .all(|particle| particle.position == Vec3::new(3.0, -2.0, 0.0))
+    );
+    // Particles are not entities
+    assert_eq!(world.entities_with::<(ParticleEmitter,)>().len(), 1);
+}
+
+#[test]
+fn extraction_draws_every_particle() {
+    let mut world = world_with_time(0.1);
+    for seed in 0..2 {
+        let entity = world.spawn();
+        world
+            .insert_component(
+                entity,
+                ParticleEmitter::new()
+                    .with_seed(seed)
+                    .with_rate(100.0)
+                    .with_layer(1),
+            )
+            .unwrap();
+    }
+
+    for _ in 0..3 {
+        step(&mut world);
+    }
+    let commands = extract_render_commands(&world);
+    assert_eq!(commands.len(), 60);
+    assert!(commands.iter().all(|command| command.layer == 1));
+}
@@ -0,0 +1,139 @@
+use pyreframe_engine::math::{Vec2, Vec3};
+use pyreframe_engine::render::{
+    AtlasBuilder, AtlasError, AtlasRegion, Camera, Materials, Meshes, RenderCommand, RenderView,
+    SoftwareRenderer, Texture, TextureAtlas, Textures, Transform, UvRect, view_matrix,
+};
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+fn overlaps(a: AtlasRegion, b: AtlasRegion) -> bool {
+    a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+}
+
+#[test]
+fn uv_rect_flips_and_maps() {
+    let rect = UvRect::new(Vec2::new(0.25, 0.5), Vec2::new(0.75, 1.0));
+    assert_eq!(rect.map(Vec2::new(0.5, 0.0)), Vec2::new(0.5, 0.5));
+    let flipped = rect.flipped(true, false);
+    assert_eq!(flipped.min, Vec2::new(0.75, 0.5));
+    assert_eq!(flipped.max, Vec2::new(0.25, 1.0));
+    assert_eq!(rect.flipped(true, true).flipped(true, true), rect);
+    assert_eq!(UvRect::default(), UvRect::FULL);
+}
+
+#[test]
+fn packed_regions_keep_their_pixels() {
+    let mut builder = AtlasBuilder::new(16);
+    builder
+        .add("red", Texture::solid(4, 2, RED))
+        .add("green", Texture::solid(6, 6, GREEN))
+        .add("blue", Texture::solid(8, 3, BLUE));
+    let atlas = builder.build().unwrap();
+    assert_eq!(atlas.len(), 3);
+    assert_eq!(atlas.index_of("blue"), Some(2));
+    assert_eq!(atlas.index_of("missing"), None);
+
+    let regions: Vec<AtlasRegion> = (0..3).map(|i| atlas.region(i).unwrap()).collect();
+    for (i, a) in regions.iter().enumerate() {
+        assert!(a.x + a.width < atlas.texture().width());
+        for b in &regions[i + 1..] {
+            assert!(!overlaps(*a, *b), "{a:?} overlaps {b:?}");
+        }
+    }
+
+    for (name, color) in [("red", RED), ("green", GREEN), ("blue", BLUE)] {
+        let region = atlas.region_named(name).unwrap();
+        let uv = atlas.uv_named(name).unwrap();
+        for &corner in &[Vec2::ZERO, Vec2::ONE, Vec2::splat(0.5)] {
+            assert_eq!(atlas.texture().sample(uv.map(corner)), color, "{name}");
+        }
+        // The padding repeats the edge
+        let padded = atlas.texture().pixel(region.x - 1, region.y - 1);
+        assert_eq!(padded, Some(color));
+    }
+}
+
+#[test]
+fn shelves_wrap_at_the_maximum_width() {
+    let mut builder = AtlasBuilder::new(8).with_padding(0);
+    for i in 0..5 {
+        builder.add(format!("tile{i}"), Texture::solid(4, 4, RED));
+    }
+    let atlas = builder.build().unwrap();
+    assert_eq!((atlas.texture().width(), atlas.texture().height()), (8, 12));
+    assert_eq!(
+        atlas.region(4),
+        Some(AtlasRegion {
+            x: 0,
+            y: 8,
+            width: 4,
+            height: 4
+        })
+    );
+}
+
+#[test]
+fn builder_rejects_bad_input() {
+    let mut builder = AtlasBuilder::new(8);
+    builder
+        .add("a", Texture::solid(2, 2, RED))
+        .add("a", Texture::solid(2, 2, RED));
+    assert_eq!(
+        builder.build(),
+        Err(AtlasError::DuplicateName("a".to_string()))
+    );
+
+    let mut builder = AtlasBuilder::new(8);
+    builder.add("wide", Texture::solid(7, 1, RED));
+    assert_eq!(
+        builder.build(),
+        Err(AtlasError::TooWide("wide".to_string()))
+    );
+}
+
+#[test]
+fn grid_slices_sprite_sheets() {
+    let atlas = TextureAtlas::from_grid(Texture::solid(8, 4, RED), 4, 2);
+    assert_eq!(atlas.len(), 8);
+    let uv = atlas.uv(5).unwrap();
+    assert_eq!(uv.min, Vec2::new(0.25, 0.5));
+    assert_eq!(uv.max, Vec2::new(0.5, 1.0));
+    assert_eq!(atlas.uv(8), None);
+}
+
+#[test]
+fn textured_command_draws_its_region() {
+    // Left half red, right half blue
+    let pixels = (0..16)
+        .map(|i| if i % 4 < 2 { RED } else { BLUE })
+        .collect();
+    let mut textures = Textures::new();
+    let texture = textures.add(Texture::new(4, 4, pixels).unwrap());
+    assert_eq!(textures.len(), 1);
+
+    let mut command = RenderCommand::new(Transform::IDENTITY, Meshes::QUAD);
+    command.transform.scale = Vec3::splat(8.0);
+    command.texture = Some(texture);
+    command.uv = UvRect::new(Vec2::new(0.5, 0.0), Vec2::ONE);
+
+    let view = RenderView::new(
+        &Camera::orthographic(8.0),
+        view_matrix(&Transform::IDENTITY),
+        &[command],
+        &Materials::new(),
+    );
+    let mut renderer = SoftwareRenderer::new(8, 8);
+    let framebuffer = renderer.render_views([&view], &Meshes::new(), &textures, &Materials::new());
+    assert!(framebuffer.pixels().iter().all(|&p| p == BLUE));
+
+    // A missing texture falls back to the tint
+    let framebuffer = renderer.render(
+        &[command],
+        &Meshes::new(),
+        &Textures::new(),
+        &Materials::new(),
+    );
+    assert_eq!(framebuffer.pixel(4, 4), Some([255; 4]));
+}
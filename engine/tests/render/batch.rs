@@ -0,0 +1,199 @@
+use pyreframe_engine::math::{Mat4, Quat, Vec2, Vec3};
+use pyreframe_engine::render::{
+    Camera, DrawBatch, FrameStats, MaterialId, Materials, Mesh, MeshId, Meshes, RenderCommand,
+    RenderPass, RenderView, SoftwareRenderer, Textures, Transform, build_batches, sort_for_view,
+    view_matrix,
+};
+
+fn command(mesh: usize, layer: i32, z: f32, alpha: u8) -> RenderCommand {
+    let mut command = RenderCommand::new(
+        Transform::from_translation(Vec3::new(0.0, 0.0, z)),
+        MeshId(mesh),
+    );
+    command.layer = layer;
+    command.sort_key = RenderCommand::sort_key_for(layer, Materials::DEFAULT, MeshId(mesh));
+    command.color[3] = alpha;
+    command
+}
+
+#[test]
+fn pass_follows_alpha() {
+    assert_eq!(
+        RenderPass::of(&command(0, 0, 0.0, 255), &Materials::new()),
+        RenderPass::Opaque
+    );
+    assert_eq!(
+        RenderPass::of(&command(0, 0, 0.0, 254), &Materials::new()),
+        RenderPass::Transparent
+    );
+}
+
+#[test]
+fn layers_then_opaque_then_transparent_back_to_front() {
+    let mut commands = vec![
+        command(0, 1, 0.0, 255),
+        command(1, 0, 5.0, 128),
+        command(1, 0, 0.0, 255),
+        command(0, 0, -3.0, 128),
+        command(0, 0, 0.0, 255),
+        command(1, 0, 2.0, 128),
+    ];
+    sort_for_view(&mut commands, Mat4::IDENTITY, &Materials::new());
+
+    let order: Vec<(i32, usize, f32, bool)> = commands
+        .iter()
+        .map(|c| {
+            (
+                c.layer,
+                c.mesh.0,
+                c.transform.translation.z,
+                RenderPass::of(c, &Materials::new()) == RenderPass::Transparent,
+            )
+        })
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            (0, 0, 0.0, false),
+            (0, 1, 0.0, false),
+            (0, 0, -3.0, true),
+            (0, 1, 2.0, true),
+            (0, 1, 5.0, true),
+            (1, 0, 0.0, false),
+        ]
+    );
+}
+
+#[test]
+fn transparent_depth_uses_the_view() {
+    // The farthest quad is drawn first: z = 1 for a camera at z = 10 looking
+    // down -z, z = 4 for one at z = -10 turned around
+    let drawn_first = |view: Mat4| {
+        let mut commands = vec![command(0, 0, 1.0, 100), command(0, 0, 4.0, 100)];
+        sort_for_view(&mut commands, view, &Materials::new());
+        commands[0].transform.translation.z
+    };
+    let facing_back = Transform {
+        rotation: Quat::from_rotation_y(std::f32::consts::PI),
+        ..Transform::from_translation(Vec3::new(0.0, 0.0, -10.0))
+    };
+    assert_eq!(
+        drawn_first(view_matrix(&Transform::from_translation(Vec3::new(
+            0.0, 0.0, 10.0
+        )))),
+        1.0
+    );
+    assert_eq!(drawn_first(view_matrix(&facing_back)), 4.0);
+}
+
+#[test]
+fn consecutive_commands_share_a_batch() {
+    let commands = vec![
+        command(0, 0, 0.0, 255),
+        command(0, 0, 1.0, 255),
+        command(1, 0, 0.0, 255),
+        command(1, 0, 0.0, 100),
+        command(1, 0, 1.0, 100),
+        command(1, 1, 0.0, 100),
+    ];
+    let batches = build_batches(&commands, &Materials::new());
+    let summary: Vec<(usize, i32, RenderPass, std::ops::Range<usize>)> = batches
+        .iter()
+        .map(|b| (b.mesh.0, b.layer, b.pass, b.instances.clone()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (0, 0, RenderPass::Opaque, 0..2),
+            (1, 0, RenderPass::Opaque, 2..3),
+            (1, 0, RenderPass::Transparent, 3..5),
+            (1, 1, RenderPass::Transparent, 5..6),
+        ]
+    );
+    assert!(build_batches(&[], &Materials::new()).is_empty());
+}
+
+#[test]
+fn ids_sharing_sort_key_bits_stay_grouped() {
+    let materials = Materials::new();
+    let (low, high) = (MaterialId(1), MaterialId(65_537));
+    assert_eq!(
+        RenderCommand::sort_key_for(0, low, MeshId(0)),
+        RenderCommand::sort_key_for(0, high, MeshId(0))
+    );
+    let mut commands: Vec<RenderCommand> = [high, low, high, low]
+        .into_iter()
+        .map(|material| {
+            let mut command = command(0, 0, 0.0, 255);
+            command.material = material;
+            command.sort_key = RenderCommand::sort_key_for(0, material, MeshId(0));
+            command
+        })
+        .collect();
+
+    sort_for_view(&mut commands, Mat4::IDENTITY, &materials);
+    let order: Vec<MaterialId> = commands.iter().map(|command| command.material).collect();
+    assert_eq!(order, vec![low, low, high, high]);
+    assert_eq!(build_batches(&commands, &materials).len(), 2);
+}
+
+#[test]
+fn stats_count_draws_batches_and_triangles() {
+    let mut meshes = Meshes::new();
+    let cube = meshes.add(Mesh::cube(1.0));
+
+    let batch = |mesh: usize, instances: std::ops::Range<usize>| DrawBatch {
+        mesh: MeshId(mesh),
+        material: Materials::DEFAULT,
+        texture: None,
+        layer: 0,
+        pass: RenderPass::Opaque,
+        instances,
+    };
+    let stats = FrameStats::for_batches(
+        &[batch(0, 0..3), batch(cube.0, 3..4), batch(9, 4..6)],
+        &meshes,
+    );
+    assert_eq!(
+        stats,
+        FrameStats {
+            draw_calls: 2,
+            batches: 1,
+            instances: 4,
+            triangles: 3 * 2 + 12,
+        }
+    );
+
+    let mut total = stats;
+    total += stats;
+    assert_eq!(total.triangles, 36);
+}
+
+#[test]
+fn translucent_quads_blend_in_depth_order() {
+    let mut meshes = Meshes::new();
+    let quad = meshes.add(Mesh::quad(Vec2::splat(4.0)));
+    let translucent = |z: f32, color: [u8; 4]| {
+        let mut command =
+            RenderCommand::new(Transform::from_translation(Vec3::new(0.0, 0.0, z)), quad);
+        command.color = color;
+        command
+    };
+    let red_front = translucent(1.0, [255, 0, 0, 128]);
+    let blue_back = translucent(-1.0, [0, 0, 255, 128]);
+
+    let camera = Camera::orthographic(8.0);
+    let render = |commands: &[RenderCommand]| {
+        let view = RenderView::new(&camera, Mat4::IDENTITY, commands, &Materials::new());
+        let mut renderer = SoftwareRenderer::new(8, 8);
+        renderer
+            .render_views([&view], &meshes, &Textures::new(), &Materials::new())
+            .pixel(4, 4)
+            .unwrap()
+    };
+
+    // Submission order does not matter; red is nearer and ends on top
+    let pixel = render(&[red_front, blue_back]);
+    assert_eq!(pixel, render(&[blue_back, red_front]));
+    assert!(pixel[0] > pixel[2]);
+}
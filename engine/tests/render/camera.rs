@@ -0,0 +1,198 @@
+use pyreframe_engine::math::{Mat4, Quat, Vec2, Vec3};
+use pyreframe_engine::render::{
+    Camera, LayerMask, Materials, Meshes, Projection, RenderCommand, RenderTarget, RenderView,
+    SoftwareRenderer, Textures, Transform, Viewport, view_matrix,
+};
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+fn assert_vec3_near(actual: Vec3, expected: Vec3) {
+    assert!(
+        (actual - expected).length() < 1e-4,
+        "{actual:?} != {expected:?}"
+    );
+}
+
+fn view(camera: Camera, position: Vec3, commands: Vec<RenderCommand>) -> RenderView {
+    RenderView::new(
+        &camera,
+        view_matrix(&Transform::from_translation(position)),
+        &commands,
+        &Materials::new(),
+    )
+}
+
+#[test]
+fn orthographic_width_follows_aspect() {
+    let projection = Projection::Orthographic {
+        height: 10.0,
+        near: -1.0,
+        far: 1.0,
+    };
+    let matrix = projection.matrix(2.0);
+    assert_vec3_near(
+        matrix.project_point3(Vec3::new(10.0, 5.0, 0.0)),
+        Vec3::new(1.0, 1.0, 0.0),
+    );
+    assert_eq!(
+        Projection::Perspective {
+            fov_y: 1.0,
+            near: 0.1,
+            far: 100.0
+        }
+        .matrix(1.5),
+        Mat4::perspective_rh(1.0, 1.5, 0.1, 100.0)
+    );
+}
+
+#[test]
+fn split_viewports_share_edges() {
+    let left = Viewport::new(0.0, 0.0, 0.5, 1.0);
+    let right = Viewport::new(0.5, 0.0, 0.5, 1.0);
+    assert_eq!(left.to_pixels(101, 50), (0, 0, 51, 50));
+    assert_eq!(right.to_pixels(101, 50), (51, 0, 50, 50));
+    assert_eq!(Viewport::FULL.to_pixels(8, 4), (0, 0, 8, 4));
+    assert_eq!(left.aspect(100, 50), 1.0);
+    assert_eq!(Viewport::new(0.0, 0.0, 0.0, 0.0).aspect(100, 50), 1.0);
+}
+
+#[test]
+fn layer_masks_select_layers() {
+    let mask = LayerMask::only(1).with(3);
+    assert!(mask.contains(1));
+    assert!(mask.contains(3));
+    assert!(!mask.contains(0));
+    assert!(!mask.contains(-1));
+    assert!(!mask.contains(40));
+    assert!(LayerMask::ALL.contains(-5));
+    assert!(LayerMask::ALL.contains(100));
+    assert!(!LayerMask::NONE.contains(0));
+    assert_eq!(LayerMask::default(), LayerMask::ALL);
+
+    // Layers a mask cannot hold are ignored rather than shifting out of range
+    assert_eq!(LayerMask::only(32), LayerMask::NONE);
+    assert_eq!(LayerMask::only(-1), LayerMask::NONE);
+    assert_eq!(mask.with(-1).with(i32::MAX), mask);
+    assert!(LayerMask::only(31).contains(31));
+}
+
+#[test]
+fn view_matrix_inverts_camera_placement() {
+    let transform = Transform {
+        translation: Vec3::new(3.0, -2.0, 5.0),
+        rotation: Quat::from_rotation_y(0.7),
+        scale: Vec3::ONE,
+    };
+    let view = view_matrix(&transform);
+    assert_vec3_near(view.project_point3(transform.translation), Vec3::ZERO);
+    let ahead = transform.transform_point(Vec3::new(0.0, 0.0, -4.0));
+    assert_vec3_near(view.project_point3(ahead), Vec3::new(0.0, 0.0, -4.0));
+}
+
+#[test]
+fn screen_to_world_uses_the_viewport() {
+    let camera = Camera::orthographic(10.0).with_viewport(Viewport::new(0.5, 0.0, 0.5, 1.0));
+    let view = view(camera, Vec3::new(100.0, 0.0, 0.0), Vec::new());
+
+    // Center of the right half of a 200x100 target
+    let center = view
+        .screen_to_world(Vec2::new(150.0, 50.0), 200, 100)
+        .unwrap();
+    assert!((center.x - 100.0).abs() < 1e-3);
+    assert!(center.y.abs() < 1e-3);
+
+    let corner = view
+        .screen_to_world(Vec2::new(100.0, 0.0), 200, 100)
+        .unwrap();
+    assert!((corner.x - 95.0).abs() < 1e-3);
+    assert!((corner.y - 5.0).abs() < 1e-3);
+}
+
+#[test]
+fn screen_to_world_works_for_pixel_sized_cameras() {
+    let view = view(Camera::orthographic(600.0), Vec3::ZERO, Vec::new());
+    let corner = view
+        .screen_to_world(Vec2::new(800.0, 600.0), 800, 600)
+        .unwrap();
+    assert!((corner.x - 400.0).abs() < 1e-2);
+    assert!((corner.y + 300.0).abs() < 1e-2);
+}
+
+#[test]
+fn camera_defaults_and_builders() {
+    let camera = Camera::orthographic(4.0)
+        .with_target(RenderTarget::Offscreen(2))
+        .with_layers(LayerMask::only(0))
+        .with_order(3)
+        .with_clear_color(None);
+    assert!(camera.active);
+    assert_eq!(camera.viewport, Viewport::FULL);
+    assert_eq!(camera.target, RenderTarget::Offscreen(2));
+    assert_eq!(camera.order, 3);
+    assert_eq!(camera.clear_color, None);
+    assert_eq!(Camera::perspective(1.0, 0.1, 10.0).clear_color, Some(BLACK));
+}
+
+#[test]
+fn split_screen_draws_each_view_in_its_half() {
+    let meshes = Meshes::new();
+    let mut red = RenderCommand::new(
+        Transform::from_translation(Vec3::new(-10.0, 0.0, 0.0)),
+        Meshes::QUAD,
+    );
+    red.color = RED;
+    red.transform.scale = Vec3::splat(4.0);
+    let mut green = RenderCommand::new(
+        Transform::from_translation(Vec3::new(10.0, 0.0, 0.0)),
+        Meshes::QUAD,
+    );
+    green.color = GREEN;
+    green.transform.scale = Vec3::splat(4.0);
+    let commands = vec![red, green];
+
+    // Each player's camera follows their own quad
+    let left = Camera::orthographic(8.0).with_viewport(Viewport::new(0.0, 0.0, 0.5, 1.0));
+    let right = Camera::orthographic(8.0).with_viewport(Viewport::new(0.5, 0.0, 0.5, 1.0));
+    let views = [
+        view(left, Vec3::new(-10.0, 0.0, 0.0), commands.clone()),
+        view(right, Vec3::new(10.0, 0.0, 0.0), commands),
+    ];
+
+    let mut renderer = SoftwareRenderer::new(16, 8);
+    let framebuffer = renderer.render_views(&views, &meshes, &Textures::new(), &Materials::new());
+
+    // Each quad fills the middle half of its view and nothing leaks across
+    assert_eq!(framebuffer.pixel(4, 4), Some(RED));
+    assert_eq!(framebuffer.pixel(12, 4), Some(GREEN));
+    assert_eq!(framebuffer.pixel(0, 4), Some(BLACK));
+    assert_eq!(framebuffer.pixel(8, 4), Some(BLACK));
+    for y in 0..8 {
+        for x in 0..16 {
+            let pixel = framebuffer.pixel(x, y).unwrap();
+            assert!(pixel != GREEN || x >= 8, "green at ({x}, {y})");
+            assert!(pixel != RED || x < 8, "red at ({x}, {y})");
+        }
+    }
+}
+
+#[test]
+fn overlay_view_without_clear_keeps_what_is_below() {
+    let meshes = Meshes::new();
+    let mut command = RenderCommand::new(Transform::IDENTITY, Meshes::QUAD);
+    command.color = RED;
+    command.transform.scale = Vec3::splat(2.0);
+
+    let base = Camera::orthographic(8.0).with_clear_color(Some(GREEN));
+    let overlay = Camera::orthographic(8.0).with_clear_color(None);
+    let views = [
+        view(base, Vec3::ZERO, Vec::new()),
+        view(overlay, Vec3::ZERO, vec![command]),
+    ];
+
+    let mut renderer = SoftwareRenderer::new(8, 8);
+    let framebuffer = renderer.render_views(&views, &meshes, &Textures::new(), &Materials::new());
+    assert_eq!(framebuffer.pixel(4, 4), Some(RED));
+    assert_eq!(framebuffer.pixel(0, 0), Some(GREEN));
+}
@@ -0,0 +1,128 @@
+use pyreframe_engine::ecs::components::Color;
+use pyreframe_engine::math::Vec4;
+use pyreframe_engine::render::{BlendMode, Hsl, Hsv, LinearColor, linear_to_srgb, srgb_to_linear};
+
+fn assert_close(actual: LinearColor, expected: LinearColor) {
+    let (a, e) = (actual.to_array(), expected.to_array());
+    assert!(
+        a.iter().zip(e).all(|(a, e)| (a - e).abs() < 1e-4),
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn srgb_transfer_matches_reference_points() {
+    assert_eq!(srgb_to_linear(0.0), 0.0);
+    assert!((srgb_to_linear(1.0) - 1.0).abs() < 1e-6);
+    assert!((srgb_to_linear(0.5) - 0.214_041).abs() < 1e-5);
+    assert!((linear_to_srgb(0.214_041) - 0.5).abs() < 1e-5);
+    // Linear segment near black
+    assert!((srgb_to_linear(0.04) - 0.04 / 12.92).abs() < 1e-7);
+}
+
+#[test]
+fn every_byte_survives_a_round_trip_through_linear() {
+    for value in 0..=255u8 {
+        let color = Color::rgba(value, value, value, value);
+        assert_eq!(Color::from(color.to_linear()), color);
+    }
+}
+
+#[test]
+fn converting_to_color_clamps_hdr_values() {
+    let bright = LinearColor::rgb(4.0, 0.5, -1.0);
+    assert!(bright.is_hdr());
+    assert_eq!(Color::from(bright), Color::rgb(255, 188, 0));
+    assert!(!LinearColor::WHITE.is_hdr());
+    assert_close(
+        LinearColor::WHITE.scaled(2.0),
+        LinearColor::rgb(2.0, 2.0, 2.0),
+    );
+}
+
+#[test]
+fn palette_constants_and_array_layout() {
+    assert_eq!(Color::default(), Color::WHITE);
+    assert_eq!(Color::ORANGE.to_array(), [255, 165, 0, 255]);
+    assert_eq!(Color::from([1, 2, 3, 4]), Color::rgba(1, 2, 3, 4));
+    assert_eq!(<[u8; 4]>::from(Color::RED.with_alpha(9)), [255, 0, 0, 9]);
+    assert_close(Color::BLACK.to_linear(), LinearColor::BLACK);
+    assert_close(Color::TRANSPARENT.to_linear(), LinearColor::TRANSPARENT);
+    assert_eq!(
+        Vec4::from(LinearColor::new(0.1, 0.2, 0.3, 0.4)),
+        Vec4::new(0.1, 0.2, 0.3, 0.4)
+    );
+}
+
+#[test]
+fn hsv_round_trips_primaries_and_mixes() {
+    assert_eq!(Hsv::from(Color::RED), Hsv::new(0.0, 1.0, 1.0));
+    assert_eq!(Hsv::from(Color::CYAN), Hsv::new(180.0, 1.0, 1.0));
+    assert_eq!(Color::from(Hsv::new(120.0, 1.0, 1.0)), Color::GREEN);
+    assert_eq!(Color::from(Hsv::new(-120.0, 1.0, 1.0)), Color::BLUE);
+    assert_eq!(Color::from(Hsv::new(0.0, 0.0, 0.5)), Color::GRAY);
+    for color in [Color::ORANGE, Color::PURPLE, Color::rgba(12, 200, 99, 40)] {
+        assert_eq!(Color::from(Hsv::from(color)), color);
+    }
+}
+
+#[test]
+fn hsl_round_trips_primaries_and_mixes() {
+    assert_eq!(Hsl::from(Color::YELLOW), Hsl::new(60.0, 1.0, 0.5));
+    assert_eq!(Hsl::from(Color::WHITE), Hsl::new(0.0, 0.0, 1.0));
+    assert_eq!(Color::from(Hsl::new(300.0, 1.0, 0.5)), Color::MAGENTA);
+    assert_eq!(Color::from(Hsl::new(0.0, 1.0, 1.0)), Color::WHITE);
+    for color in [Color::ORANGE, Color::PURPLE, Color::rgba(12, 200, 99, 40)] {
+        assert_eq!(Color::from(Hsl::from(color)), color);
+    }
+}
+
+#[test]
+fn premultiplied_alpha_round_trips() {
+    let color = LinearColor::new(0.8, 0.4, 0.2, 0.5);
+    assert_close(color.premultiplied(), LinearColor::new(0.4, 0.2, 0.1, 0.5));
+    assert_close(color.premultiplied().unpremultiplied(), color);
+    assert_eq!(
+        LinearColor::new(1.0, 1.0, 1.0, 0.0).unpremultiplied(),
+        LinearColor::TRANSPARENT
+    );
+}
+
+#[test]
+fn blend_modes_combine_source_and_destination() {
+    let dst = LinearColor::rgb(0.5, 0.5, 0.5);
+    let src = LinearColor::new(1.0, 0.0, 0.2, 0.5);
+    assert_close(
+        src.blend(dst, BlendMode::Opaque),
+        LinearColor::rgb(1.0, 0.0, 0.2),
+    );
+    assert_close(
+        src.blend(dst, BlendMode::Alpha),
+        LinearColor::rgb(0.75, 0.25, 0.35),
+    );
+    assert_close(
+        src.blend(dst, BlendMode::Additive),
+        LinearColor::rgb(1.0, 0.5, 0.6),
+    );
+    assert_close(
+        src.blend(dst, BlendMode::Multiply),
+        LinearColor::rgb(0.5, 0.25, 0.3),
+    );
+    // Blending over nothing keeps the source
+    assert_close(src.blend(LinearColor::TRANSPARENT, BlendMode::Alpha), src);
+
+    // Linear blending is brighter than the framebuffer's sRGB blending
+    let half_white = LinearColor::WHITE.with_alpha(0.5);
+    let blended = Color::from(half_white.blend(LinearColor::BLACK, BlendMode::Alpha));
+    assert_eq!(blended, Color::rgb(188, 188, 188));
+}
+
+#[test]
+fn lerp_and_luminance() {
+    let mid = LinearColor::BLACK.lerp(LinearColor::WHITE.with_alpha(0.0), 0.25);
+    assert_close(mid, LinearColor::new(0.25, 0.25, 0.25, 0.75));
+    assert!((LinearColor::WHITE.luminance() - 1.0).abs() < 1e-6);
+    assert!(
+        LinearColor::rgb(0.0, 1.0, 0.0).luminance() > LinearColor::rgb(1.0, 0.0, 0.0).luminance()
+    );
+}
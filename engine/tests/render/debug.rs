@@ -0,0 +1,143 @@
+use pyreframe_engine::Engine;
+use pyreframe_engine::World;
+use pyreframe_engine::input::Input;
+use pyreframe_engine::math::{Mat4, Vec3};
+use pyreframe_engine::render::{
+    DebugDraw, DebugOverlay, DebugShape, Materials, Meshes, RenderCommand, SoftwareRenderer,
+    Textures, Transform,
+};
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+const WHITE: [u8; 4] = [255; 4];
+
+#[test]
+fn shapes_break_down_into_lines_and_labels() {
+    let mut debug = DebugDraw::new();
+    debug.line(Vec3::ZERO, Vec3::X, RED);
+    debug.aabb(Vec3::ZERO, Vec3::ONE, RED);
+    debug.circle(Vec3::new(1.0, 2.0, 0.0), 3.0, RED);
+    debug.arrow(Vec3::ZERO, Vec3::new(4.0, 0.0, 0.0), GREEN);
+    debug.label(Vec3::Y, "hp 10", WHITE);
+    assert_eq!(debug.len(), 5);
+
+    let overlay = debug.flush(1.0 / 60.0);
+    assert_eq!(overlay.lines.len(), 1 + 12 + 24 + 3);
+    assert_eq!(overlay.labels.len(), 1);
+    assert_eq!(overlay.labels[0].text, "hp 10");
+
+    // Box edges are axis-aligned and one unit long
+    for line in &overlay.lines[1..13] {
+        let edge = (line.end - line.start).abs();
+        assert_eq!(edge.x + edge.y + edge.z, 1.0);
+    }
+    for line in &overlay.lines[13..37] {
+        let distance = (line.start - Vec3::new(1.0, 2.0, 0.0)).length();
+        assert!((distance - 3.0).abs() < 1e-5);
+        assert_eq!(line.start.z, 0.0);
+    }
+    // The arrow head spreads in the xy plane, back from the tip
+    let head = &overlay.lines[38..];
+    assert!(
+        head.iter()
+            .all(|line| line.start == Vec3::new(4.0, 0.0, 0.0))
+    );
+    assert!(
+        head.iter()
+            .all(|line| line.end.x == 3.0 && line.end.z == 0.0)
+    );
+    assert_eq!(head[0].end.y, -head[1].end.y);
+    assert!(head.iter().all(|line| line.color == GREEN));
+}
+
+#[test]
+fn shapes_last_for_their_duration() {
+    let mut debug = DebugDraw::new();
+    debug.add(
+        DebugShape::Line {
+            start: Vec3::ZERO,
+            end: Vec3::Y,
+        },
+        RED,
+        0.5,
+    );
+    debug.line(Vec3::ZERO, Vec3::X, GREEN);
+
+    assert_eq!(debug.flush(0.25).lines.len(), 2);
+    assert_eq!(debug.flush(0.25).lines.len(), 1);
+    assert!(debug.flush(0.25).is_empty());
+    assert!(debug.is_empty());
+}
+
+#[test]
+fn disabled_debug_draw_queues_nothing() {
+    let mut debug = DebugDraw::new();
+    debug.label(Vec3::ZERO, "kept", WHITE);
+    debug.set_enabled(false);
+    assert!(!debug.is_enabled());
+    assert!(debug.is_empty());
+    debug.line(Vec3::ZERO, Vec3::X, RED);
+    assert!(debug.flush(0.1).is_empty());
+
+    debug.set_enabled(true);
+    debug.line(Vec3::ZERO, Vec3::X, RED);
+    assert_eq!(debug.flush(0.1).lines.len(), 1);
+}
+
+fn draw_gizmos(world: &mut World) {
+    let debug = world.get_resource_mut::<DebugDraw>().unwrap();
+    debug.circle(Vec3::ZERO, 1.0, GREEN);
+}
+
+#[test]
+fn engine_flushes_debug_draw_into_frame_output() {
+    let mut engine = Engine::new();
+    engine.schedule_mut().add_system(draw_gizmos);
+    engine.world_mut().insert_resource(DebugDraw::new());
+
+    let output = engine.tick(Input::default(), 1.0 / 60.0);
+    assert_eq!(output.debug.lines.len(), 24);
+    // One-frame shapes are gone once the frame is flushed
+    assert!(
+        engine
+            .world()
+            .get_resource::<DebugDraw>()
+            .unwrap()
+            .is_empty()
+    );
+
+    let mut plain = Engine::new();
+    assert_eq!(
+        plain.tick(Input::default(), 1.0 / 60.0).debug,
+        DebugOverlay::default()
+    );
+}
+
+#[test]
+fn overlay_is_drawn_over_geometry() {
+    let mut renderer = SoftwareRenderer::new(32, 32);
+    renderer.set_view_projection(Mat4::orthographic_rh(-8.0, 8.0, -8.0, 8.0, -10.0, 10.0));
+    let mut quad = RenderCommand::new(
+        Transform {
+            translation: Vec3::new(0.0, 0.0, 5.0),
+            scale: Vec3::splat(16.0),
+            ..Transform::IDENTITY
+        },
+        Meshes::QUAD,
+    );
+    quad.color = [0, 0, 255, 255];
+    renderer.render(&[quad], &Meshes::new(), &Textures::new(), &Materials::new());
+
+    let mut debug = DebugDraw::new();
+    // Behind the quad, yet still drawn on top
+    debug.line(Vec3::new(-4.0, 0.0, -5.0), Vec3::new(4.0, 0.0, -5.0), RED);
+    debug.label(Vec3::new(-8.0, 8.0, 0.0), "T", WHITE);
+    renderer.draw_debug(&debug.flush(0.0));
+
+    let framebuffer = renderer.framebuffer();
+    assert_eq!(framebuffer.pixel(16, 16), Some(RED));
+    assert_eq!(framebuffer.pixel(16, 8), Some([0, 0, 255, 255]));
+    // The top row of 'T' spans five pixels from the label's corner
+    assert!((0..5).all(|x| framebuffer.pixel(x, 0) == Some(WHITE)));
+    assert_eq!(framebuffer.pixel(5, 0), Some([0, 0, 255, 255]));
+}
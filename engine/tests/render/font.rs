@@ -0,0 +1,151 @@
+use pyreframe_engine::math::Vec2;
+use pyreframe_engine::render::{BitmapFont, FontError, Fonts, TextAlign, TextureId};
+
+const FNT: &str = r#"info face="Pixel Sans" size=8 bold=0 padding=0,0,0,0
+common lineHeight=10 base=8 scaleW=64 scaleH=32 pages=1
+page id=0 file="pixel sans.png"
+chars count=3
+char id=65 x=0 y=0 width=6 height=8 xoffset=0 yoffset=1 xadvance=7 page=0
+char id=86 x=8 y=0 width=6 height=8 xoffset=0 yoffset=1 xadvance=7 page=0
+char id=32 x=0 y=0 width=0 height=0 xoffset=0 yoffset=0 xadvance=4 page=0
+kernings count=1
+kerning first=65 second=86 amount=-2
+"#;
+
+fn builtin() -> BitmapFont {
+    BitmapFont::builtin(TextureId(0))
+}
+
+#[test]
+fn builtin_font_covers_printable_ascii() {
+    let font = builtin();
+    assert!((' '..='~').all(|ch| font.glyph(ch).is_some()));
+    assert!(font.glyph('\u{e9}').is_none());
+    assert_eq!(font.line_height(), 8.0);
+    assert_eq!(font.glyph('A').unwrap().advance, 6.0);
+
+    let texture = BitmapFont::builtin_texture();
+    assert_eq!((texture.width(), texture.height()), (96, 48));
+    // A space is empty; the top row of 'T' is fully lit
+    let region = font.glyph('T').unwrap().region;
+    let pixel = |x: usize, y: usize| texture.pixels()[y * texture.width() + x];
+    assert!((0..5).all(|x| pixel(region.x + x, region.y) == [255; 4]));
+    let space = font.glyph(' ').unwrap().region;
+    assert!((0..5).all(|x| (0..7).all(|y| pixel(space.x + x, space.y + y) == [0; 4])));
+}
+
+#[test]
+fn layout_places_glyphs_and_skips_whitespace() {
+    let font = builtin();
+    let layout = font.layout("Hi there", TextAlign::Left, None);
+    assert_eq!(layout.lines, 1);
+    assert_eq!(layout.width, 48.0);
+    assert_eq!(layout.height, 8.0);
+    assert_eq!(layout.glyphs.len(), 7);
+    assert_eq!(layout.glyphs[0].ch, 'H');
+    assert_eq!(layout.glyphs[0].min, Vec2::ZERO);
+    assert_eq!(layout.glyphs[0].size, Vec2::new(5.0, 7.0));
+    assert_eq!(layout.glyphs[2].ch, 't');
+    assert_eq!(layout.glyphs[2].min, Vec2::new(18.0, 0.0));
+    assert_eq!(font.measure("Hi there"), 48.0);
+}
+
+#[test]
+fn alignment_shifts_each_line() {
+    let font = builtin();
+    let centered = font.layout("ab\nabcd", TextAlign::Center, None);
+    assert_eq!(centered.lines, 2);
+    assert_eq!(centered.glyphs[0].min, Vec2::new(-6.0, 0.0));
+    assert_eq!(centered.glyphs[2].min, Vec2::new(-12.0, 8.0));
+
+    let right = font.layout("ab", TextAlign::Right, None);
+    assert_eq!(right.glyphs[1].min.x, -6.0);
+}
+
+#[test]
+fn long_lines_wrap_between_words() {
+    let font = builtin();
+    let layout = font.layout("one two three", TextAlign::Left, Some(48.0));
+    assert_eq!(layout.lines, 2);
+    assert_eq!(layout.width, 42.0);
+    let second_line: String = layout
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.min.y == 8.0)
+        .map(|glyph| glyph.ch)
+        .collect();
+    assert_eq!(second_line, "three");
+
+    // A word wider than the line is broken between characters
+    let broken = font.layout("abcdefgh", TextAlign::Left, Some(24.0));
+    assert_eq!(broken.lines, 2);
+    assert_eq!(broken.glyphs[4].min, Vec2::new(0.0, 8.0));
+}
+
+#[test]
+fn missing_characters_fall_back_to_question_mark() {
+    let font = builtin();
+    let layout = font.layout("\u{e9}", TextAlign::Left, None);
+    assert_eq!(layout.glyphs.len(), 1);
+    assert_eq!(layout.glyphs[0].ch, '\u{e9}');
+    assert_eq!(
+        layout.glyphs[0].uv,
+        font.layout("?", TextAlign::Left, None).glyphs[0].uv
+    );
+}
+
+#[test]
+fn bmfont_descriptor_is_parsed() {
+    let font = BitmapFont::parse_bmfont(FNT, TextureId(4)).unwrap();
+    assert_eq!(font.texture(), TextureId(4));
+    assert_eq!(font.line_height(), 10.0);
+    assert_eq!(font.base(), 8.0);
+    let glyph = font.glyph('V').unwrap();
+    assert_eq!((glyph.region.x, glyph.region.width), (8, 6));
+    assert_eq!(glyph.offset, Vec2::new(0.0, 1.0));
+    assert_eq!(font.kerning('A', 'V'), -2.0);
+    assert_eq!(font.kerning('V', 'A'), 0.0);
+
+    let layout = font.layout("AV", TextAlign::Left, None);
+    assert_eq!(layout.width, 12.0);
+    assert_eq!(layout.glyphs[1].min, Vec2::new(5.0, 1.0));
+    assert_eq!(layout.glyphs[1].uv.min, Vec2::new(0.125, 0.0));
+    assert_eq!(layout.glyphs[1].uv.max, Vec2::new(0.21875, 0.25));
+    // Without a '?' glyph unknown characters are skipped
+    assert!(font.layout("z", TextAlign::Left, None).glyphs.is_empty());
+}
+
+#[test]
+fn invalid_descriptors_are_rejected() {
+    assert_eq!(
+        BitmapFont::parse_bmfont("char id=65 x=0", TextureId(0)),
+        Err(FontError::InvalidLine { line: 1 })
+    );
+    assert_eq!(
+        BitmapFont::parse_bmfont("info size=8\ncommon lineHeight=x base=8", TextureId(0)),
+        Err(FontError::InvalidLine { line: 2 })
+    );
+    assert_eq!(
+        BitmapFont::parse_bmfont("info size=8", TextureId(0)),
+        Err(FontError::MissingCommon)
+    );
+    assert_eq!(
+        BitmapFont::parse_bmfont(
+            "common lineHeight=10 base=8 scaleW=64 scaleH=32 pages=2",
+            TextureId(0)
+        ),
+        Err(FontError::MultiplePages)
+    );
+}
+
+#[test]
+fn fonts_hand_out_ids_in_order() {
+    let mut fonts = Fonts::new();
+    assert!(fonts.is_empty());
+    let first = fonts.add(builtin());
+    let second = fonts.add(builtin());
+    assert_ne!(first, second);
+    assert!(fonts.contains(second));
+    assert_eq!(fonts.len(), 2);
+    assert_eq!(fonts.get(first), Some(&builtin()));
+}
@@ -0,0 +1,130 @@
+use pyreframe_engine::render::ImageError;
+use pyreframe_engine::render::image::{
+    decode_png, decode_ppm, encode_png, encode_ppm, load_image, save_image,
+};
+
+fn pattern(width: usize, height: usize) -> Vec<[u8; 4]> {
+    (0..width * height)
+        .map(|i| [(i * 7) as u8, (i * 13) as u8, (i * 31) as u8, (i * 3) as u8])
+        .collect()
+}
+
+#[test]
+fn ppm_round_trip_drops_alpha() {
+    let pixels = pattern(5, 3);
+    let bytes = encode_ppm(5, 3, &pixels);
+    assert!(bytes.starts_with(b"P6\n5 3\n255\n"));
+
+    let decoded = decode_ppm(&bytes).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (5, 3));
+    for (decoded, original) in decoded.pixels().iter().zip(&pixels) {
+        assert_eq!(decoded[..3], original[..3]);
+        assert_eq!(decoded[3], 255);
+    }
+}
+
+#[test]
+fn ppm_header_may_contain_comments() {
+    let bytes = b"P6\n# made by hand\n1 1\n255\n\x01\x02\x03";
+    assert_eq!(decode_ppm(bytes).unwrap().pixels(), &[[1, 2, 3, 255]]);
+    assert_eq!(
+        decode_ppm(b"P3\n1 1\n255\n"),
+        Err(ImageError::InvalidFormat)
+    );
+    assert_eq!(
+        decode_ppm(b"P6\n2 2\n255\n\x00"),
+        Err(ImageError::Truncated)
+    );
+}
+
+#[test]
+fn png_round_trip_keeps_every_channel() {
+    // Large enough to need several stored deflate blocks
+    let pixels = pattern(200, 100);
+    let bytes = encode_png(200, 100, &pixels);
+    let decoded = decode_png(&bytes).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (200, 100));
+    assert_eq!(decoded.pixels(), &pixels[..]);
+}
+
+#[test]
+fn decodes_compressed_and_filtered_png() {
+    // 32x32 RGB, every scanline filter type, dynamic Huffman, split IDAT, extra tEXt chunk
+    let bytes = include_bytes!("../fixtures/gradient_rgb_filtered.png");
+    let decoded = decode_png(bytes).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (32, 32));
+    for y in 0..32 {
+        for x in 0..32 {
+            let expected = [(x * 8) as u8, (y * 8) as u8, ((x + y) * 4) as u8, 255];
+            assert_eq!(decoded.pixel(x, y), Some(expected), "pixel {x},{y}");
+        }
+    }
+}
+
+#[test]
+fn rejects_damaged_png() {
+    let mut bytes = encode_png(2, 2, &pattern(2, 2));
+    assert_eq!(decode_png(&bytes[..20]), Err(ImageError::Truncated));
+    assert_eq!(decode_png(b"GIF89a"), Err(ImageError::InvalidFormat));
+
+    // Flip a pixel byte inside IDAT
+    let idat = bytes.windows(4).position(|w| w == b"IDAT").unwrap();
+    bytes[idat + 12] ^= 0xff;
+    assert_eq!(decode_png(&bytes), Err(ImageError::Corrupt));
+}
+
+/// Bitwise CRC-32 as used by PNG chunks.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[test]
+fn rejects_headers_too_large_to_address() {
+    assert_eq!(
+        decode_ppm(b"P6 4294967296 4294967296 255\n"),
+        Err(ImageError::TooLarge)
+    );
+    assert_eq!(
+        decode_ppm(format!("P6 {} 2 255\n", usize::MAX).as_bytes()),
+        Err(ImageError::TooLarge)
+    );
+
+    // Patch a valid PNG's IHDR to 0xFFFFFFFF x 0xFFFFFFFF and fix its CRC
+    let mut bytes = encode_png(1, 1, &pattern(1, 1));
+    let ihdr = bytes.windows(4).position(|w| w == b"IHDR").unwrap();
+    bytes[ihdr + 4..ihdr + 12].fill(0xff);
+    let crc = crc32(&bytes[ihdr..ihdr + 17]);
+    bytes[ihdr + 17..ihdr + 21].copy_from_slice(&crc.to_be_bytes());
+    assert_eq!(decode_png(&bytes), Err(ImageError::TooLarge));
+}
+
+#[test]
+fn save_and_load_pick_format() {
+    let pixels = pattern(3, 2);
+    let png = std::env::temp_dir().join("pyreframe_image_save.png");
+    let ppm = std::env::temp_dir().join("pyreframe_image_save.ppm");
+    save_image(&png, 3, 2, &pixels).unwrap();
+    save_image(&ppm, 3, 2, &pixels).unwrap();
+    let from_png = load_image(&png).unwrap();
+    let from_ppm = load_image(&ppm).unwrap();
+    std::fs::remove_file(&png).unwrap();
+    std::fs::remove_file(&ppm).unwrap();
+
+    assert_eq!(from_png.pixels(), &pixels[..]);
+    assert_eq!(from_ppm.pixel(1, 0).unwrap()[..3], pixels[1][..3]);
+    assert_eq!(
+        load_image(&png),
+        Err(ImageError::Io(std::io::ErrorKind::NotFound))
+    );
+}
@@ -0,0 +1,213 @@
+use pyreframe_engine::math::{Mat4, Vec3};
+use pyreframe_engine::render::{
+    BlendMode, Camera, Material, MaterialId, Materials, Meshes, RenderCommand, RenderPass,
+    RenderView, SoftwareRenderer, Texture, TextureId, Textures, Transform, Uniform, build_batches,
+};
+
+fn quad(material: MaterialId, color: [u8; 4]) -> RenderCommand {
+    let mut command = RenderCommand::new(
+        Transform {
+            scale: Vec3::splat(2.0),
+            ..Transform::IDENTITY
+        },
+        Meshes::QUAD,
+    );
+    command.material = material;
+    command.color = color;
+    command
+}
+
+/// Draws the commands over a grey background and returns the center pixel.
+fn center_pixel(commands: &[RenderCommand], textures: &Textures, materials: &Materials) -> [u8; 4] {
+    let mut renderer = SoftwareRenderer::new(4, 4);
+    renderer.set_view_projection(Mat4::IDENTITY);
+    renderer.set_clear_color([100, 100, 100, 255]);
+    renderer
+        .render(commands, &Meshes::new(), textures, materials)
+        .pixel(2, 2)
+        .unwrap()
+}
+
+#[test]
+fn registry_starts_with_default_and_resolves_unknown_ids() {
+    let mut materials = Materials::new();
+    assert!(materials.is_empty());
+    assert!(materials.contains(Materials::DEFAULT));
+    assert_eq!(materials.resolve(Materials::DEFAULT), &Material::new());
+
+    let glow = materials.add(Material::new().with_blend(BlendMode::Additive));
+    assert_eq!(glow, MaterialId(1));
+    assert_eq!(materials.len(), 1);
+    assert_eq!(materials.resolve(glow).blend, BlendMode::Additive);
+    assert!(!materials.contains(MaterialId(7)));
+    assert_eq!(materials.resolve(MaterialId(7)), &Material::new());
+}
+
+#[test]
+fn uniforms_are_named_and_replaceable() {
+    let mut material = Material::new()
+        .with_uniform("strength", Uniform::Float(0.5))
+        .with_uniform("mask", Uniform::Texture(TextureId(3)));
+    assert_eq!(
+        material.set_uniform("strength", Uniform::Float(2.0)),
+        Some(Uniform::Float(0.5))
+    );
+    assert_eq!(material.uniform("strength"), Some(&Uniform::Float(2.0)));
+    assert_eq!(material.uniform("missing"), None);
+    let names: Vec<&str> = material.uniforms().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["mask", "strength"]);
+}
+
+#[test]
+fn pass_follows_blend_mode() {
+    let tint = [255, 255, 255, 255];
+    assert_eq!(Material::new().pass(tint, None), RenderPass::Opaque);
+    assert_eq!(
+        Material::new().pass([255, 255, 255, 128], None),
+        RenderPass::Transparent
+    );
+    assert_eq!(
+        Material::new()
+            .with_color([255, 255, 255, 128])
+            .pass(tint, None),
+        RenderPass::Transparent
+    );
+    let opaque = Material::new().with_blend(BlendMode::Opaque);
+    assert_eq!(opaque.pass([255, 255, 255, 0], None), RenderPass::Opaque);
+    assert_eq!(
+        opaque.with_texture(TextureId(1)).pass(tint, None),
+        RenderPass::Opaque
+    );
+    let additive = Material::new().with_blend(BlendMode::Additive);
+    assert_eq!(additive.pass(tint, None), RenderPass::Transparent);
+
+    // Textures may hold transparent texels, so textured alpha blending stays transparent
+    assert_eq!(
+        Material::new().pass(tint, Some(TextureId(1))),
+        RenderPass::Transparent
+    );
+    assert_eq!(
+        Material::new().with_texture(TextureId(1)).pass(tint, None),
+        RenderPass::Transparent
+    );
+
+    let mut materials = Materials::new();
+    let glow = materials.add(additive);
+    assert_eq!(
+        RenderPass::of(&quad(glow, tint), &materials),
+        RenderPass::Transparent
+    );
+    assert_eq!(
+        RenderPass::of(&quad(Materials::DEFAULT, tint), &materials),
+        RenderPass::Opaque
+    );
+}
+
+#[test]
+fn sort_key_groups_by_material_within_a_layer() {
+    let key = RenderCommand::sort_key_for;
+    assert!(key(0, MaterialId(1), Meshes::QUAD) < key(0, MaterialId(2), Meshes::QUAD));
+    assert!(key(0, MaterialId(9), Meshes::QUAD) < key(1, MaterialId(0), Meshes::QUAD));
+}
+
+#[test]
+fn batches_split_by_material_and_use_its_texture() {
+    let mut materials = Materials::new();
+    let brick = materials.add(Material::new().with_texture(TextureId(4)));
+    let commands = [
+        quad(brick, [255; 4]),
+        quad(brick, [255; 4]),
+        quad(Materials::DEFAULT, [255; 4]),
+        {
+            let mut own = quad(brick, [255; 4]);
+            own.texture = Some(TextureId(5));
+            own
+        },
+    ];
+    let batches = build_batches(&commands, &materials);
+    let summary: Vec<(MaterialId, Option<TextureId>, usize)> = batches
+        .iter()
+        .map(|b| (b.material, b.texture, b.len()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (brick, Some(TextureId(4)), 2),
+            (Materials::DEFAULT, None, 1),
+            (brick, Some(TextureId(5)), 1),
+        ]
+    );
+}
+
+#[test]
+fn view_batches_carry_materials() {
+    let mut materials = Materials::new();
+    let glow = materials.add(Material::new().with_blend(BlendMode::Additive));
+    let commands = [quad(glow, [255; 4]), quad(Materials::DEFAULT, [255; 4])];
+    let view = RenderView::new(
+        &Camera::orthographic(4.0),
+        Mat4::IDENTITY,
+        &commands,
+        &materials,
+    );
+    // The additive quad is transparent, so it is drawn after the opaque one
+    let order: Vec<MaterialId> = view.batches.iter().map(|b| b.material).collect();
+    assert_eq!(order, vec![Materials::DEFAULT, glow]);
+}
+
+#[test]
+fn renderer_blends_by_material() {
+    let mut materials = Materials::new();
+    let add = materials.add(Material::new().with_blend(BlendMode::Additive));
+    let multiply = materials.add(Material::new().with_blend(BlendMode::Multiply));
+    let opaque = materials.add(Material::new().with_blend(BlendMode::Opaque));
+    let textures = Textures::new();
+
+    let pixel = |material, color| center_pixel(&[quad(material, color)], &textures, &materials);
+    assert_eq!(pixel(add, [100, 0, 200, 255]), [200, 100, 255, 255]);
+    assert_eq!(pixel(add, [100, 0, 200, 0]), [100, 100, 100, 255]);
+    assert_eq!(pixel(multiply, [255, 0, 128, 255]), [100, 0, 50, 255]);
+    assert_eq!(pixel(opaque, [10, 20, 30, 40]), [10, 20, 30, 255]);
+    assert_eq!(
+        pixel(Materials::DEFAULT, [200, 200, 200, 128]),
+        [150, 150, 150, 255]
+    );
+}
+
+#[test]
+fn base_color_and_texture_tint_commands() {
+    let mut textures = Textures::new();
+    let green = textures.add(Texture::solid(2, 2, [0, 200, 0, 255]));
+    let mut materials = Materials::new();
+    let tinted = materials.add(Material::new().with_color([255, 128, 0, 255]));
+    let textured = materials.add(Material::new().with_texture(green));
+
+    assert_eq!(
+        center_pixel(&[quad(tinted, [255, 255, 128, 255])], &textures, &materials),
+        [255, 128, 0, 255]
+    );
+    assert_eq!(
+        center_pixel(&[quad(tinted, [128, 255, 255, 255])], &textures, &materials),
+        [128, 128, 0, 255]
+    );
+    assert_eq!(
+        center_pixel(&[quad(textured, [255; 4])], &textures, &materials),
+        [0, 200, 0, 255]
+    );
+}
+
+#[test]
+fn alpha_cutoff_discards_faint_fragments() {
+    let mut materials = Materials::new();
+    let cutout =
+        materials.add(Material::new().with_uniform(Material::ALPHA_CUTOFF, Uniform::Float(0.5)));
+    let textures = Textures::new();
+    assert_eq!(
+        center_pixel(&[quad(cutout, [255, 0, 0, 100])], &textures, &materials),
+        [100, 100, 100, 255]
+    );
+    assert_eq!(
+        center_pixel(&[quad(cutout, [255, 0, 0, 255])], &textures, &materials),
+        [255, 0, 0, 255]
+    );
+}
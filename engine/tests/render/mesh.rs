@@ -0,0 +1,146 @@
+use pyreframe_engine::math::{Vec2, Vec3};
+use pyreframe_engine::render::{
+    Materials, Mesh, MeshError, MeshId, Meshes, RenderCommand, SoftwareRenderer, Textures,
+    Transform,
+};
+
+/// Asserts every triangle is counter-clockwise when seen from outside the origin.
+fn assert_faces_outward(mesh: &Mesh) {
+    for [a, b, c] in mesh.triangles() {
+        let normal = (b.position - a.position).cross(c.position - a.position);
+        let center = (a.position + b.position + c.position) / 3.0;
+        assert!(normal.dot(center) > 0.0, "inward triangle at {center:?}");
+    }
+}
+
+fn assert_indices_valid(mesh: &Mesh) {
+    assert_eq!(mesh.indices.len() % 3, 0);
+    assert!(
+        mesh.indices
+            .iter()
+            .all(|&index| (index as usize) < mesh.vertices.len())
+    );
+}
+
+#[test]
+fn registry_hands_out_sequential_ids() {
+    let mut meshes = Meshes::new();
+    assert!(meshes.is_empty());
+    assert_eq!(meshes.get(Meshes::QUAD), Some(&Mesh::quad(Vec2::ONE)));
+
+    let circle = meshes.add(Mesh::circle(1.0, 8));
+    let cube = meshes.add(Mesh::cube(1.0));
+    assert_eq!((circle, cube), (MeshId(1), MeshId(2)));
+    assert_eq!(meshes.len(), 2);
+    assert_eq!(meshes.get(cube).unwrap().triangle_count(), 12);
+    assert!(meshes.contains(circle));
+    assert!(!meshes.contains(MeshId(3)));
+    assert_eq!(meshes.get(MeshId(3)), None);
+
+    meshes.get_mut(circle).unwrap().indices.truncate(3);
+    let ids: Vec<(MeshId, usize)> = meshes
+        .iter()
+        .map(|(id, mesh)| (id, mesh.triangle_count()))
+        .collect();
+    assert_eq!(ids, vec![(MeshId(0), 2), (MeshId(1), 1), (MeshId(2), 12)]);
+}
+
+#[test]
+fn validate_reports_unknown_mesh() {
+    let meshes = Meshes::new();
+    let good = RenderCommand::new(Transform::IDENTITY, Meshes::QUAD);
+    let bad = RenderCommand::new(Transform::IDENTITY, MeshId(5));
+
+    assert_eq!(meshes.validate(&[good]), Ok(()));
+    assert_eq!(
+        meshes.validate(&[good, bad]),
+        Err(MeshError::UnknownMesh(MeshId(5)))
+    );
+}
+
+#[test]
+fn quad_and_grid_face_positive_z() {
+    let quad = Mesh::quad(Vec2::new(4.0, 2.0));
+    let grid = Mesh::grid(Vec2::new(4.0, 2.0), 3, 2);
+    assert_eq!(quad.triangle_count(), 2);
+    assert_eq!(grid.vertices.len(), 12);
+    assert_eq!(grid.triangle_count(), 12);
+
+    for mesh in [&quad, &grid] {
+        assert_indices_valid(mesh);
+        for [a, b, c] in mesh.triangles() {
+            let normal = (b.position - a.position).cross(c.position - a.position);
+            assert!(normal.z > 0.0);
+        }
+        let max_x = mesh
+            .vertices
+            .iter()
+            .map(|v| v.position.x)
+            .fold(f32::MIN, f32::max);
+        let min_y = mesh
+            .vertices
+            .iter()
+            .map(|v| v.position.y)
+            .fold(f32::MAX, f32::min);
+        assert_eq!((max_x, min_y), (2.0, -1.0));
+    }
+
+    // Top-left corner maps to the top-left of the texture
+    let top_left = grid.vertices[0];
+    assert_eq!(top_left.position, Vec3::new(-2.0, 1.0, 0.0));
+    assert_eq!(top_left.uv, Vec2::ZERO);
+}
+
+#[test]
+fn circle_is_a_fan_of_segments() {
+    let circle = Mesh::circle(2.0, 16);
+    assert_indices_valid(&circle);
+    assert_eq!(circle.vertices.len(), 17);
+    assert_eq!(circle.triangle_count(), 16);
+    assert!(
+        circle.vertices[1..]
+            .iter()
+            .all(|v| (v.position.length() - 2.0).abs() < 1e-5)
+    );
+    assert_eq!(Mesh::circle(1.0, 1).triangle_count(), 3);
+}
+
+#[test]
+fn cube_and_sphere_face_outward() {
+    let cube = Mesh::cube(2.0);
+    assert_indices_valid(&cube);
+    assert_eq!(cube.vertices.len(), 24);
+    assert!(cube.vertices.iter().all(|v| {
+        let p = v.position;
+        p.x.abs() == 1.0 && p.y.abs() == 1.0 && p.z.abs() == 1.0
+    }));
+    assert_faces_outward(&cube);
+
+    let sphere = Mesh::sphere(3.0, 12, 6);
+    assert_indices_valid(&sphere);
+    assert_eq!(sphere.vertices.len(), 13 * 7);
+    // Pole rings have one triangle per segment, the others two
+    assert_eq!(sphere.triangle_count(), 12 * (2 * 6 - 2));
+    assert!(
+        sphere
+            .vertices
+            .iter()
+            .all(|v| (v.position.length() - 3.0).abs() < 1e-4)
+    );
+    assert_faces_outward(&sphere);
+}
+
+#[test]
+fn circle_renders_round() {
+    let mut meshes = Meshes::new();
+    let circle = meshes.add(Mesh::circle(0.5, 32));
+    let mut renderer = SoftwareRenderer::new(16, 16);
+    let command = RenderCommand::new(Transform::IDENTITY, circle);
+    let framebuffer = renderer.render(&[command], &meshes, &Textures::new(), &Materials::new());
+
+    let white = |x, y| framebuffer.pixel(x, y) == Some([255; 4]);
+    assert!(white(8, 8));
+    assert!(white(4, 8) && white(11, 8));
+    // Corners of the bounding square stay empty
+    assert!(!white(4, 4) && !white(11, 11));
+}
@@ -0,0 +1,17 @@
+//! Render test module
+//
+// Groups rendering tests by subsystem.
+
+mod atlas;
+mod batch;
+mod camera;
+mod color;
+#[cfg(feature = "debug-draw")]
+mod debug;
+mod font;
+mod image;
+mod material;
+mod mesh;
+mod renderer;
+mod terminal;
+mod tilemap;
@@ -0,0 +1,158 @@
+use pyreframe_engine::math::{Mat4, Vec2, Vec3};
+use pyreframe_engine::render::{
+    Framebuffer, Materials, MeshId, Meshes, RenderCommand, SoftwareRenderer, Texture, Textures,
+    Transform,
+};
+
+const RED: [u8; 4] = [255, 0, 0, 255];
+const BLUE: [u8; 4] = [0, 0, 255, 255];
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+fn covered(framebuffer: &Framebuffer, color: [u8; 4]) -> Vec<(usize, usize)> {
+    let mut pixels = Vec::new();
+    for y in 0..framebuffer.height() {
+        for x in 0..framebuffer.width() {
+            if framebuffer.pixel(x, y) == Some(color) {
+                pixels.push((x, y));
+            }
+        }
+    }
+    pixels
+}
+
+#[test]
+fn clear_fills_color_and_resets_depth() {
+    let mut renderer = SoftwareRenderer::new(4, 3);
+    renderer.set_clear_color(BLUE);
+    renderer.clear();
+    let framebuffer = renderer.framebuffer();
+    assert!(framebuffer.pixels().iter().all(|&p| p == BLUE));
+    assert_eq!(framebuffer.depth(3, 2), Some(1.0));
+    assert_eq!(framebuffer.pixel(4, 0), None);
+    assert_eq!(framebuffer.to_rgba_bytes().len(), 4 * 3 * 4);
+}
+
+#[test]
+fn rect_covers_exactly_its_pixels() {
+    let mut renderer = SoftwareRenderer::new(8, 8);
+    renderer.clear();
+    renderer.draw_rect(Vec2::new(2.0, 2.0), Vec2::new(6.0, 5.0), 0.5, RED);
+
+    let pixels = covered(renderer.framebuffer(), RED);
+    assert_eq!(pixels.len(), 12);
+    assert!(
+        pixels
+            .iter()
+            .all(|&(x, y)| (2..6).contains(&x) && (2..5).contains(&y))
+    );
+}
+
+#[test]
+fn shared_edges_are_not_blended_twice() {
+    let mut renderer = SoftwareRenderer::new(8, 8);
+    renderer.clear();
+    renderer.draw_rect(Vec2::ZERO, Vec2::new(8.0, 8.0), 0.5, [255, 255, 255, 128]);
+
+    let first = renderer.framebuffer().pixel(0, 0).unwrap();
+    assert_eq!(first, [128, 128, 128, 255]);
+    assert!(renderer.framebuffer().pixels().iter().all(|&p| p == first));
+}
+
+#[test]
+fn depth_test_keeps_nearer_fragments() {
+    let mut renderer = SoftwareRenderer::new(4, 4);
+    renderer.clear();
+    renderer.draw_rect(Vec2::ZERO, Vec2::new(4.0, 4.0), 0.2, RED);
+    renderer.draw_rect(Vec2::ZERO, Vec2::new(4.0, 4.0), 0.8, BLUE);
+    assert_eq!(renderer.framebuffer().pixel(1, 1), Some(RED));
+    assert_eq!(renderer.framebuffer().depth(1, 1), Some(0.2));
+
+    renderer.draw_rect(Vec2::ZERO, Vec2::new(4.0, 4.0), 0.1, BLUE);
+    assert_eq!(renderer.framebuffer().pixel(1, 1), Some(BLUE));
+}
+
+#[test]
+fn translucent_fragments_blend_without_writing_depth() {
+    let mut renderer = SoftwareRenderer::new(2, 2);
+    renderer.clear();
+    renderer.draw_rect(Vec2::ZERO, Vec2::new(2.0, 2.0), 0.5, [255, 0, 0, 64]);
+    assert_eq!(renderer.framebuffer().pixel(0, 0), Some([64, 0, 0, 255]));
+    assert_eq!(renderer.framebuffer().depth(0, 0), Some(1.0));
+
+    // Fully transparent fragments change nothing
+    renderer.draw_rect(Vec2::ZERO, Vec2::new(2.0, 2.0), 0.5, [0, 255, 0, 0]);
+    assert_eq!(renderer.framebuffer().pixel(0, 0), Some([64, 0, 0, 255]));
+}
+
+#[test]
+fn sprite_samples_texture_and_applies_tint() {
+    let texture = Texture::new(2, 2, vec![RED, BLUE, BLUE, RED]).unwrap();
+    let mut renderer = SoftwareRenderer::new(4, 4);
+    renderer.clear();
+    renderer.draw_sprite(&texture, Vec2::ZERO, Vec2::new(4.0, 4.0), 0.5, [255; 4]);
+
+    let framebuffer = renderer.framebuffer();
+    assert_eq!(framebuffer.pixel(0, 0), Some(RED));
+    assert_eq!(framebuffer.pixel(3, 0), Some(BLUE));
+    assert_eq!(framebuffer.pixel(0, 3), Some(BLUE));
+    assert_eq!(framebuffer.pixel(3, 3), Some(RED));
+
+    renderer.draw_sprite(
+        &texture,
+        Vec2::ZERO,
+        Vec2::new(4.0, 4.0),
+        0.4,
+        [0, 255, 255, 255],
+    );
+    assert_eq!(renderer.framebuffer().pixel(0, 0), Some(BLACK));
+    assert_eq!(renderer.framebuffer().pixel(3, 0), Some(BLUE));
+}
+
+#[test]
+fn render_draws_commands_through_view_projection() {
+    let meshes = Meshes::new();
+    let mut renderer = SoftwareRenderer::new(8, 8);
+    // World units -4..4 map onto the 8 pixel wide target
+    renderer.set_view_projection(Mat4::orthographic_rh(-4.0, 4.0, -4.0, 4.0, -1.0, 1.0));
+
+    let transform = Transform {
+        scale: Vec3::splat(2.0),
+        ..Transform::from_translation(Vec3::new(-2.0, 2.0, 0.0))
+    };
+    let mut command = RenderCommand::new(transform, Meshes::QUAD);
+    command.color = RED;
+    let missing = RenderCommand::new(Transform::IDENTITY, MeshId(5));
+
+    renderer.render(
+        &[command, missing],
+        &meshes,
+        &Textures::new(),
+        &Materials::new(),
+    );
+    let pixels = covered(renderer.framebuffer(), RED);
+    // The 2x2 quad around (-2, 2) lands on pixels 1..3 in x and 1..3 in y
+    assert_eq!(pixels, vec![(1, 1), (2, 1), (1, 2), (2, 2)]);
+    assert_eq!(covered(renderer.framebuffer(), BLACK).len(), 60);
+}
+
+#[test]
+fn triangles_behind_the_eye_are_skipped() {
+    let meshes = Meshes::new();
+    let mut renderer = SoftwareRenderer::new(8, 8);
+    let view = Mat4::look_at_rh(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+    let projection = Mat4::perspective_rh(1.0, 1.0, 0.1, 100.0);
+    renderer.set_view_projection(projection * view);
+
+    let mut behind = RenderCommand::new(
+        Transform::from_translation(Vec3::new(0.0, 0.0, 10.0)),
+        Meshes::QUAD,
+    );
+    behind.color = RED;
+    renderer.render(&[behind], &meshes, &Textures::new(), &Materials::new());
+    assert!(covered(renderer.framebuffer(), RED).is_empty());
+
+    let mut in_front = behind;
+    in_front.transform = Transform::IDENTITY;
+    renderer.render(&[in_front], &meshes, &Textures::new(), &Materials::new());
+    assert!(covered(renderer.framebuffer(), RED).contains(&(4, 4)));
+}
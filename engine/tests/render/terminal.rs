@@ -0,0 +1,139 @@
+use pyreframe_engine::math::{Vec2, Vec3};
+use pyreframe_engine::render::{
+    Materials, Meshes, RenderCommand, TerminalCell, TerminalRenderer, Textures, Transform,
+};
+
+/// A 2x2 world-unit quad at `(x, y)` in the given color.
+fn square(x: f32, y: f32, color: [u8; 4]) -> RenderCommand {
+    let transform = Transform {
+        scale: Vec3::splat(2.0),
+        ..Transform::from_translation(Vec3::new(x, y, 0.0))
+    };
+    let mut command = RenderCommand::new(transform, Meshes::QUAD);
+    command.color = color;
+    command
+}
+
+/// A 16x4 grid showing world x in -8..8 and y in -4..4 with 2:1 cells.
+fn terminal() -> TerminalRenderer {
+    let mut terminal = TerminalRenderer::new(16, 4);
+    let view = terminal.view_2d(Vec2::ZERO, 4.0);
+    terminal.set_view_projection(view);
+    terminal
+}
+
+#[test]
+fn view_2d_keeps_world_units_square() {
+    let mut terminal = TerminalRenderer::new(40, 10);
+    assert_eq!(terminal.aspect_ratio(), 2.0);
+    terminal.set_cell_aspect(1.0);
+    assert_eq!(terminal.cell_aspect(), 1.0);
+    assert_eq!(terminal.aspect_ratio(), 4.0);
+}
+
+#[test]
+fn ascii_mode_shades_by_brightness() {
+    let mut terminal = terminal();
+    terminal.set_ansi_colors(false);
+    terminal.render(
+        &[square(-3.0, 1.0, [255; 4])],
+        &Meshes::new(),
+        &Textures::new(),
+        &Materials::new(),
+    );
+
+    // Cells are twice as tall as wide, so the square covers two columns of one row
+    assert_eq!(
+        terminal.to_plain_text(),
+        "                \n    @@          \n                \n                \n"
+    );
+}
+
+#[test]
+fn ascii_mode_ignores_color_changes_with_the_same_shade() {
+    let mut terminal = terminal();
+    terminal.set_ansi_colors(false);
+    let (meshes, textures, materials) = (Meshes::new(), Textures::new(), Materials::new());
+    terminal.render(
+        &[square(-3.0, 1.0, [255, 0, 0, 255])],
+        &meshes,
+        &textures,
+        &materials,
+    );
+
+    // Pure red and a slightly different red map to the same character
+    let output = terminal.render(
+        &[square(-3.0, 1.0, [250, 2, 0, 255])],
+        &meshes,
+        &textures,
+        &materials,
+    );
+    assert_eq!(output, "");
+    assert_eq!(terminal.cell(4, 1).unwrap().color, [0; 3]);
+}
+
+#[test]
+fn first_frame_is_a_full_colored_redraw() {
+    let mut terminal = terminal();
+    let output = terminal.render(
+        &[square(0.0, 1.0, [200, 10, 30, 255])],
+        &Meshes::new(),
+        &Textures::new(),
+        &Materials::new(),
+    );
+
+    assert!(output.starts_with("\x1b[0m\x1b[2J\x1b[1;1H\x1b[48;2;0;0;0m"));
+    assert!(output.contains("\x1b[48;2;200;10;30m  "));
+    assert!(output.ends_with("\x1b[0m"));
+    assert_eq!(output.matches(' ').count(), 16 * 4);
+    assert_eq!(
+        terminal.cell(8, 1),
+        Some(TerminalCell {
+            ch: ' ',
+            color: [200, 10, 30]
+        })
+    );
+    assert_eq!(terminal.cell(16, 0), None);
+}
+
+#[test]
+fn later_frames_only_redraw_changed_cells() {
+    let mut terminal = terminal();
+    let meshes = Meshes::new();
+    terminal.render(
+        &[square(-3.0, 1.0, [255; 4])],
+        &meshes,
+        &Textures::new(),
+        &Materials::new(),
+    );
+
+    assert_eq!(
+        terminal.render(
+            &[square(-3.0, 1.0, [255; 4])],
+            &meshes,
+            &Textures::new(),
+            &Materials::new()
+        ),
+        ""
+    );
+
+    // Moving right by two units clears columns 4..6 and fills columns 6..8
+    let output = terminal.render(
+        &[square(-1.0, 1.0, [255; 4])],
+        &meshes,
+        &Textures::new(),
+        &Materials::new(),
+    );
+    assert_eq!(output.matches(' ').count(), 4);
+    assert!(output.contains("\x1b[2;5H"));
+    assert!(!output.contains("\x1b[2J"));
+
+    terminal.invalidate();
+    let output = terminal.render(
+        &[square(-1.0, 1.0, [255; 4])],
+        &meshes,
+        &Textures::new(),
+        &Materials::new(),
+    );
+    assert!(output.contains("\x1b[2J"));
+}
@@ -0,0 +1,363 @@
+use pyreframe_engine::World;
+use pyreframe_engine::core::extract::extract_render_commands;
+use pyreframe_engine::ecs::components::Position;
+use pyreframe_engine::math::{Quat, Vec2, Vec3};
+use pyreframe_engine::render::{
+    Materials, Meshes, TextureId, Tile, TileFlags, Tilemap, TilemapError, Tileset, Transform,
+    build_batches,
+};
+
+const TMX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="orthogonal" renderorder="right-down" width="3" height="2" tilewidth="16" tileheight="8" infinite="0">
+ <tileset firstgid="1" name="ground &amp; walls" tilewidth="16" tileheight="8" tilecount="4" columns="2">
+  <image source="tiles.png" width="32" height="16"/>
+ </tileset>
+ <!-- Layers are drawn in document order -->
+ <layer id="1" name="ground" width="3" height="2">
+  <data encoding="csv">
+1,2,0,
+4,2147483651,0
+</data>
+ </layer>
+ <objectgroup id="3" name="spawns"/>
+ <layer id="2" name="decor" width="3" height="2" visible="0" opacity="0.5">
+  <data>
+   <tile/><tile gid="2"/><tile/>
+   <tile/><tile/><tile gid="536870913"/>
+  </data>
+ </layer>
+</map>
+"#;
+
+const JSON: &str = r#"{ "width": 3, "height": 2, "tilewidth": 16, "tileheight": 8,
+  "infinite": false, "orientation": "orthogonal",
+  "tilesets": [{ "firstgid": 1, "name": "ground & walls", "columns": 2, "tilecount": 4,
+    "tilewidth": 16, "tileheight": 8, "image": "tiles.png", "imagewidth": 32, "imageheight": 16 }],
+  "layers": [
+    { "type": "tilelayer", "name": "ground", "width": 3, "height": 2, "opacity": 1,
+      "visible": true, "data": [1, 2, 0, 4, 2147483651, 0] },
+    { "type": "objectgroup", "name": "spawns", "objects": [] },
+    { "type": "tilelayer", "name": "decor", "width": 3, "height": 2, "opacity": 0.5,
+      "visible": false, "data": [0, 2, 0, 0, 0, 536870913] }
+  ] }"#;
+
+fn tileset() -> Tileset {
+    Tileset::grid(TextureId(1), 32, 32, 2, 2)
+}
+
+fn map_with_layer(width: u32, height: u32) -> Tilemap {
+    let mut map = Tilemap::new(width, height, Vec2::ONE, tileset());
+    map.add_layer("ground").unwrap();
+    map
+}
+
+#[test]
+fn tiles_are_set_and_read_per_layer() {
+    let mut map = map_with_layer(4, 3);
+    let wall = Tile::new(2).with_flags(TileFlags::SOLID);
+    assert_eq!(map.set(0, 1, 2, Some(wall)), Ok(None));
+    assert_eq!(map.get(0, 1, 2), Some(wall));
+    assert_eq!(map.get(0, 2, 1), None);
+    assert!(map.has_flags(1, 2, TileFlags::SOLID));
+    assert!(!map.has_flags(0, 0, TileFlags::SOLID));
+
+    assert_eq!(map.set(0, 1, 2, None), Ok(Some(wall)));
+    assert_eq!(
+        map.set(0, 4, 0, Some(wall)),
+        Err(TilemapError::OutOfBounds { x: 4, y: 0 })
+    );
+    assert_eq!(
+        map.set(1, 0, 0, Some(wall)),
+        Err(TilemapError::UnknownLayer(1))
+    );
+    assert_eq!(map.layer_index("ground"), Some(0));
+    assert_eq!(map.layer_index("sky"), None);
+}
+
+#[test]
+fn tileset_uv_skips_margin_and_spacing() {
+    let tileset = Tileset {
+        margin: 1,
+        spacing: 2,
+        tile_width: 4,
+        tile_height: 4,
+        image_width: 12,
+        image_height: 12,
+        columns: 2,
+        tile_count: 4,
+        ..tileset()
+    };
+    let uv = tileset.uv(3).unwrap();
+    assert_eq!(uv.min, Vec2::new(7.0 / 12.0, 7.0 / 12.0));
+    assert_eq!(uv.max, Vec2::new(11.0 / 12.0, 11.0 / 12.0));
+    assert_eq!(tileset.uv(4), None);
+}
+
+#[test]
+fn world_and_tile_coordinates_convert_both_ways() {
+    let map = Tilemap::new(4, 3, Vec2::new(2.0, 1.0), tileset());
+    let transform = Transform {
+        translation: Vec3::new(10.0, 5.0, 0.0),
+        scale: Vec3::splat(2.0),
+        ..Transform::IDENTITY
+    };
+    // Tile (1, 2) spans 4..8 along x and 4..6 below the origin along y, scaled twice
+    assert_eq!(
+        map.tile_to_world(&transform, 1, 2),
+        Vec3::new(16.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        map.world_to_tile(&transform, Vec3::new(16.0, 0.0, 0.0)),
+        Some((1, 2))
+    );
+    assert_eq!(
+        map.world_to_tile(&transform, Vec3::new(10.1, 4.9, 0.0)),
+        Some((0, 0))
+    );
+    assert_eq!(
+        map.world_to_tile(&transform, Vec3::new(9.9, 4.9, 0.0)),
+        None
+    );
+    assert_eq!(
+        map.world_to_tile(&transform, Vec3::new(10.1, 5.1, 0.0)),
+        None
+    );
+    assert_eq!(
+        map.world_to_tile(&transform, Vec3::new(26.1, 4.9, 0.0)),
+        None
+    );
+}
+
+#[test]
+fn visible_layers_are_extracted_chunk_by_chunk() {
+    let mut map = map_with_layer(4, 2).with_chunk_size(2);
+    for (x, y) in [(0, 0), (3, 0), (2, 1), (1, 1)] {
+        map.set(0, x, y, Some(Tile::new(1))).unwrap();
+    }
+    let hidden = map.add_layer("hidden").unwrap();
+    map.set(hidden, 0, 0, Some(Tile::new(0))).unwrap();
+    map.layer_mut(hidden).unwrap().visible = false;
+    let layer = map.layer_mut(0).unwrap();
+    layer.layer = 2;
+    layer.tint = [255, 255, 255, 128];
+
+    let commands = map.render_commands(&Transform::IDENTITY);
+    let centers: Vec<Vec3> = commands
+        .iter()
+        .map(|command| command.transform.translation)
+        .collect();
+    // The left chunk comes first, each walked row by row
+    assert_eq!(
+        centers,
+        [
+            Vec3::new(0.5, -0.5, 0.0),
+            Vec3::new(1.5, -1.5, 0.0),
+            Vec3::new(3.5, -0.5, 0.0),
+            Vec3::new(2.5, -1.5, 0.0),
+        ]
+    );
+    let command = commands[0];
+    assert_eq!(command.mesh, Meshes::QUAD);
+    assert_eq!(command.texture, Some(TextureId(1)));
+    assert_eq!(command.uv, tileset().uv(1).unwrap());
+    assert_eq!(command.color, [255, 255, 255, 128]);
+    assert_eq!(command.layer, 2);
+    assert_eq!(command.transform.scale, Vec3::ONE);
+    assert_eq!(build_batches(&commands, &Materials::new()).len(), 1);
+}
+
+#[test]
+fn flipped_tiles_mirror_and_turn_their_quad() {
+    let mut map = map_with_layer(3, 1);
+    let flipped = |flags| Some(Tile::new(0).with_flags(flags));
+    map.set(0, 0, 0, flipped(TileFlags::FLIP_X)).unwrap();
+    map.set(0, 1, 0, flipped(TileFlags::FLIP_DIAGONAL)).unwrap();
+    map.set(0, 2, 0, Some(Tile::new(9))).unwrap();
+
+    let commands = map.render_commands(&Transform::IDENTITY);
+    // Tiles past the end of the tileset are not drawn
+    assert_eq!(commands.len(), 2);
+    let uv = tileset().uv(0).unwrap();
+    assert_eq!(commands[0].uv, uv.flipped(true, false));
+    assert_eq!(commands[0].transform.rotation, Quat::IDENTITY);
+    assert_eq!(commands[1].uv, uv.flipped(false, true));
+    assert_eq!(
+        commands[1].transform.rotation,
+        Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2)
+    );
+}
+
+#[test]
+fn tilemap_entities_are_extracted() {
+    let mut world = World::new();
+    let mut map = map_with_layer(2, 2);
+    map.set(0, 1, 1, Some(Tile::new(3))).unwrap();
+    let entity = world.spawn();
+    world.insert_component(entity, map).unwrap();
+    world
+        .insert_component(entity, Position::new(-1.0, 1.0, 0.0))
+        .unwrap();
+
+    let commands = extract_render_commands(&world);
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].transform.translation, Vec3::new(0.5, -0.5, 0.0));
+    assert_eq!(commands[0].uv, tileset().uv(3).unwrap());
+}
+
+#[test]
+fn tmx_maps_are_loaded() {
+    let map = Tilemap::parse_tmx(TMX, TextureId(7)).unwrap();
+    assert_eq!((map.width(), map.height()), (3, 2));
+    assert_eq!(map.tile_size(), Vec2::new(1.0, 0.5));
+    assert_eq!(map.tileset().texture, TextureId(7));
+    assert_eq!(map.tileset().uv(3).unwrap().min, Vec2::new(0.5, 0.5));
+    assert_eq!(map.layers().len(), 2);
+
+    let ground = map.layer(0).unwrap();
+    assert_eq!(ground.name, "ground");
+    assert!(ground.visible);
+    assert_eq!((ground.layer, ground.tint), (0, [255; 4]));
+    assert_eq!(map.get(0, 0, 0), Some(Tile::new(0)));
+    assert_eq!(map.get(0, 2, 0), None);
+    assert_eq!(map.get(0, 0, 1), Some(Tile::new(3)));
+    assert_eq!(
+        map.get(0, 1, 1),
+        Some(Tile::new(2).with_flags(TileFlags::FLIP_X))
+    );
+
+    let decor = map.layer(1).unwrap();
+    assert!(!decor.visible);
+    assert_eq!((decor.layer, decor.tint[3]), (1, 128));
+    assert_eq!(map.get(1, 1, 0), Some(Tile::new(1)));
+    assert_eq!(
+        map.get(1, 2, 1),
+        Some(Tile::new(0).with_flags(TileFlags::FLIP_DIAGONAL))
+    );
+}
+
+#[test]
+fn json_maps_match_tmx_maps() {
+    assert_eq!(
+        Tilemap::parse_tiled_json(JSON, TextureId(7)),
+        Tilemap::parse_tmx(TMX, TextureId(7))
+    );
+}
+
+#[test]
+fn oversized_maps_are_rejected_without_panicking() {
+    let mut map = Tilemap::new(u32::MAX, u32::MAX, Vec2::ONE, tileset());
+    assert_eq!(
+        map.add_layer("ground"),
+        Err(TilemapError::TooLarge {
+            width: u32::MAX,
+            height: u32::MAX,
+        })
+    );
+    assert!(map.layers().is_empty());
+
+    // 70000 x 70000 cells overflow a u32 but not the size check
+    let huge = TMX.replace(
+        "width=\"3\" height=\"2\" tilewidth",
+        "width=\"70000\" height=\"70000\" tilewidth",
+    );
+    assert_eq!(
+        Tilemap::parse_tmx(&huge, TextureId(0)),
+        Err(TilemapError::LayerSizeMismatch {
+            layer: "ground".to_string(),
+            expected: 4_900_000_000,
+            found: 6,
+        })
+    );
+}
+
+#[test]
+fn tilesets_that_do_not_fit_their_image_are_rejected() {
+    let texture = TextureId(0);
+    let overflowing = JSON.replace(
+        "\"tilewidth\": 16, \"tileheight\": 8, \"image\"",
+        "\"tilewidth\": 4294967295, \"spacing\": 1, \"tileheight\": 8, \"image\"",
+    );
+    assert_eq!(
+        Tilemap::parse_tiled_json(&overflowing, texture),
+        Err(TilemapError::InvalidValue("tileset.tilewidth".to_string()))
+    );
+    assert_eq!(
+        Tilemap::parse_tmx(&TMX.replace("tilecount=\"4\"", "tilecount=\"6\""), texture),
+        Err(TilemapError::InvalidValue("tileset.tileheight".to_string()))
+    );
+
+    // Tiles placed past u32 pixels have no texture coordinates instead of panicking
+    let tileset = Tileset {
+        tile_width: u32::MAX,
+        spacing: 1,
+        ..tileset()
+    };
+    assert_eq!(tileset.uv(1), None);
+}
+
+#[test]
+fn deeply_nested_documents_are_rejected() {
+    let texture = TextureId(0);
+    assert_eq!(
+        Tilemap::parse_tiled_json(&"[".repeat(200_000), texture),
+        Err(TilemapError::Syntax { offset: 64 })
+    );
+    let nested = format!("{}{}", "<a>".repeat(200_000), "</a>".repeat(200_000));
+    assert!(matches!(
+        Tilemap::parse_tmx(&nested, texture),
+        Err(TilemapError::Syntax { .. })
+    ));
+}
+
+#[test]
+fn invalid_tiled_maps_are_rejected() {
+    let texture = TextureId(0);
+    assert_eq!(
+        Tilemap::parse_tmx(&TMX.replace("4,2147483651,0", "4,3"), texture),
+        Err(TilemapError::LayerSizeMismatch {
+            layer: "ground".to_string(),
+            expected: 6,
+            found: 5,
+        })
+    );
+    assert_eq!(
+        Tilemap::parse_tmx(&TMX.replace("1,2,0,", "1,5,0,"), texture),
+        Err(TilemapError::InvalidTile { gid: 5 })
+    );
+    assert_eq!(
+        Tilemap::parse_tmx(&TMX.replace("\"csv\"", "\"base64\""), texture),
+        Err(TilemapError::Unsupported("base64 encoding".to_string()))
+    );
+    assert_eq!(
+        Tilemap::parse_tmx(
+            &TMX.replace(" width=\"3\" height=\"2\" tilewidth", " tilewidth"),
+            texture
+        ),
+        Err(TilemapError::Missing("map.width".to_string()))
+    );
+    assert!(matches!(
+        Tilemap::parse_tmx(&TMX.replace("</layer>", ""), texture),
+        Err(TilemapError::Syntax { .. })
+    ));
+    assert_eq!(
+        Tilemap::parse_tiled_json(
+            &JSON.replace("\"infinite\": false", "\"infinite\": true"),
+            texture
+        ),
+        Err(TilemapError::Unsupported("infinite map".to_string()))
+    );
+    assert_eq!(
+        Tilemap::parse_tiled_json(
+            &JSON.replace(
+                "\"width\": 3, \"height\": 2, \"tilewidth\"",
+                "\"width\": \"3\", \"height\": 2, \"tilewidth\""
+            ),
+            texture
+        ),
+        Err(TilemapError::InvalidValue("map.width".to_string()))
+    );
+    assert_eq!(
+        Tilemap::parse_tiled_json("{ \"width\": 3,, }", texture),
+        Err(TilemapError::Syntax { offset: 13 })
+    );
+}
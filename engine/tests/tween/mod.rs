@@ -0,0 +1,6 @@
+//! Tween test module
+//
+// Groups tween playback and system tests.
+
+mod playback;
+mod system;
@@ -0,0 +1,85 @@
+use pyreframe_engine::math::{Easing, Vec3};
+use pyreframe_engine::tween::{Repeat, Tween, TweenStep};
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-5
+}
+
+#[test]
+fn single_cycle_runs_from_zero_to_one() {
+    let mut tween = Tween::position(Vec3::ZERO, Vec3::X, 1.0);
+    assert_eq!(tween.progress(), 0.0);
+    assert_eq!(tween.advance(0.25), TweenStep::default());
+    assert!(close(tween.progress(), 0.25));
+
+    let step = tween.advance(1.0);
+    assert!(step.completed);
+    assert_eq!(step.looped, 0);
+    assert!(tween.is_finished());
+    assert_eq!(tween.progress(), 1.0);
+    assert_eq!(tween.advance(1.0), TweenStep::default());
+}
+
+#[test]
+fn easing_shapes_progress() {
+    let mut tween = Tween::position(Vec3::ZERO, Vec3::X, 2.0).with_easing(Easing::QuadIn);
+    tween.advance(1.0);
+    assert!(close(tween.progress(), 0.25));
+}
+
+#[test]
+fn delay_holds_progress_at_zero() {
+    let mut tween = Tween::position(Vec3::ZERO, Vec3::X, 1.0).with_delay(0.5);
+    tween.advance(0.4);
+    assert!(tween.is_delayed());
+    assert_eq!(tween.progress(), 0.0);
+    tween.advance(0.6);
+    assert!(!tween.is_delayed());
+    assert!(close(tween.progress(), 0.5));
+    assert!(tween.advance(0.5).completed);
+}
+
+#[test]
+fn looping_reports_each_cycle() {
+    let mut tween = Tween::position(Vec3::ZERO, Vec3::X, 1.0).with_repeat(Repeat::Times(3));
+    assert_eq!(tween.advance(1.5).looped, 1);
+    assert!(close(tween.progress(), 0.5));
+
+    // A large step crosses the second loop and finishes the third cycle
+    let step = tween.advance(5.0);
+    assert_eq!(step.looped, 1);
+    assert!(step.completed);
+    assert_eq!(tween.progress(), 1.0);
+}
+
+#[test]
+fn forever_never_finishes() {
+    let mut tween = Tween::position(Vec3::ZERO, Vec3::X, 0.5).with_repeat(Repeat::Forever);
+    let step = tween.advance(10.25);
+    assert_eq!(step.looped, 20);
+    assert!(!step.completed);
+    assert!(close(tween.progress(), 0.5));
+}
+
+#[test]
+fn ping_pong_reverses_every_other_cycle() {
+    let mut tween = Tween::position(Vec3::ZERO, Vec3::X, 1.0)
+        .with_repeat(Repeat::Times(2))
+        .with_ping_pong(true);
+    tween.advance(0.75);
+    assert!(close(tween.progress(), 0.75));
+    tween.advance(0.5);
+    assert!(close(tween.progress(), 0.75));
+    assert!(tween.advance(1.0).completed);
+    assert_eq!(tween.progress(), 0.0);
+}
+
+#[test]
+fn reset_restarts_playback() {
+    let mut tween = Tween::position(Vec3::ZERO, Vec3::X, 1.0).with_delay(0.2);
+    tween.advance(2.0);
+    assert!(tween.is_finished());
+    tween.reset();
+    assert!(tween.is_delayed());
+    assert_eq!(tween.elapsed(), 0.0);
+}
@@ -0,0 +1,213 @@
+use pyreframe_engine::World;
+use pyreframe_engine::ecs::components::{Color, Position};
+use pyreframe_engine::ecs::system::{time_system, tween_system};
+use pyreframe_engine::math::{Quat, Vec3};
+use pyreframe_engine::render::Transform;
+use pyreframe_engine::time::{FrameDelta, Time};
+use pyreframe_engine::tween::{Repeat, Tween, TweenEvent, TweenEvents};
+
+fn world_with_time(dt: f32) -> World {
+    let mut world = World::new();
+    world.insert_resource(Time::default());
+    world.insert_resource(FrameDelta { dt });
+    world
+}
+
+fn step(world: &mut World) {
+    time_system(world);
+    tween_system(world);
+}
+
+#[test]
+fn animates_position() {
+    let mut world = world_with_time(0.5);
+    let entity = world.spawn();
+    world.insert_component(entity, Position::default()).unwrap();
+    world
+        .insert_component(
+            entity,
+            Tween::position(Vec3::ZERO, Vec3::new(4.0, 2.0, 0.0), 1.0),
+        )
+        .unwrap();
+
+    step(&mut world);
+    let pos = world.get_component::<Position>(entity).unwrap();
+    assert!(pos.abs_diff_eq(Vec3::new(2.0, 1.0, 0.0), 1e-5));
+    assert!(
+        world
+            .get_resource::<TweenEvents>()
+            .unwrap()
+            .events()
+            .is_empty()
+    );
+
+    step(&mut world);
+    let pos = world.get_component::<Position>(entity).unwrap();
+    assert!(pos.abs_diff_eq(Vec3::new(4.0, 2.0, 0.0), 1e-5));
+    assert!(
+        world
+            .get_resource::<TweenEvents>()
+            .unwrap()
+            .completed(entity)
+    );
+
+    // Events only last for the frame they happened in
+    step(&mut world);
+    assert!(
+        world
+            .get_resource::<TweenEvents>()
+            .unwrap()
+            .events()
+            .is_empty()
+    );
+}
+
+#[test]
+fn animates_color() {
+    let mut world = world_with_time(0.5);
+    let entity = world.spawn();
+    let black = Color {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 255,
+    };
+    let white = Color {
+        r: 255,
+        g: 255,
+        b: 255,
+        a: 255,
+    };
+    world.insert_component(entity, black).unwrap();
+    world
+        .insert_component(entity, Tween::color(black, white, 1.0))
+        .unwrap();
+
+    step(&mut world);
+    assert_eq!(
+        world.get_component::<Color>(entity).unwrap(),
+        &Color {
+            r: 128,
+            g: 128,
+            b: 128,
+            a: 255
+        }
+    );
+}
+
+#[test]
+fn animates_transform() {
+    let mut world = world_with_time(0.5);
+    let entity = world.spawn();
+    let to = Transform {
+        translation: Vec3::new(2.0, 0.0, 0.0),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        scale: Vec3::splat(3.0),
+    };
+    world.insert_component(entity, Transform::IDENTITY).unwrap();
+    world
+        .insert_component(entity, Tween::transform(Transform::IDENTITY, to, 1.0))
+        .unwrap();
+
+    step(&mut world);
+    let transform = world.get_component::<Transform>(entity).unwrap();
+    assert!(transform.translation.abs_diff_eq(Vec3::X, 1e-5));
+    assert!(transform.scale.abs_diff_eq(Vec3::splat(2.0), 1e-5));
+    assert!(
+        transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4), 1e-5)
+    );
+}
+
+#[test]
+fn delayed_tween_leaves_component_alone() {
+    let mut world = world_with_time(0.1);
+    let entity = world.spawn();
+    world
+        .insert_component(entity, Position::new(9.0, 9.0, 9.0))
+        .unwrap();
+    world
+        .insert_component(
+            entity,
+            Tween::position(Vec3::ZERO, Vec3::X, 1.0).with_delay(0.5),
+        )
+        .unwrap();
+
+    step(&mut world);
+    assert_eq!(
+        world.get_component::<Position>(entity).unwrap(),
+        &Position::new(9.0, 9.0, 9.0)
+    );
+}
+
+#[test]
+fn loop_events_are_published() {
+    let mut world = world_with_time(1.0);
+    let entity = world.spawn();
+    world.insert_component(entity, Position::default()).unwrap();
+    world
+        .insert_component(
+            entity,
+            Tween::position(Vec3::ZERO, Vec3::X, 1.0).with_repeat(Repeat::Times(2)),
+        )
+        .unwrap();
+
+    step(&mut world);
+    assert_eq!(
+        world.get_resource::<TweenEvents>().unwrap().events(),
+        &[TweenEvent::Looped(entity)]
+    );
+    step(&mut world);
+    assert_eq!(
+        world.get_resource::<TweenEvents>().unwrap().events(),
+        &[TweenEvent::Completed(entity)]
+    );
+}
+
+#[test]
+fn empty_tweens_apply_the_end_value_and_complete_on_first_step() {
+    let mut world = world_with_time(0.016);
+    let zero_duration = world.spawn();
+    let zero_cycles = world.spawn();
+    for (entity, tween) in [
+        (
+            zero_duration,
+            Tween::position(Vec3::ZERO, Vec3::new(3.0, 0.0, 0.0), 0.0),
+        ),
+        (
+            zero_cycles,
+            Tween::position(Vec3::ZERO, Vec3::new(5.0, 0.0, 0.0), 1.0)
+                .with_repeat(Repeat::Times(0)),
+        ),
+    ] {
+        world.insert_component(entity, Position::default()).unwrap();
+        world.insert_component(entity, tween).unwrap();
+    }
+
+    step(&mut world);
+    assert_eq!(
+        world.get_component::<Position>(zero_duration).unwrap(),
+        &Position::new(3.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        world.get_component::<Position>(zero_cycles).unwrap(),
+        &Position::new(5.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        world.get_resource::<TweenEvents>().unwrap().events(),
+        &[
+            TweenEvent::Completed(zero_duration),
+            TweenEvent::Completed(zero_cycles)
+        ]
+    );
+
+    step(&mut world);
+    assert!(
+        world
+            .get_resource::<TweenEvents>()
+            .unwrap()
+            .events()
+            .is_empty()
+    );
+}
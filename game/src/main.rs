@@ -5,48 +5,159 @@
 //! Defines gameplay behavior.
 //! Uses the engine as a library.
 
-use pyreframe_engine::ecs::system::movement_system;
-use pyreframe_engine::{
-    World,
-    ecs::components::{Color, Position, Velocity},
+use pyreframe_engine::Engine;
+use pyreframe_engine::core::frame::FrameOutput;
+use pyreframe_engine::ecs::components::{Color, Position, Renderable, Sprite, Text, Velocity};
+use pyreframe_engine::ecs::system::{movement_system, particle_system, time_system};
+use pyreframe_engine::input::Input;
+use pyreframe_engine::math::{Vec2, Vec3};
+use pyreframe_engine::particles::{Curve, Gradient, ParticleEmitter};
+use pyreframe_engine::render::{
+    AtlasBuilder, BitmapFont, BlendMode, Fonts, Hsv, Material, Materials, Mesh, Meshes,
+    TerminalRenderer, TextAlign, Texture, Textures, Transform,
 };
+use pyreframe_engine::time::Time;
 
 fn main() {
-    let mut world = World::new();
+    let mut engine = Engine::new();
+    engine.schedule_mut().add_system(time_system);
+    engine.schedule_mut().add_system(movement_system);
+    engine.schedule_mut().add_system(particle_system);
 
-    let player = world.spawn();
+    let mut meshes = Meshes::new();
+    let circle = meshes.add(Mesh::circle(0.5, 24));
+    let ground = meshes.add(Mesh::grid(Vec2::new(16.0, 1.0), 16, 1));
+
+    let mut atlas = AtlasBuilder::new(64);
+    atlas
+        .add(
+            "crate",
+            checker(8, [150, 100, 40, 255], [220, 180, 110, 255]),
+        )
+        .add("flag", Texture::solid(6, 4, [40, 200, 60, 255]));
+    let atlas = atlas.build().expect("sprite images fit the atlas");
+    let crate_uv = atlas.uv_named("crate").expect("crate was packed");
+    let flag_uv = atlas.uv_named("flag").expect("flag was packed");
+    let mut textures = Textures::new();
+    let sprites = textures.add(atlas.into_texture());
+    let mut fonts = Fonts::new();
+    let font = fonts.add(BitmapFont::builtin(
+        textures.add(BitmapFont::builtin_texture()),
+    ));
+    let mut materials = Materials::new();
+    let glow = materials.add(Material::new().with_blend(BlendMode::Additive));
+
+    let world = engine.world_mut();
 
+    let floor = world.spawn();
+    let _ = world.insert_component(floor, Position::new(0.0, -3.5, 0.0));
+    let _ = world.insert_component(floor, Renderable::new(ground));
+    let _ = world.insert_component(floor, Color::rgb(90, 60, 30));
+
+    let sun = world.spawn();
+    let _ = world.insert_component(sun, Renderable::new(circle).with_material(glow));
+    let _ = world.insert_component(sun, Color::from(Hsv::new(52.0, 1.0, 1.0)));
     let _ = world.insert_component(
-        player,
-        Position {
-            x: 1.0,
-            y: 1.0,
-            z: 0.0,
+        sun,
+        Transform {
+            translation: Vec3::new(5.0, 2.5, 0.0),
+            scale: Vec3::splat(2.0),
+            ..Transform::IDENTITY
         },
     );
+
+    let crate_box = world.spawn();
+    let _ = world.insert_component(crate_box, Position::new(-3.5, -2.5, 0.0));
     let _ = world.insert_component(
-        player,
-        Velocity {
-            dx: 1.0,
-            dy: 0.5,
-            dz: 2.0,
-        },
+        crate_box,
+        Sprite::new(sprites, Vec2::ONE).with_region(crate_uv),
     );
+
+    let flag = world.spawn();
+    let _ = world.insert_component(flag, Position::new(2.0, -3.0, 0.0));
     let _ = world.insert_component(
-        player,
-        Color {
-            r: 255,
-            g: 0,
-            b: 0,
-            a: 255,
-        },
+        flag,
+        Sprite::new(sprites, Vec2::new(1.5, 1.0))
+            .with_region(flag_uv)
+            .with_anchor(Vec2::new(0.0, 0.0)),
+    );
+
+    let player = world.spawn();
+    let _ = world.insert_component(player, Position::new(-6.0, -2.5, 0.0));
+    let _ = world.insert_component(player, Velocity::new(0.25, 0.0, 0.0));
+    let _ = world.insert_component(player, Renderable::new(Meshes::QUAD).with_layer(1));
+    let _ = world.insert_component(player, Color::RED);
+
+    let smoke = world.spawn();
+    let _ = world.insert_component(smoke, Position::new(-3.5, -1.8, 0.0));
+    let _ = world.insert_component(
+        smoke,
+        ParticleEmitter::new()
+            .with_seed(1)
+            .with_rate(30.0)
+            .with_lifetime(0.2..0.4)
+            .with_velocity(Vec3::new(-0.5, 1.0, 0.0)..Vec3::new(0.5, 2.0, 0.0))
+            .with_size(Curve::linear(0.3, 0.1))
+            .with_color(Gradient::linear(Color::GRAY, Color::GRAY.with_alpha(0))),
+    );
+
+    let title = world.spawn();
+    let _ = world.insert_component(title, Position::new(0.0, 3.8, 0.0));
+    let _ = world.insert_component(
+        title,
+        Text::new("PYRE", font, 1.5)
+            .with_align(TextAlign::Center)
+            .with_layer(2),
     );
 
-    world.add_system(movement_system);
+    world.insert_resource(Time::default());
+    world.insert_resource(meshes);
+    world.insert_resource(textures);
+    world.insert_resource(fonts);
+    world.insert_resource(materials);
 
-    world.run_systems();
+    let mut output = FrameOutput::default();
+    for _ in 0..20 {
+        output = engine.tick(Input::default(), 1.0 / 60.0);
+    }
+
+    let world = engine.world();
+    let meshes = world
+        .get_resource::<Meshes>()
+        .expect("meshes were inserted");
+    let textures = world
+        .get_resource::<Textures>()
+        .expect("textures were inserted");
+    let materials = world
+        .get_resource::<Materials>()
+        .expect("materials were inserted");
+    if let Err(error) = meshes.validate(&output.render_commands) {
+        eprintln!("Invalid render command: {error:?}");
+        return;
+    }
+
+    let mut terminal = TerminalRenderer::new(48, 16);
+    terminal.set_ansi_colors(false);
+    terminal.set_view_projection(terminal.view_2d(Vec2::ZERO, 4.0));
+    terminal.render(&output.render_commands, meshes, textures, materials);
+    print!("{}", terminal.to_plain_text());
 
     if let Ok(pos) = world.get_component::<Position>(player) {
-        println!("Entity position: ({}, {}, {})", pos.x, pos.y, pos.z);
+        println!("Player position: ({}, {}, {})", pos.x, pos.y, pos.z);
     }
 }
+
+/// A square texture of alternating 2x2 pixel blocks.
+fn checker(size: usize, dark: [u8; 4], light: [u8; 4]) -> Texture {
+    let pixels = (0..size * size)
+        .map(|i| {
+            let (x, y) = (i % size, i / size);
+            if (x / 2 + y / 2) % 2 == 0 {
+                dark
+            } else {
+                light
+            }
+        })
+        .collect();
+    Texture::new(size, size, pixels).expect("pixel count matches size")
+}